@@ -23,7 +23,7 @@ macro_rules! cmake_vars {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 enum LinkKind {
     Default,
     Static,
@@ -206,6 +206,281 @@ impl LinkFlags {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LinkSource {
+    LibDir,
+    BuildFromSource,
+    Framework,
+    PkgConfig,
+    CmakeConfig,
+    Vcpkg,
+    EmscriptenPort,
+    Default,
+}
+
+impl LinkSource {
+    const ALL: [Self; 8] = [
+        Self::LibDir,
+        Self::BuildFromSource,
+        Self::Framework,
+        Self::PkgConfig,
+        Self::CmakeConfig,
+        Self::Vcpkg,
+        Self::EmscriptenPort,
+        Self::Default,
+    ];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::LibDir => "lib-dir",
+            Self::BuildFromSource => "build-from-source",
+            Self::Framework => "framework",
+            Self::PkgConfig => "pkg-config",
+            Self::CmakeConfig => "cmake-config",
+            Self::Vcpkg => "vcpkg",
+            Self::EmscriptenPort => "emscripten-port",
+            Self::Default => "default",
+        }
+    }
+
+    // true if the library isn't the one installed on the system
+    fn is_private(self) -> bool {
+        matches!(self, Self::LibDir | Self::BuildFromSource)
+    }
+}
+
+// How a -sys crate resolved its library. This is passed on to dependent -sys crates
+// via cargo metadata, so they can check that everything links the same SDL.
+struct LinkInfo {
+    source: LinkSource,
+    link_kind: LinkKind,
+    lib_dir: Option<String>,
+    version: Option<String>,
+}
+
+impl LinkInfo {
+    fn new(source: LinkSource, link_kind: LinkKind) -> Self {
+        Self {
+            source,
+            link_kind,
+            lib_dir: None,
+            version: None,
+        }
+    }
+
+    fn from_dep(links: &str) -> Option<Self> {
+        let var = |key: &str| {
+            env::var(format!("DEP_{links}_{key}"))
+                .ok()
+                .filter(|value| !value.is_empty())
+        };
+        let source = var("LINK_SOURCE")?;
+        Some(Self {
+            source: *LinkSource::ALL.iter().find(|s| s.as_str() == source)?,
+            link_kind: if var("LINK_STATIC").as_deref() == Some("1") {
+                LinkKind::Static
+            } else {
+                LinkKind::Default
+            },
+            lib_dir: var("LINK_LIB_DIR"),
+            version: var("LINK_VERSION"),
+        })
+    }
+
+    // version of the library in the source crate, from the build metadata of the crate version
+    #[cfg(feature = "build-from-source")]
+    fn source_version() -> Option<String> {
+        let version = env::var("CARGO_PKG_VERSION").ok()?;
+        Some(version.split_once('+')?.1.rsplit('-').next()?.to_owned())
+    }
+
+    // find the library `lib_name` installed in `lib_dir`, with its version from the
+    // pkg-config file for `package_name` if there is one
+    fn find_installed(lib_dir: &str, package_name: &str, lib_name: &str) -> Option<Self> {
+        use std::path::Path;
+
+        let dir = Path::new(lib_dir);
+        let version = read_to_string(dir.join(format!("pkgconfig/{package_name}.pc")))
+            .ok()
+            .and_then(|pc| {
+                pc.lines()
+                    .find_map(|line| Some(line.strip_prefix("Version:")?.trim().to_owned()))
+            });
+        let found = version.is_some()
+            || [
+                format!("lib{lib_name}.so"),
+                format!("lib{lib_name}.dylib"),
+                format!("lib{lib_name}.a"),
+                format!("{lib_name}.lib"),
+                format!("{lib_name}.dll"),
+                format!("{lib_name}.framework"),
+            ]
+            .iter()
+            .any(|file| dir.join(file).exists());
+        found.then(|| Self {
+            source: LinkSource::LibDir,
+            link_kind: LinkKind::Default,
+            lib_dir: Some(lib_dir.to_owned()),
+            version,
+        })
+    }
+
+    // true if `dir` is the directory this library was found in
+    fn is_lib_dir(&self, dir: &str) -> bool {
+        use std::path::Path;
+
+        let Some(lib_dir) = &self.lib_dir else {
+            return false;
+        };
+        let lib_dir = Path::new(lib_dir);
+        let mut dirs = vec![lib_dir.to_path_buf()];
+        if self.source == LinkSource::BuildFromSource {
+            // the build output has the libraries in subdirectories
+            dirs.extend([
+                lib_dir.join("lib"),
+                lib_dir.join("lib64"),
+                lib_dir.join("bin"),
+            ]);
+        }
+        let canonical = |dir: &Path| dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+        let dir = canonical(Path::new(dir));
+        dirs.iter().any(|lib_dir| canonical(lib_dir) == dir)
+    }
+
+    fn describe(&self) -> String {
+        let mut s = self.source.as_str().to_owned();
+        if let Some(version) = &self.version {
+            s.push_str(&format!(", version {version}"));
+        }
+        if let Some(lib_dir) = &self.lib_dir {
+            s.push_str(&format!(", in {lib_dir}"));
+        }
+        s
+    }
+
+    fn send_to_cargo_metadata(&self) {
+        let source = self.source.as_str();
+        let link_static = self.link_kind == LinkKind::Static;
+        let lib_dir = self.lib_dir.as_deref().unwrap_or_default();
+        let version = self.version.as_deref().unwrap_or_default();
+        println!("cargo::metadata=LINK_SOURCE={source}");
+        println!("cargo::metadata=LINK_STATIC={}", link_static as u8);
+        println!("cargo::metadata=LINK_LIB_DIR={lib_dir}");
+        println!("cargo::metadata=LINK_VERSION={version}");
+
+        // `;` separated entries for sdl3-sys and this crate. Satellite -sys crates don't depend
+        // on each other, so this is all they can see
+        let mut report = env::var("DEP_SDL3_LINK_REPORT").unwrap_or_default();
+        if !report.is_empty() {
+            report.push(';');
+        }
+        report.push_str(&format!(
+            "{},source={source},link={},version={version},lib_dir={lib_dir}",
+            config("package_name"),
+            if link_static { "static" } else { "shared" },
+        ));
+        println!("cargo::metadata=LINK_REPORT={report}");
+    }
+
+    // check that a satellite library links the same SDL as sdl3-sys
+    fn check_consistency(&self) -> Result<(), Box<dyn Error>> {
+        let Some(sdl) = Self::from_dep("SDL3") else {
+            // this is sdl3-sys, or sdl3-sys doesn't link
+            return Ok(());
+        };
+        if env_flag("SKIP_LINK_CHECK") == Some(true) {
+            return Ok(());
+        }
+        let package = config("package_name");
+        let lib_name = config("lib_name");
+        let skip = format!(
+            "Set {}=1 to skip this check",
+            env_var_name("SKIP_LINK_CHECK")
+        );
+
+        if sdl.source.is_private() && !self.source.is_private() {
+            return Err(format!(
+                "sdl3-sys links SDL3 ({}), but {package}-sys links the system {lib_name} ({}), \
+                which links its own copy of SDL3. Use the same `build-from-source` setting for \
+                both crates, or set {} to a {lib_name} built with the same SDL3. {skip}.",
+                sdl.describe(),
+                self.describe(),
+                env_var_name("LIB_DIR"),
+            )
+            .into());
+        }
+
+        if sdl.link_kind == LinkKind::Static && self.link_kind == LinkKind::Default {
+            return Err(format!(
+                "sdl3-sys links SDL3 statically ({}), but {package}-sys links {lib_name} \
+                dynamically ({}). The shared {lib_name} library would load its own copy of SDL3. \
+                Enable the `link-static` feature or set {}=1 for {package}-sys. {skip}.",
+                sdl.describe(),
+                self.describe(),
+                env_var_name("STATIC"),
+            )
+            .into());
+        }
+
+        // the SDL3 that this library uses, if it can be found. A library built from source
+        // is built with the SDL3 from sdl3-sys
+        let mut uses = None;
+        #[cfg(feature = "use-pkg-config")]
+        if self.source == LinkSource::PkgConfig {
+            // the SDL3 that this library's pkg-config file requires
+            if let Ok(lib) = pkg_config::Config::new()
+                .cargo_metadata(false)
+                .env_metadata(false)
+                .probe("sdl3")
+            {
+                let mut info = Self::new(LinkSource::PkgConfig, LinkKind::Default);
+                info.lib_dir = lib.link_paths.first().map(|p| p.display().to_string());
+                info.version = Some(lib.version);
+                uses = Some(info);
+            }
+        }
+        if self.source != LinkSource::BuildFromSource && uses.is_none() {
+            // an SDL3 installed next to this library
+            uses = self
+                .lib_dir
+                .as_deref()
+                .filter(|lib_dir| !sdl.is_lib_dir(lib_dir))
+                .and_then(|lib_dir| Self::find_installed(lib_dir, "sdl3", "SDL3"));
+        }
+        let Some(uses) = uses else {
+            return Ok(());
+        };
+
+        if let (Some(lib_dir), Some(_)) = (&uses.lib_dir, &sdl.lib_dir) {
+            if !sdl.is_lib_dir(lib_dir) {
+                return Err(format!(
+                    "sdl3-sys links SDL3 ({}), but {package}-sys links {lib_name} ({}), which \
+                    uses the SDL3 in {lib_dir}. Set SDL3_SYS_LIB_DIR to the SDL3 that {lib_name} \
+                    was built with, or use the same `build-from-source` setting for both crates. \
+                    {skip}.",
+                    sdl.describe(),
+                    self.describe(),
+                )
+                .into());
+            }
+        }
+
+        if let (Some(version), Some(sdl_version)) = (&uses.version, &sdl.version) {
+            if version != sdl_version {
+                return Err(format!(
+                    "sdl3-sys links SDL3 ({}), but {package}-sys links {lib_name} ({}), which \
+                    requires SDL3 version {version}. {skip}.",
+                    sdl.describe(),
+                    self.describe(),
+                )
+                .into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
 fn config(key: &str) -> &str {
     struct Config {
         map: BTreeMap<String, String>,
@@ -235,7 +510,128 @@ fn config(key: &str) -> &str {
         .unwrap_or_else(|| panic!("config key {key} not set"))
 }
 
-#[cfg(all(feature = "build-from-source", not(feature = "link-static")))]
+// Environment variables that override the feature-driven build configuration.
+// Each is prefixed with the name of the crate in uppercase, e.g. `SDL3_SYS_LIB_DIR`
+const ENV_VARS: &[&str] = &[
+    "LIB_DIR",
+    "STATIC",
+    "BUILD_FROM_SOURCE",
+    "NO_PKG_CONFIG",
+    "SKIP_LINK_CHECK",
+    "CMAKE_DEFINES",
+];
+
+fn env_var_name(name: &str) -> String {
+    format!(
+        "{}_SYS_{name}",
+        config("package_name").to_uppercase().replace('-', "_")
+    )
+}
+
+fn env_var(name: &str) -> Option<String> {
+    debug_assert!(ENV_VARS.contains(&name));
+    env::var(env_var_name(name)).ok()
+}
+
+fn env_flag(name: &str) -> Option<bool> {
+    let value = env_var(name)?;
+    match value.to_ascii_lowercase().as_str() {
+        "1" | "on" | "true" | "yes" => Some(true),
+        "" | "0" | "off" | "false" | "no" => Some(false),
+        _ => panic!(
+            "invalid value for {}: `{value}` (expected 1 or 0)",
+            env_var_name(name)
+        ),
+    }
+}
+
+fn is_emscripten() -> bool {
+    env::var("CARGO_CFG_TARGET_OS").unwrap() == "emscripten"
+}
+
+// emscripten only supports static linking
+fn link_static() -> bool {
+    is_emscripten() || env_flag("STATIC").unwrap_or(cfg!(feature = "link-static"))
+}
+
+// Link the library from the emscripten ports, building it with embuilder if it isn't in the
+// emscripten cache yet
+fn link_emscripten_port(
+    port: &str,
+    libs: &[&str],
+    link_flags: &mut LinkFlags,
+) -> Result<LinkInfo, Box<dyn Error>> {
+    use std::{path::PathBuf, process::Command};
+
+    let cache_dir = match env::var("EM_CACHE").ok().filter(|dir| !dir.is_empty()) {
+        Some(dir) => PathBuf::from(dir),
+        None => {
+            let output = Command::new("em-config").arg("CACHE").output().map_err(|e| {
+                format!("couldn't run em-config to find the emscripten cache: {e}. Make sure the emscripten SDK is activated")
+            })?;
+            if !output.status.success() {
+                return Err(format!(
+                    "em-config failed: {}",
+                    String::from_utf8_lossy(&output.stderr).trim()
+                )
+                .into());
+            }
+            PathBuf::from(String::from_utf8(output.stdout)?.trim())
+        }
+    };
+    let lib_dir = cache_dir
+        .join("sysroot")
+        .join("lib")
+        .join("wasm32-emscripten");
+
+    if !lib_dir.join(format!("lib{}.a", libs[0])).is_file() {
+        let status = Command::new("embuilder")
+            .arg("build")
+            .arg(port)
+            .status()
+            .map_err(|e| format!("couldn't run embuilder to build the {port} port: {e}"))?;
+        if !status.success() {
+            return Err(format!(
+                "embuilder failed to build the {port} port. \
+                Enable the `build-from-source` feature to build {} from source instead",
+                config("lib_name")
+            )
+            .into());
+        }
+    }
+
+    link_flags.search_lib(lib_dir.display());
+    for lib in libs {
+        link_flags.link_static_lib(lib);
+    }
+    let mut link_info = LinkInfo::new(LinkSource::EmscriptenPort, LinkKind::Static);
+    link_info.lib_dir = Some(lib_dir.display().to_string());
+    Ok(link_info)
+}
+
+// Extra cmake variables for building from source, as `NAME=VALUE` pairs separated by whitespace
+#[cfg(feature = "build-from-source")]
+fn cmake_defines() -> Result<Vec<(String, String)>, Box<dyn Error>> {
+    let Some(defines) = env_var("CMAKE_DEFINES") else {
+        return Ok(Vec::new());
+    };
+    defines
+        .split_whitespace()
+        .map(|define| {
+            let define = define.strip_prefix("-D").unwrap_or(define);
+            match define.split_once('=') {
+                Some((name, value)) if !name.is_empty() => Ok((name.to_owned(), value.to_owned())),
+                _ => Err(format!(
+                    "invalid define in {}: `{define}` (expected NAME=VALUE)",
+                    env_var_name("CMAKE_DEFINES")
+                )
+                .into()),
+            }
+        })
+        .collect()
+}
+
+#[cfg(feature = "build-from-source")]
 // based on find_cargo_target_dir from sdl2-sys
 fn top_level_cargo_target_dir() -> std::path::PathBuf {
     use std::path::PathBuf;
@@ -256,6 +652,451 @@ fn top_level_cargo_target_dir() -> std::path::PathBuf {
     target
 }
 
+// Directory for caching libraries built from source between builds. Shared by all the -sys crates
+#[cfg(feature = "build-from-source")]
+const BUILD_CACHE_DIR_ENV_VAR: &str = "SDL3_BUILD_CACHE_DIR";
+
+#[cfg(feature = "build-from-source")]
+fn build_cache_key(link_static: bool) -> String {
+    let mut key = String::new();
+    let mut add = |name: &str, value: &str| {
+        key.push_str(name);
+        key.push('=');
+        key.push_str(value);
+        key.push('\n');
+    };
+
+    add("package", &env::var("CARGO_PKG_NAME").unwrap());
+    add("version", &env::var("CARGO_PKG_VERSION").unwrap());
+    add("revision", SOURCE_REVISION_HASH);
+    add("target", &env::var("TARGET").unwrap());
+    add("opt-level", &env::var("OPT_LEVEL").unwrap());
+    add("debug", &env::var("DEBUG").unwrap());
+    add("link-static", if link_static { "1" } else { "0" });
+    add(
+        "cmake-defines",
+        &env_var("CMAKE_DEFINES").unwrap_or_default(),
+    );
+
+    // features select subsystems, codecs, etc
+    let mut features: Vec<String> = env::vars()
+        .filter_map(|(var, _)| var.strip_prefix("CARGO_FEATURE_").map(str::to_owned))
+        .collect();
+    features.sort();
+    add("features", &features.join(","));
+
+    // satellite libraries are built against the SDL from sdl3-sys. if that was built from
+    // source, its cache key identifies it without depending on where it was built
+    match env::var("DEP_SDL3_BUILD_CACHE_KEY") {
+        Ok(sdl_key) if !sdl_key.is_empty() => add("sdl3", &sdl_key),
+        _ => {
+            for var in ["DEP_SDL3_CMAKE_DIR", "DEP_SDL3_LINK_VERSION"] {
+                add(var, &env::var(var).unwrap_or_default());
+            }
+        }
+    }
+    for var in ["CMAKE_GENERATOR", "CMAKE_TOOLCHAIN_FILE"] {
+        add(var, &env::var(var).unwrap_or_default());
+    }
+
+    let compiler = cc::Build::new().get_compiler();
+    add("cc", &compiler.path().display().to_string());
+    for arg in compiler.args() {
+        add("cc-arg", &arg.to_string_lossy());
+    }
+    if !compiler.is_like_msvc() {
+        if let Ok(output) = compiler.to_command().arg("--version").output() {
+            add("cc-version", &String::from_utf8_lossy(&output.stdout));
+        }
+    }
+
+    // 64-bit FNV-1a
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in key.bytes() {
+        hash = (hash ^ byte as u64).wrapping_mul(0x100000001b3);
+    }
+    format!("{}-{hash:016x}", config("package_name"))
+}
+
+// Variables from the cmake cache of a build from source. A copy of the cache is kept with the
+// installed library, so it's also available when the build is reused from the build cache
+#[cfg(feature = "build-from-source")]
+struct CmakeCache(BTreeMap<String, String>);
+
+#[cfg(feature = "build-from-source")]
+impl CmakeCache {
+    fn path(out_dir: &std::path::Path) -> std::path::PathBuf {
+        out_dir
+            .join("share")
+            .join(config("package_name"))
+            .join("CMakeCache.txt")
+    }
+
+    fn save(out_dir: &std::path::Path) -> std::io::Result<()> {
+        let path = Self::path(out_dir);
+        std::fs::create_dir_all(path.parent().unwrap())?;
+        std::fs::copy(out_dir.join("build").join("CMakeCache.txt"), path)?;
+        Ok(())
+    }
+
+    fn load(out_dir: &std::path::Path) -> Self {
+        let mut vars = BTreeMap::new();
+        for line in read_to_string(Self::path(out_dir))
+            .unwrap_or_default()
+            .lines()
+        {
+            if line.starts_with('#') || line.starts_with("//") {
+                continue;
+            }
+            if let Some((key, value)) = line.split_once('=') {
+                let name = key.split(':').next().unwrap();
+                vars.insert(name.to_owned(), value.to_owned());
+            }
+        }
+        Self(vars)
+    }
+
+    fn is_enabled(&self, name: &str) -> bool {
+        self.0.get(name).is_some_and(|value| {
+            matches!(
+                value.to_ascii_uppercase().as_str(),
+                "ON" | "1" | "TRUE" | "YES" | "Y"
+            )
+        })
+    }
+}
+
+// Features of the linked library that are exported as cfgs if they can be detected
+struct FeatureProbes {
+    // name of the build config header generated by the library's cmake build
+    build_config_header: &'static str,
+    probes: &'static [FeatureProbe],
+}
+
+impl FeatureProbes {
+    #[allow(unused)]
+    const NONE: Self = Self {
+        build_config_header: "",
+        probes: &[],
+    };
+}
+
+struct FeatureProbe {
+    cfg: &'static str,
+    // define in the build config header. With a `!` prefix the cfg is set if it's not defined
+    define: &'static str,
+    // cmake option to check if the build config header isn't available in a build from source
+    #[cfg_attr(not(feature = "build-from-source"), allow(dead_code))]
+    cmake_option: &'static str,
+    // pkg-config modules in `Requires.private` that imply the feature
+    requires: &'static [&'static str],
+}
+
+#[cfg(feature = "build-from-source")]
+fn save_build_config_header(out_dir: &std::path::Path) -> std::io::Result<()> {
+    fn find(dir: &std::path::Path, name: &str, depth: usize) -> Option<std::path::PathBuf> {
+        let mut subdirs = Vec::new();
+        for entry in std::fs::read_dir(dir).ok()?.flatten() {
+            let path = entry.path();
+            if entry.file_name().to_str() == Some(name) {
+                return Some(path);
+            } else if depth > 0 && path.is_dir() {
+                subdirs.push(path);
+            }
+        }
+        subdirs
+            .into_iter()
+            .find_map(|dir| find(&dir, name, depth - 1))
+    }
+
+    let name = FEATURE_PROBES.build_config_header;
+    if !name.is_empty() {
+        if let Some(header) = find(&out_dir.join("build"), name, 3) {
+            let path = out_dir.join("share").join(config("package_name"));
+            std::fs::create_dir_all(&path)?;
+            std::fs::copy(header, path.join(name))?;
+        }
+    }
+    Ok(())
+}
+
+// Detect the features in `FEATURE_PROBES` from the build config header if it can be found
+// near the library or in `include_dirs`, from the cmake cache of a build from source, or from
+// the pkg-config `Requires.private` modules. Returns the name of the source and the cfgs
+#[allow(unused_variables)]
+fn probe_features(
+    link_info: &LinkInfo,
+    include_dirs: &[std::path::PathBuf],
+    pkg_config_requires: Option<&[String]>,
+) -> Option<(&'static str, Vec<String>)> {
+    use std::path::{Path, PathBuf};
+
+    let header_name = FEATURE_PROBES.build_config_header;
+    if header_name.is_empty() {
+        return None;
+    }
+
+    let mut dirs: Vec<PathBuf> = Vec::new();
+    if let Some(lib_dir) = &link_info.lib_dir {
+        let lib_dir = Path::new(lib_dir);
+        dirs.push(lib_dir.to_owned());
+        if let Some(parent) = lib_dir.parent() {
+            dirs.push(parent.to_owned());
+        }
+    }
+    for dir in include_dirs {
+        dirs.push(dir.clone());
+        if let Some(parent) = dir.parent() {
+            dirs.push(parent.to_owned());
+        }
+    }
+    let header = dirs.iter().find_map(|dir| {
+        [
+            dir.join("share").join(config("package_name")),
+            dir.join(config("include_dir")),
+            dir.join("include").join("build_config"),
+        ]
+        .into_iter()
+        .map(|dir| dir.join(header_name))
+        .find(|path| path.is_file())
+    });
+    if let Some(header) = header {
+        println!("cargo::rerun-if-changed={}", header.display());
+        let contents = read_to_string(&header).ok()?;
+        let defines: Vec<&str> = contents
+            .lines()
+            .filter_map(|line| {
+                let mut words = line.split_whitespace();
+                (words.next() == Some("#define")).then(|| words.next())?
+            })
+            .collect();
+        let cfgs = FEATURE_PROBES
+            .probes
+            .iter()
+            .filter(|probe| match probe.define.strip_prefix('!') {
+                Some(define) => !defines.contains(&define),
+                None => defines.contains(&probe.define),
+            })
+            .map(|probe| probe.cfg.to_owned())
+            .collect();
+        return Some(("build-config", cfgs));
+    }
+
+    #[cfg(feature = "build-from-source")]
+    if let (LinkSource::BuildFromSource, Some(out_dir)) = (link_info.source, &link_info.lib_dir) {
+        let cmake_cache = CmakeCache::load(Path::new(out_dir));
+        if !cmake_cache.0.is_empty() {
+            let cfgs = FEATURE_PROBES
+                .probes
+                .iter()
+                .filter(|probe| {
+                    !probe.cmake_option.is_empty() && cmake_cache.is_enabled(probe.cmake_option)
+                })
+                .map(|probe| probe.cfg.to_owned())
+                .collect();
+            return Some(("cmake-cache", cfgs));
+        }
+    }
+
+    let requires = pkg_config_requires?;
+    let cfgs = FEATURE_PROBES
+        .probes
+        .iter()
+        .filter(|probe| {
+            probe
+                .requires
+                .iter()
+                .any(|module| requires.iter().any(|r| r == module))
+        })
+        .map(|probe| probe.cfg.to_owned())
+        .collect();
+    Some(("pkg-config", cfgs))
+}
+
+// Get the modules in the `Requires.private` field of the pkg-config file for the library
+#[cfg(feature = "use-pkg-config")]
+fn pkg_config_requires_private() -> Option<Vec<String>> {
+    let package_name = config("package_name");
+    let pc_dir = pkg_config::get_variable(package_name, "pcfiledir").ok()?;
+    let pc =
+        read_to_string(std::path::Path::new(&pc_dir).join(format!("{package_name}.pc"))).ok()?;
+    let requires = pc
+        .lines()
+        .find_map(|line| line.strip_prefix("Requires.private:"))?;
+    Some(
+        requires
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|module| {
+                module
+                    .chars()
+                    .next()
+                    .is_some_and(|c| c.is_ascii_alphabetic())
+            })
+            .map(str::to_owned)
+            .collect(),
+    )
+}
+
+// Write the licenses and revisions of the libraries that were built from source to
+// `share/licenses/<package name>` in `out_dir`. The vendored libraries that were enabled
+// are read from the cmake cache
+#[cfg(feature = "build-from-source")]
+fn write_source_licenses(out_dir: &std::path::Path) -> Result<(), Box<dyn Error>> {
+    use std::path::Path;
+
+    let cmake_cache = CmakeCache::load(out_dir);
+    let option_enabled = |name: &str| cmake_cache.is_enabled(name);
+
+    let mut licenses = vec![source_crate::LICENSE];
+    if source_crate::VENDORED_OPTION.is_some_and(option_enabled) {
+        for vendored in source_crate::VENDORED {
+            if vendored.cmake_options.is_empty()
+                || vendored.cmake_options.iter().any(|o| option_enabled(o))
+            {
+                licenses.push(vendored.license);
+            }
+        }
+    }
+
+    let mut bundle = String::new();
+    let mut revision = format!(
+        "{} ({})\n",
+        source_crate::REVISION,
+        source_crate::REVISION_HASH
+    );
+    for (i, license) in licenses.iter().enumerate() {
+        let title = format!("{} ({})", license.name, license.spdx);
+        bundle.push_str(&format!("{title}\n{}\n\n", "=".repeat(title.len())));
+        for file in license.files {
+            match read_to_string(Path::new(SOURCE_DIR).join(file)) {
+                Ok(text) => {
+                    bundle.push_str(text.trim_end());
+                    bundle.push_str("\n\n");
+                }
+                Err(e) => {
+                    println!("cargo::warning=couldn't read license file {file}: {e}");
+                }
+            }
+        }
+        if i != 0 {
+            revision.push_str(&format!("    vendored: {}\n", license.name));
+        }
+    }
+
+    let dir = out_dir
+        .join("share")
+        .join("licenses")
+        .join(config("package_name"));
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(dir.join("LICENSES.txt"), bundle)?;
+    std::fs::write(dir.join("REVISION.txt"), revision)?;
+    Ok(())
+}
+
+// Write the consolidated license bundle and revision manifest for everything that was built
+// from source to `OUT_DIR`, including those from sdl3-sys for satellite libraries
+#[cfg(feature = "build-from-source")]
+fn output_license_bundle(out_dir: &std::path::Path) -> Result<(), Box<dyn Error>> {
+    use std::path::PathBuf;
+
+    let cargo_out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    let licenses_dir = out_dir
+        .join("share")
+        .join("licenses")
+        .join(config("package_name"));
+    for (file, key) in [
+        ("LICENSES.txt", "LICENSES"),
+        ("REVISION.txt", "REVISION_MANIFEST"),
+    ] {
+        let Ok(own) = read_to_string(licenses_dir.join(file)) else {
+            println!(
+                "cargo::warning={file} not found in {}",
+                licenses_dir.display()
+            );
+            continue;
+        };
+        let mut text = env::var_os(format!("DEP_SDL3_{key}"))
+            .and_then(|path| read_to_string(path).ok())
+            .map(|text| text + "\n")
+            .unwrap_or_default();
+        text.push_str(&own);
+        let path = cargo_out_dir.join(file);
+        std::fs::write(&path, text)?;
+        println!("cargo::metadata={key}={}", path.display());
+    }
+    Ok(())
+}
+
+// Copy the installed library from `out_dir` to `cache_dir`. The cmake build directory is skipped
+#[cfg(feature = "build-from-source")]
+fn store_in_build_cache(
+    out_dir: &std::path::Path,
+    cache_dir: &std::path::Path,
+) -> Result<(), Box<dyn Error>> {
+    use std::{fs, path::Path};
+
+    fn copy_dir(src: &Path, dst: &Path, skip: &[&str]) -> std::io::Result<()> {
+        fs::create_dir_all(dst)?;
+        for entry in fs::read_dir(src)? {
+            let entry = entry?;
+            if skip.iter().any(|s| entry.file_name() == *s) {
+                continue;
+            }
+            let (src, dst) = (entry.path(), dst.join(entry.file_name()));
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() {
+                copy_dir(&src, &dst, &[])?;
+            } else {
+                #[cfg(unix)]
+                if file_type.is_symlink() {
+                    std::os::unix::fs::symlink(fs::read_link(&src)?, &dst)?;
+                    continue;
+                }
+                fs::copy(&src, &dst)?;
+            }
+        }
+        Ok(())
+    }
+
+    // pkg-config and cmake files may refer to the install prefix
+    fn relocate(dir: &Path, from: &str, to: &str) -> std::io::Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if entry.file_type()?.is_dir() {
+                relocate(&path, from, to)?;
+            } else if path
+                .extension()
+                .is_some_and(|ext| ext == "pc" || ext == "cmake")
+            {
+                if let Ok(contents) = fs::read_to_string(&path) {
+                    if contents.contains(from) {
+                        fs::write(&path, contents.replace(from, to))?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    let tmp_dir = cache_dir.with_extension(format!("tmp{}", std::process::id()));
+    let _ = fs::remove_dir_all(&tmp_dir);
+    copy_dir(out_dir, &tmp_dir, &["build"])?;
+    relocate(
+        &tmp_dir,
+        &out_dir.display().to_string(),
+        &cache_dir.display().to_string(),
+    )?;
+    if fs::rename(&tmp_dir, cache_dir).is_err() {
+        // another build may have stored the same library first
+        fs::remove_dir_all(&tmp_dir)?;
+        if !cache_dir.is_dir() {
+            return Err(format!("couldn't store build in {}", cache_dir.display()).into());
+        }
+    }
+    Ok(())
+}
+
 #[cfg(all(feature = "build-from-source", not(feature = "link-framework")))]
 fn find_and_output_cmake_dir_metadata(out_dir: &std::path::Path) -> Result<(), Box<dyn Error>> {
     use std::path::{Path, PathBuf};
@@ -281,18 +1122,329 @@ fn find_and_output_cmake_dir_metadata(out_dir: &std::path::Path) -> Result<(), B
     }
 }
 
+#[cfg(feature = "use-cmake-config")]
+// find the library from a CMake config package in `CMAKE_PREFIX_PATH`
+fn find_cmake_config_package(
+    link_kind: LinkKind,
+    link_flags: &mut LinkFlags,
+) -> Result<Option<LinkInfo>, Box<dyn Error>> {
+    use std::path::{Path, PathBuf};
+
+    // split the arguments of a cmake command into tokens
+    fn tokens(args: &str) -> Vec<String> {
+        let mut tokens = Vec::new();
+        let mut chars = args.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == '"' {
+                let mut token = String::new();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '"' => break,
+                        '\\' => token.extend(chars.next()),
+                        c => token.push(c),
+                    }
+                }
+                tokens.push(token);
+            } else if !c.is_whitespace() {
+                let mut token = String::from(c);
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() {
+                        break;
+                    }
+                    token.push(c);
+                    chars.next();
+                }
+                tokens.push(token);
+            }
+        }
+        tokens
+    }
+
+    // find the arguments of each invocation of a cmake command
+    fn commands<'a>(script: &'a str, command: &'a str) -> impl Iterator<Item = &'a str> {
+        let mut pos = 0;
+        std::iter::from_fn(move || {
+            while pos < script.len() {
+                let rest = &script[pos..];
+                pos += rest.find('\n').map_or(rest.len(), |i| i + 1);
+                let Some(args) = rest
+                    .trim_start_matches([' ', '\t'])
+                    .strip_prefix(command)
+                    .and_then(|s| s.trim_start_matches([' ', '\t']).strip_prefix('('))
+                else {
+                    continue;
+                };
+                // the arguments may span multiple lines
+                let mut in_quotes = false;
+                let mut escaped = false;
+                let end = args
+                    .char_indices()
+                    .find(|&(_, c)| {
+                        match c {
+                            _ if escaped => escaped = false,
+                            '\\' => escaped = true,
+                            '"' => in_quotes = !in_quotes,
+                            ')' if !in_quotes => return true,
+                            _ => (),
+                        }
+                        false
+                    })
+                    .map_or(args.len(), |(i, _)| i);
+                pos = script.len() - args.len() + end;
+                return Some(&args[..end]);
+            }
+            None
+        })
+    }
+
+    fn find_config_dir(lib_name: &str) -> Option<PathBuf> {
+        let config_files = [
+            format!("{lib_name}Config.cmake"),
+            format!("{}-config.cmake", lib_name.to_lowercase()),
+        ];
+        let is_config_dir = |dir: &Path| config_files.iter().any(|f| dir.join(f).is_file());
+        for prefix in env::split_paths(&env::var_os("CMAKE_PREFIX_PATH")?) {
+            let mut lib_dirs = vec![
+                prefix.join("lib"),
+                prefix.join("lib64"),
+                prefix.join("share"),
+            ];
+            if let Ok(rd) = std::fs::read_dir(prefix.join("lib")) {
+                // multiarch dirs, e.g. `lib/x86_64-linux-gnu`
+                lib_dirs.extend(rd.flatten().map(|entry| entry.path()));
+            }
+            let candidates =
+                [prefix.clone(), prefix.join("cmake")]
+                    .into_iter()
+                    .chain(lib_dirs.iter().flat_map(|dir| {
+                        [
+                            dir.join("cmake").join(lib_name),
+                            dir.join(lib_name),
+                            dir.join(lib_name).join("cmake"),
+                        ]
+                    }));
+            for dir in candidates {
+                if is_config_dir(&dir) {
+                    return Some(dir);
+                }
+            }
+        }
+        None
+    }
+
+    fn version_num(version: &str) -> Vec<u32> {
+        version.split('.').map(|s| s.parse().unwrap_or(0)).collect()
+    }
+
+    let lib_name = config("lib_name");
+    let Some(config_dir) = find_config_dir(lib_name) else {
+        return Ok(None);
+    };
+
+    let mut scripts = Vec::new();
+    if let Ok(rd) = std::fs::read_dir(&config_dir) {
+        for entry in rd.flatten() {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "cmake") {
+                if let Ok(script) = read_to_string(&path) {
+                    scripts.push((path, script));
+                }
+            }
+        }
+    }
+
+    // check the version of the package
+    let mut package_version = None;
+    for (_, script) in scripts.iter() {
+        for args in commands(script, "set") {
+            if let [var, version, ..] = &tokens(args)[..] {
+                if var != "PACKAGE_VERSION" {
+                    continue;
+                }
+                package_version = Some(version.clone());
+                if version_num(version) < version_num(config("lib_min_version")) {
+                    println!(
+                        "cargo::warning=ignoring {lib_name} {version} in {}: version {} or later is required",
+                        config_dir.display(),
+                        config("lib_min_version")
+                    );
+                    return Ok(None);
+                }
+            }
+        }
+    }
+
+    // exported targets use paths relative to `_IMPORT_PREFIX`, which is set by going up
+    // from the directory of the script that sets it. per-configuration scripts are
+    // included from that script.
+    let import_prefix = scripts.iter().find_map(|(path, script)| {
+        let count = commands(script, "get_filename_component")
+            .filter(|args| tokens(args).first().is_some_and(|t| t == "_IMPORT_PREFIX"))
+            .count();
+        (count != 0).then(|| path.ancestors().nth(count)).flatten()
+    });
+
+    // find the properties of the imported shared and static targets
+    let mut targets: BTreeMap<LinkKind, BTreeMap<String, String>> = BTreeMap::new();
+    for (_, script) in scripts.iter() {
+        for args in commands(script, "set_target_properties") {
+            let tokens = tokens(args);
+            let Some((target, props)) = tokens.split_first() else {
+                continue;
+            };
+            let kind = if *target == format!("{lib_name}::{lib_name}-shared") {
+                LinkKind::Default
+            } else if *target == format!("{lib_name}::{lib_name}-static") {
+                LinkKind::Static
+            } else {
+                continue;
+            };
+            let props = props
+                .strip_prefix(&["PROPERTIES".to_owned()])
+                .unwrap_or(props);
+            for prop in props.chunks_exact(2) {
+                let value = match import_prefix {
+                    Some(prefix) => {
+                        prop[1].replace("${_IMPORT_PREFIX}", &prefix.display().to_string())
+                    }
+                    None => prop[1].clone(),
+                };
+                targets
+                    .entry(kind)
+                    .or_default()
+                    .insert(prop[0].clone(), value);
+            }
+        }
+    }
+
+    let kind_name = |kind| match kind {
+        LinkKind::Static => "static",
+        LinkKind::Default => "shared",
+    };
+    let Some(props) = targets.remove(&link_kind) else {
+        let Some((found, _)) = targets.pop_first() else {
+            return Ok(None);
+        };
+        return Err(format!(
+            "the {lib_name} CMake package in {} has a {} library, but a {} library was \
+            requested. Use the `link-static` feature or set {} to choose the kind of library \
+            to link.",
+            config_dir.display(),
+            kind_name(found),
+            kind_name(link_kind),
+            env_var_name("STATIC"),
+        )
+        .into());
+    };
+    let location = [
+        "",
+        "_NOCONFIG",
+        "_RELEASE",
+        "_RELWITHDEBINFO",
+        "_MINSIZEREL",
+        "_DEBUG",
+    ]
+    .into_iter()
+    .find_map(|config| {
+        props
+            .get(&format!("IMPORTED_IMPLIB{config}"))
+            .or_else(|| props.get(&format!("IMPORTED_LOCATION{config}")))
+    });
+    let Some(location) = location else {
+        return Ok(None);
+    };
+    let location = Path::new(location);
+
+    fn link_path(link_flags: &mut LinkFlags, path: &Path, link_kind: LinkKind) {
+        let Some(file_name) = path.file_name().and_then(|f| f.to_str()) else {
+            return;
+        };
+        let file_name = if env::var("CARGO_CFG_TARGET_ENV").unwrap() == "msvc" {
+            file_name
+        } else {
+            file_name.strip_prefix("lib").unwrap_or(file_name)
+        };
+        let name = file_name.split('.').next().unwrap();
+        if let Some(dir) = path.parent() {
+            link_flags.search_lib(dir.display());
+        }
+        match link_kind {
+            LinkKind::Static => link_flags.link_static_lib(name),
+            LinkKind::Default => link_flags.link_lib(name),
+        }
+    }
+    link_path(link_flags, location, link_kind);
+
+    if link_kind == LinkKind::Static {
+        // dependencies of the static library
+        for lib in props
+            .get("INTERFACE_LINK_LIBRARIES")
+            .map(|libs| libs.split(';'))
+            .into_iter()
+            .flatten()
+        {
+            let lib = lib
+                .strip_prefix("$<LINK_ONLY:")
+                .and_then(|lib| lib.strip_suffix('>'))
+                .unwrap_or(lib);
+            if lib.contains("::") || lib.starts_with('$') {
+                // other cmake targets, e.g. SDL3::SDL3 for the satellite libraries,
+                // are linked by their own crates
+            } else if let Some(framework) = lib.strip_prefix("-Wl,-framework,") {
+                link_flags.link_framework(framework);
+            } else if let Some(framework) = lib.strip_prefix("-Wl,-weak_framework,") {
+                link_flags.link_weak_framework(framework);
+            } else if let Some(lib) = lib.strip_prefix("-l") {
+                link_flags.link_lib(lib);
+            } else if Path::new(lib).is_absolute() {
+                link_path(link_flags, Path::new(lib), LinkKind::Default);
+            } else if !lib.is_empty() && !lib.starts_with('-') {
+                link_flags.link_lib(lib);
+            }
+        }
+    }
+
+    println!("cargo::metadata=CMAKE_DIR={}", config_dir.display());
+    let mut link_info = LinkInfo::new(LinkSource::CmakeConfig, link_kind);
+    link_info.lib_dir = location.parent().map(|dir| dir.display().to_string());
+    link_info.version = package_version;
+    Ok(Some(link_info))
+}
+
 fn build(
     f: impl FnOnce(&mut BuildConfig) -> Result<(), Box<dyn Error>>,
 ) -> Result<(), Box<dyn Error>> {
     let _ = &f;
 
+    println!("cargo::rerun-if-changed=config.txt");
+    println!(
+        "cargo::rustc-env=LIB_MIN_VERSION={}",
+        config("lib_min_version")
+    );
+    println!("cargo::rerun-if-env-changed=DOCS_RS");
+    for name in ENV_VARS {
+        println!("cargo::rerun-if-env-changed={}", env_var_name(name));
+    }
+    #[cfg(feature = "build-from-source")]
+    println!("cargo::rerun-if-env-changed={BUILD_CACHE_DIR_ENV_VAR}");
+    #[cfg(feature = "use-cmake-config")]
+    println!("cargo::rerun-if-env-changed=CMAKE_PREFIX_PATH");
+    println!("cargo::rerun-if-env-changed=EM_CACHE");
+    for option in CMAKE_CFGS {
+        println!("cargo::rustc-check-cfg=cfg({})", option.to_lowercase());
+    }
+    for probe in FEATURE_PROBES.probes {
+        println!("cargo::rustc-check-cfg=cfg({})", probe.cfg);
+    }
+
     if cfg!(feature = "no-link") || env::var("DOCS_RS").is_ok() {
         // don't build/link with no-link feature or on docs.rs
     } else {
         let do_link = !cfg!(feature = "no-link");
         let mut link_flags = LinkFlags::default();
 
-        let link_kind = if cfg!(feature = "link-static") {
+        let link_static = link_static();
+        let link_kind = if link_static {
             LinkKind::Static
         } else {
             LinkKind::Default
@@ -300,213 +1452,319 @@ fn build(
 
         let lib_name = config("lib_name");
 
-        #[cfg(feature = "build-from-source")]
-        {
-            use rpkg_config::{Link, PkgConfig};
-            use std::path::Path;
-
-            let package_name = config("package_name");
-
-            let mut build_config = BuildConfig::new(SOURCE_DIR);
-
-            // workaround for android builds being built for the wrong arch
-            // TODO: find out why cmake/cc does the wrong thing
-            if env::var("CARGO_CFG_TARGET_OS").unwrap() == "android" {
-                let target_arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap();
-                let (abi, processor) = match target_arch.as_str() {
-                    "aarch64" => ("arm64-v8a", "arm64"),
-                    "arm" => ("armeabi-v7a", "arm"),
-                    "x86_64" => ("x86_64", "x86_64"),
-                    "x86" => ("x86", "x86"),
-                    other => (other, other),
-                };
-                build_config.define("ANDROID_ABI", abi);
-                build_config.define("CMAKE_SYSTEM_NAME", "Android");
-                build_config.define("CMAKE_SYSTEM_PROCESSOR", processor);
+        let lib_dir = env_var("LIB_DIR");
+        let build_from_source = match env_flag("BUILD_FROM_SOURCE") {
+            Some(true) if lib_dir.is_some() => {
+                return Err(format!(
+                    "both {} and {} are set",
+                    env_var_name("BUILD_FROM_SOURCE"),
+                    env_var_name("LIB_DIR")
+                )
+                .into());
             }
+            Some(true) if !cfg!(feature = "build-from-source") => {
+                return Err(format!(
+                    "{} is set, but the `build-from-source` feature isn't enabled",
+                    env_var_name("BUILD_FROM_SOURCE")
+                )
+                .into());
+            }
+            Some(value) => value,
+            None => lib_dir.is_none() && cfg!(feature = "build-from-source"),
+        };
 
-            f(&mut build_config)?;
-            let out_dir = build_config.build();
-            println!("cargo::metadata=OUT_DIR={}", out_dir.display());
-
-            if let Ok(cfg) = PkgConfig::open(
-                &out_dir.join(format!("lib/pkgconfig/{package_name}.pc")),
-            )
-            .or_else(|_| {
-                PkgConfig::open(&out_dir.join(format!("lib64/pkgconfig/{package_name}.pc")))
-            }) {
-                for link in cfg.libs_with_private(cfg!(feature = "link-static"))? {
-                    match link {
-                        Link::SearchLib(path) => {
-                            link_flags.search_lib(path.display());
-                        }
-
-                        Link::SearchFramework(path) => {
-                            link_flags.search_framework(path.display());
-                        }
-
-                        Link::Lib(path) => {
-                            if path == Path::new(lib_name) {
-                                match link_kind {
-                                    LinkKind::Static => {
-                                        if env::var("CARGO_CFG_TARGET_ENV").unwrap() == "msvc" {
-                                            link_flags
-                                                .link_static_lib(format!("{lib_name}-static"));
-                                        } else {
-                                            link_flags.link_static_lib(path.display());
-                                        }
-                                    }
-                                    LinkKind::Default => {
-                                        link_flags.link_lib(path.display());
-                                    }
-                                }
-                            } else {
-                                link_flags.link_lib(path.display());
-                            }
-                        }
-
-                        Link::Framework(path) => {
-                            link_flags.link_framework(path.display());
-                        }
+        if !build_from_source && env_var("CMAKE_DEFINES").is_some() {
+            println!(
+                "cargo::warning={} is ignored when not building from source",
+                env_var_name("CMAKE_DEFINES")
+            );
+        }
 
-                        Link::WeakFramework(path) => {
-                            link_flags.link_weak_framework(path.display());
-                        }
+        let mut link_info = LinkInfo::new(LinkSource::Default, link_kind);
+        // cfgs describing what the library was built with, if known
+        #[allow(unused_mut)]
+        let mut cfgs: Vec<String> = Vec::new();
+        // include dirs and `Requires.private` modules from pkg-config, for probing features
+        #[allow(unused_mut)]
+        let mut include_dirs: Vec<std::path::PathBuf> = Vec::new();
+        #[allow(unused_mut)]
+        let mut pkg_config_requires: Option<Vec<String>> = None;
 
-                        _ => (),
-                    };
-                }
-            } else if LINK_FRAMEWORK {
-                link_flags.search_framework(out_dir.display());
+        if let Some(lib_dir) = lib_dir {
+            // use the library in the directory set in the environment
+            link_info = LinkInfo::new(LinkSource::LibDir, link_kind);
+            link_info.lib_dir = Some(lib_dir.clone());
+            if LINK_FRAMEWORK {
+                link_flags.search_framework(&lib_dir);
                 link_flags.link_framework(lib_name);
             } else {
-                link_flags.search_lib(out_dir.display());
-                link_flags.search_lib(format!("{}/lib", out_dir.display()));
-                link_flags.search_lib(format!("{}/lib64", out_dir.display()));
+                link_flags.search_lib(&lib_dir);
                 match link_kind {
                     LinkKind::Static => link_flags.link_static_lib(lib_name),
                     LinkKind::Default => link_flags.link_lib(lib_name),
                 }
             }
+        } else if build_from_source {
+            #[cfg(feature = "build-from-source")]
+            {
+                use rpkg_config::{Link, PkgConfig};
+                use std::path::Path;
 
-            #[cfg(not(feature = "link-framework"))]
-            find_and_output_cmake_dir_metadata(&out_dir)?;
+                let package_name = config("package_name");
 
-            #[cfg(not(feature = "link-static"))]
-            {
-                // copy built library to top level target dir
-                let toplevel = top_level_cargo_target_dir();
+                let mut build_config = BuildConfig::new(SOURCE_DIR);
 
-                #[cfg(feature = "link-framework")]
-                {
-                    let wanted_framework = format!("{lib_name}.framework");
-                    if let Ok(rd) = std::fs::read_dir(out_dir) {
-                        for entry in rd {
-                            let entry = entry?;
-                            if entry.file_name().to_str() == Some(&wanted_framework) {
-                                // the framework is a directory so we can't just copy it as a file
-
-                                let target = toplevel.join(&wanted_framework);
-                                let _ = std::fs::remove_file(&target);
-
-                                #[cfg(unix)]
-                                {
-                                    std::os::unix::fs::symlink(entry.path(), &target)?;
+                // workaround for android builds being built for the wrong arch
+                // TODO: find out why cmake/cc does the wrong thing
+                if env::var("CARGO_CFG_TARGET_OS").unwrap() == "android" {
+                    let target_arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap();
+                    let (abi, processor) = match target_arch.as_str() {
+                        "aarch64" => ("arm64-v8a", "arm64"),
+                        "arm" => ("armeabi-v7a", "arm"),
+                        "x86_64" => ("x86_64", "x86_64"),
+                        "x86" => ("x86", "x86"),
+                        other => (other, other),
+                    };
+                    build_config.define("ANDROID_ABI", abi);
+                    build_config.define("CMAKE_SYSTEM_NAME", "Android");
+                    build_config.define("CMAKE_SYSTEM_PROCESSOR", processor);
+                }
+
+                let cache_key = build_cache_key(link_static);
+                println!("cargo::metadata=BUILD_CACHE_KEY={cache_key}");
+                let cache_dir = env::var_os(BUILD_CACHE_DIR_ENV_VAR)
+                    .filter(|dir| !dir.is_empty())
+                    .map(|dir| Path::new(&dir).join(&cache_key));
+                let out_dir = match cache_dir {
+                    Some(cache_dir) if cache_dir.is_dir() => {
+                        // reuse the library from an earlier build
+                        cache_dir
+                    }
+                    cache_dir => {
+                        f(&mut build_config)?;
+                        // defines from the environment override those set by features
+                        for (name, value) in cmake_defines()? {
+                            build_config.define(name, value);
+                        }
+                        let out_dir = build_config.build();
+                        CmakeCache::save(&out_dir)?;
+                        save_build_config_header(&out_dir)?;
+                        write_source_licenses(&out_dir)?;
+                        match cache_dir {
+                            Some(cache_dir) => match store_in_build_cache(&out_dir, &cache_dir) {
+                                Ok(()) => cache_dir,
+                                Err(e) => {
+                                    println!("cargo::warning=couldn't cache build: {e}");
+                                    out_dir
                                 }
-                                #[cfg(windows)]
-                                {
-                                    // this will likely fail, but let's try
-                                    let _ =
-                                        std::os::windows::fs::symlink_dir(entry.path(), &target);
+                            },
+                            None => out_dir,
+                        }
+                    }
+                };
+                println!("cargo::metadata=OUT_DIR={}", out_dir.display());
+                output_license_bundle(&out_dir)?;
+
+                let cmake_cache = CmakeCache::load(&out_dir);
+                for option in CMAKE_CFGS {
+                    if cmake_cache.is_enabled(option) {
+                        cfgs.push(option.to_lowercase());
+                    }
+                }
+
+                link_info = LinkInfo::new(
+                    LinkSource::BuildFromSource,
+                    if LINK_FRAMEWORK {
+                        LinkKind::Default
+                    } else {
+                        link_kind
+                    },
+                );
+                link_info.lib_dir = Some(out_dir.display().to_string());
+                link_info.version = LinkInfo::source_version();
+
+                if let Ok(cfg) =
+                    PkgConfig::open(&out_dir.join(format!("lib/pkgconfig/{package_name}.pc")))
+                        .or_else(|_| {
+                            PkgConfig::open(
+                                &out_dir.join(format!("lib64/pkgconfig/{package_name}.pc")),
+                            )
+                        })
+                {
+                    for link in cfg.libs_with_private(link_static)? {
+                        match link {
+                            Link::SearchLib(path) => {
+                                link_flags.search_lib(path.display());
+                            }
+
+                            Link::SearchFramework(path) => {
+                                link_flags.search_framework(path.display());
+                            }
+
+                            Link::Lib(path) => {
+                                if path == Path::new(lib_name) {
+                                    match link_kind {
+                                        LinkKind::Static => {
+                                            if env::var("CARGO_CFG_TARGET_ENV").unwrap() == "msvc" {
+                                                link_flags
+                                                    .link_static_lib(format!("{lib_name}-static"));
+                                            } else {
+                                                link_flags.link_static_lib(path.display());
+                                            }
+                                        }
+                                        LinkKind::Default => {
+                                            link_flags.link_lib(path.display());
+                                        }
+                                    }
+                                } else {
+                                    link_flags.link_lib(path.display());
                                 }
+                            }
 
-                                break;
+                            Link::Framework(path) => {
+                                link_flags.link_framework(path.display());
                             }
-                        }
+
+                            Link::WeakFramework(path) => {
+                                link_flags.link_weak_framework(path.display());
+                            }
+
+                            _ => (),
+                        };
+                    }
+                } else if LINK_FRAMEWORK {
+                    link_flags.search_framework(out_dir.display());
+                    link_flags.link_framework(lib_name);
+                } else {
+                    link_flags.search_lib(out_dir.display());
+                    link_flags.search_lib(format!("{}/lib", out_dir.display()));
+                    link_flags.search_lib(format!("{}/lib64", out_dir.display()));
+                    match link_kind {
+                        LinkKind::Static => link_flags.link_static_lib(lib_name),
+                        LinkKind::Default => link_flags.link_lib(lib_name),
                     }
                 }
+
                 #[cfg(not(feature = "link-framework"))]
-                {
-                    let wanted_dylib_base = format!("lib{lib_name}");
-                    let wanted_so_base = format!("lib{lib_name}.so");
-                    let wanted_dll = format!("{lib_name}.dll");
-                    let mut got_dylib = None;
-                    let mut got_so = None;
-
-                    let mut get_dylib = |entry: &std::fs::DirEntry| {
-                        if let Some((true, _, _)) = got_dylib {
-                            return true;
+                find_and_output_cmake_dir_metadata(&out_dir)?;
+
+                if !link_static {
+                    // copy built library to top level target dir
+                    let toplevel = top_level_cargo_target_dir();
+
+                    #[cfg(feature = "link-framework")]
+                    {
+                        let wanted_framework = format!("{lib_name}.framework");
+                        if let Ok(rd) = std::fs::read_dir(out_dir) {
+                            for entry in rd {
+                                let entry = entry?;
+                                if entry.file_name().to_str() == Some(&wanted_framework) {
+                                    // the framework is a directory so we can't just copy it as a file
+
+                                    let target = toplevel.join(&wanted_framework);
+                                    let _ = std::fs::remove_file(&target);
+
+                                    #[cfg(unix)]
+                                    {
+                                        std::os::unix::fs::symlink(entry.path(), &target)?;
+                                    }
+                                    #[cfg(windows)]
+                                    {
+                                        // this will likely fail, but let's try
+                                        let _ = std::os::windows::fs::symlink_dir(
+                                            entry.path(),
+                                            &target,
+                                        );
+                                    }
+
+                                    break;
+                                }
+                            }
                         }
-                        if let Some(filename) = entry.file_name().to_str() {
-                            if let Some(dlext) = filename.strip_prefix(&wanted_dylib_base) {
-                                if let Some(dlext) = dlext.strip_suffix(".dylib") {
-                                    if let Some(dlext) = dlext.strip_prefix('.') {
-                                        if dlext.parse::<u32>().is_ok() {
-                                            got_dylib =
+                    }
+                    #[cfg(not(feature = "link-framework"))]
+                    {
+                        let wanted_dylib_base = format!("lib{lib_name}");
+                        let wanted_so_base = format!("lib{lib_name}.so");
+                        let wanted_dll = format!("{lib_name}.dll");
+                        let mut got_dylib = None;
+                        let mut got_so = None;
+
+                        let mut get_dylib = |entry: &std::fs::DirEntry| {
+                            if let Some((true, _, _)) = got_dylib {
+                                return true;
+                            }
+                            if let Some(filename) = entry.file_name().to_str() {
+                                if let Some(dlext) = filename.strip_prefix(&wanted_dylib_base) {
+                                    if let Some(dlext) = dlext.strip_suffix(".dylib") {
+                                        if let Some(dlext) = dlext.strip_prefix('.') {
+                                            if dlext.parse::<u32>().is_ok() {
+                                                got_dylib =
+                                                    Some((true, entry.path(), filename.to_owned()));
+                                                return true;
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            false
+                        };
+
+                        let mut get_so = |entry: &std::fs::DirEntry| {
+                            if let Some((true, _, _)) = got_so {
+                                return true;
+                            }
+                            if let Some(filename) = entry.file_name().to_str() {
+                                if let Some(soext) = filename.strip_prefix(&wanted_so_base) {
+                                    if let Some(soext) = soext.strip_prefix('.') {
+                                        if soext.parse::<u32>().is_ok() {
+                                            got_so =
                                                 Some((true, entry.path(), filename.to_owned()));
                                             return true;
                                         }
+                                    } else if soext.is_empty() {
+                                        got_so = Some((false, entry.path(), filename.to_owned()));
                                     }
                                 }
                             }
-                        }
-                        false
-                    };
+                            false
+                        };
 
-                    let mut get_so = |entry: &std::fs::DirEntry| {
-                        if let Some((true, _, _)) = got_so {
-                            return true;
-                        }
-                        if let Some(filename) = entry.file_name().to_str() {
-                            if let Some(soext) = filename.strip_prefix(&wanted_so_base) {
-                                if let Some(soext) = soext.strip_prefix('.') {
-                                    if soext.parse::<u32>().is_ok() {
-                                        got_so = Some((true, entry.path(), filename.to_owned()));
-                                        return true;
-                                    }
-                                } else if soext.is_empty() {
-                                    got_so = Some((false, entry.path(), filename.to_owned()));
+                        if let Ok(rd) = std::fs::read_dir(out_dir.join("lib64")) {
+                            for entry in rd {
+                                if get_so(&entry?) {
+                                    break;
                                 }
                             }
                         }
-                        false
-                    };
-
-                    if let Ok(rd) = std::fs::read_dir(out_dir.join("lib64")) {
-                        for entry in rd {
-                            if get_so(&entry?) {
-                                break;
+                        if let Ok(rd) = std::fs::read_dir(out_dir.join("lib")) {
+                            for entry in rd {
+                                let entry = entry?;
+                                get_dylib(&entry);
+                                get_so(&entry);
                             }
                         }
-                    }
-                    if let Ok(rd) = std::fs::read_dir(out_dir.join("lib")) {
-                        for entry in rd {
-                            let entry = entry?;
-                            get_dylib(&entry);
-                            get_so(&entry);
+                        if let Some((_, dl_path, dl_fn)) = got_dylib {
+                            std::fs::copy(dl_path, toplevel.join(&dl_fn))?;
                         }
-                    }
-                    if let Some((_, dl_path, dl_fn)) = got_dylib {
-                        std::fs::copy(dl_path, toplevel.join(&dl_fn))?;
-                    }
-                    if let Some((_, so_path, so_fn)) = got_so {
-                        std::fs::copy(so_path, toplevel.join(&so_fn))?;
-                    }
-                    if let Ok(rd) = std::fs::read_dir(out_dir.join("bin")) {
-                        for entry in rd {
-                            let entry = entry?;
-                            if entry.file_name().to_str() == Some(&wanted_dll) {
-                                std::fs::copy(entry.path(), toplevel.join(&wanted_dll))?;
-                                break;
+                        if let Some((_, so_path, so_fn)) = got_so {
+                            std::fs::copy(so_path, toplevel.join(&so_fn))?;
+                        }
+                        if let Ok(rd) = std::fs::read_dir(out_dir.join("bin")) {
+                            for entry in rd {
+                                let entry = entry?;
+                                if entry.file_name().to_str() == Some(&wanted_dll) {
+                                    std::fs::copy(entry.path(), toplevel.join(&wanted_dll))?;
+                                    break;
+                                }
                             }
                         }
                     }
                 }
             }
-        }
-        #[cfg(not(feature = "build-from-source"))]
-        {
+        } else {
             if LINK_FRAMEWORK {
+                link_info = LinkInfo::new(LinkSource::Framework, LinkKind::Default);
                 // FIXME: rust doesn't support linking to xcframeworks
                 let home = env::var("HOME");
                 let link_search = |name| {
@@ -541,18 +1799,25 @@ fn build(
                     }
                 }
                 link_flags.link_framework(lib_name);
+            } else if let (true, Some((port, libs))) = (is_emscripten(), EMSCRIPTEN_PORT) {
+                link_info = link_emscripten_port(port, libs, &mut link_flags)?;
             } else {
                 #[allow(unused_mut)]
                 let mut handled = false;
 
                 #[cfg(feature = "use-pkg-config")]
-                if !handled {
+                if !handled && env_flag("NO_PKG_CONFIG") != Some(true) {
                     if let Ok(lib) = pkg_config::Config::new()
-                        .statik(cfg!(feature = "link-static"))
+                        .statik(link_static)
                         .atleast_version(config("lib_min_version"))
                         .probe(config("package_name"))
                     {
                         handled = true;
+                        link_info = LinkInfo::new(LinkSource::PkgConfig, link_kind);
+                        link_info.lib_dir = lib.link_paths.first().map(|p| p.display().to_string());
+                        link_info.version = Some(lib.version.clone());
+                        include_dirs = lib.include_paths.clone();
+                        pkg_config_requires = pkg_config_requires_private();
                         for path in lib.link_paths.iter() {
                             link_flags.search_lib(path.display());
                         }
@@ -575,9 +1840,28 @@ fn build(
                     }
                 }
 
+                #[cfg(feature = "use-cmake-config")]
+                if !handled {
+                    if let Some(info) = find_cmake_config_package(link_kind, &mut link_flags)? {
+                        handled = true;
+                        link_info = info;
+                    }
+                }
+
                 #[cfg(feature = "use-vcpkg")]
                 if !handled {
-                    handled = vcpkg::find_package(config("package_name")).is_ok();
+                    if let Ok(lib) = vcpkg::find_package(config("package_name")) {
+                        handled = true;
+                        link_info = LinkInfo::new(
+                            LinkSource::Vcpkg,
+                            if lib.is_static {
+                                LinkKind::Static
+                            } else {
+                                LinkKind::Default
+                            },
+                        );
+                        link_info.lib_dir = lib.link_paths.first().map(|p| p.display().to_string());
+                    }
                 }
 
                 if !handled {
@@ -593,6 +1877,31 @@ fn build(
             }
         }
 
+        if link_info.version.is_none() {
+            // use the version from a pkg-config file installed with the library, if any
+            link_info.version = link_info
+                .lib_dir
+                .as_deref()
+                .and_then(|lib_dir| {
+                    LinkInfo::find_installed(lib_dir, config("package_name"), lib_name)
+                })
+                .and_then(|installed| installed.version);
+        }
+        link_info.check_consistency()?;
+        link_info.send_to_cargo_metadata();
+
+        if let Some((source, probed)) =
+            probe_features(&link_info, &include_dirs, pkg_config_requires.as_deref())
+        {
+            println!("cargo::metadata=PROBE={source}");
+            cfgs.extend(probed);
+        }
+        for cfg in cfgs.iter() {
+            println!("cargo::rustc-cfg={cfg}");
+            println!("cargo::metadata={}=1", cfg.to_uppercase());
+        }
+        println!("cargo::metadata=CFGS={}", cfgs.join(","));
+
         if do_link {
             link_flags.send_to_cargo_link_flags();
         } else {
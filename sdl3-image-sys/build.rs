@@ -1,8 +1,46 @@
 #[cfg(feature = "build-from-source")]
 const SOURCE_DIR: &str = sdl3_image_src::SOURCE_DIR;
+#[cfg(feature = "build-from-source")]
+const SOURCE_REVISION_HASH: &str = sdl3_image_src::REVISION_HASH;
+#[cfg(feature = "build-from-source")]
+use sdl3_image_src as source_crate;
 
 const LINK_FRAMEWORK: bool = cfg!(feature = "link-framework");
 
+// cmake options that are exported as lowercase cfgs if they were enabled when building from source
+const CMAKE_CFGS: &[&str] = &[
+    "SDLIMAGE_VENDORED",
+    "SDLIMAGE_BACKEND_STB",
+    "SDLIMAGE_BACKEND_WIC",
+    "SDLIMAGE_BACKEND_IMAGEIO",
+    "SDLIMAGE_AVIF",
+    "SDLIMAGE_BMP",
+    "SDLIMAGE_GIF",
+    "SDLIMAGE_JPG",
+    "SDLIMAGE_JXL",
+    "SDLIMAGE_LBM",
+    "SDLIMAGE_PCX",
+    "SDLIMAGE_PNG",
+    "SDLIMAGE_PNM",
+    "SDLIMAGE_QOI",
+    "SDLIMAGE_SVG",
+    "SDLIMAGE_TGA",
+    "SDLIMAGE_TIF",
+    "SDLIMAGE_WEBP",
+    "SDLIMAGE_XCF",
+    "SDLIMAGE_XPM",
+    "SDLIMAGE_XV",
+    "SDLIMAGE_AVIF_SAVE",
+    "SDLIMAGE_JPG_SAVE",
+    "SDLIMAGE_PNG_SAVE",
+];
+
+// features of the linked library that are exported as cfgs if they can be detected
+const FEATURE_PROBES: FeatureProbes = FeatureProbes::NONE;
+
+// emscripten port and the static libraries it builds, linked for emscripten when not building from source
+const EMSCRIPTEN_PORT: Option<(&str, &[&str])> = Some(("sdl3_image", &["SDL3_image"]));
+
 include!("build-common.rs");
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -20,7 +58,7 @@ fn main() -> Result<(), Box<dyn Error>> {
                     "SDL3_image is currently missing a configuration option to build as a framework. You can download the official framework build from <https://github.com/libsdl-org/SDL_image/releases>."
                 );
                 //config.define("SDL_FRAMEWORK", "ON");
-            } else if cfg!(feature = "link-static") {
+            } else if link_static() {
                 config.define("BUILD_SHARED_LIBS", "OFF");
             }
 
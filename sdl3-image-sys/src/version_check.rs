@@ -0,0 +1,14 @@
+//! Check that the linked SDL_image library is compatible with the bindings at runtime
+//!
+//! See [`sdl3_sys::version_check`] for details.
+
+use crate::image::{IMG_Version, SDL_IMAGE_VERSION};
+pub use sdl3_sys::version_check::{ApiKind, UnavailableApi, VersionReport};
+
+/// Compare the version of the linked SDL_image library with the version of the bindings.
+///
+/// This calls [`IMG_Version()`], so SDL_image must be linked. It doesn't check SDL itself;
+/// use [`sdl3_sys::version_check::check_compatible()`] for that.
+pub fn check_compatible() -> VersionReport {
+    sdl3_sys::__version_report!("SDL_image", SDL_IMAGE_VERSION, IMG_Version(), None)
+}
@@ -195,17 +195,45 @@ fn op2(c0: char, c1: char) -> [TokenTree; 2] {
 
 struct Context {
     sdl3_main: Path,
+    flags: Vec<String>,
 }
 
 impl Context {
     pub fn new() -> Self {
         Self {
             sdl3_main: Path::from_str("::sdl3_main").unwrap(),
+            flags: Vec::new(),
         }
     }
 
-    pub fn with_main(sdl3_main: Path) -> Self {
-        Self { sdl3_main }
+    pub fn parse(attr: &mut &[TokenTree], flags: &[&str]) -> Result<Self, Error> {
+        let mut ctx = Self::new();
+        while !attr.is_empty() {
+            let arg = Ident::parse(attr)?;
+            let name = arg.to_string();
+            if name == "sdl3_main" {
+                parse_op(attr, "=")?;
+                ctx.sdl3_main = Path::parse(attr)?;
+            } else if flags.contains(&name.as_str()) {
+                ctx.flags.push(name);
+            } else if flags.is_empty() {
+                return Err(Error::new(Some(arg.span()), "expected `sdl3_main`"));
+            } else {
+                let mut expected = String::from("expected `sdl3_main`");
+                for flag in flags {
+                    expected.push_str(&format!(" or `{flag}`"));
+                }
+                return Err(Error::new(Some(arg.span()), expected));
+            }
+            if !attr.is_empty() {
+                parse_op(attr, ",")?;
+            }
+        }
+        Ok(ctx)
+    }
+
+    pub fn has_flag(&self, flag: &str) -> bool {
+        self.flags.iter().any(|f| f == flag)
     }
 
     pub fn sdl3_main_path(&self) -> TokenStream {
@@ -282,13 +310,96 @@ fn app_type_ident(name: &str) -> Ident {
     priv_ident("t", name)
 }
 
+fn app_state_type(f: &Function) -> Type {
+    if let Some(rtype) = &f.return_type {
+        if let Some(generics) = rtype.path_generics() {
+            // `Option<S>`, `AppResultWithState<S>` or `Result<S, E>`
+            if generics.params.len() == 1
+                || (generics.params.len() == 2 && rtype.is_ident("Result"))
+            {
+                if let Generic::Type(t) = &generics.params[0] {
+                    return t.clone();
+                }
+            }
+        }
+    }
+    Type::unit()
+}
+
+fn app_iterate_ac(f: &Function) -> Result<u8, Error> {
+    Ok(match f.params.len() {
+        1 => f.params[0].ty.classify()? as u8,
+        _ => 0,
+    })
+}
+
+fn app_event_ac(f: &Function) -> Result<(u8, u8), Error> {
+    Ok(match f.params.len() {
+        1 => (0, f.params[0].ty.classify()? as u8),
+        2 => (
+            f.params[0].ty.classify()? as u8,
+            f.params[1].ty.classify()? as u8,
+        ),
+        _ => (0, 0),
+    })
+}
+
+fn app_quit_ac(f: &Function) -> Result<u8, Error> {
+    Ok(match f.params.len() {
+        1 | 2 => f.params[0].ty.classify()? as u8,
+        _ => 0,
+    })
+}
+
+fn contains_impl_trait(ts: TokenStream) -> bool {
+    ts.into_iter().any(|tt| match tt {
+        TokenTree::Ident(i) => i.to_string() == "impl",
+        TokenTree::Group(g) => contains_impl_trait(g.stream()),
+        _ => false,
+    })
+}
+
+// `impl Trait` isn't allowed in fn pointer types, so if `f` returns e.g. `impl IntoAppResult`
+// or `Result<impl IntoAppResult, E>`, wrap it in a function that returns `SDL_AppResult`
+fn erase_impl_return_type(ctx: &Context, f: Function) -> Function {
+    let Some(rtype) = &f.return_type else {
+        return f;
+    };
+    if !contains_impl_trait(miniquote!(#rtype)) {
+        return f;
+    }
+    let mut wrapper = Function::new(priv_ident("fnr", &f.ident.to_string()));
+    wrapper.unsafe_kw = f.unsafe_kw.clone();
+    wrapper.params = f.params.clone();
+    for (i, param) in wrapper.params.iter_mut().enumerate() {
+        param.mut_kw = None;
+        param.ident = Ident::new(&format!("__sdl3_main_arg{i}"), Span::mixed_site());
+    }
+    wrapper.return_type = Some(Type::Other(
+        miniquote!(#{ctx.sdl3_sys_path()}::init::SDL_AppResult)
+            .into_iter()
+            .collect(),
+    ));
+    let args = wrapper.params.to_args();
+    let call = if f.unsafe_kw.is_some() {
+        miniquote!(unsafe { #{&f.ident} #args })
+    } else {
+        miniquote!(#{&f.ident} #args)
+    };
+    wrapper.body = TokenTree::Group(Group::new(
+        Delimiter::Brace,
+        miniquote!(#f #{ctx.sdl3_main_path()}::IntoAppResult::into_sdl_app_result(#call)),
+    ));
+    wrapper
+}
+
 fn app_fn(
     name: &str,
     attr: TokenStream,
     item: TokenStream,
     f: impl FnOnce(&mut TokenStream, &Context, Function) -> Result<(), Error>,
 ) -> TokenStream {
-    wrap(attr, item, |out, ctx, item| {
+    wrap(attr, item, &[], |out, ctx, item| {
         let item = Function::parse_all(item)?;
         if let Some(abi) = &item.abi {
             return Err(Error::new(
@@ -296,12 +407,17 @@ fn app_fn(
                 "this function shouldn't set an ABI",
             ));
         }
+        let raw_fn = if matches!(name, "app_iterate" | "app_event") {
+            erase_impl_return_type(ctx, item.clone())
+        } else {
+            item.clone()
+        };
         miniquote_to! { out =>
             mod #{&item.ident} {}
             #[allow(non_upper_case_globals)]
-            const #{app_raw_fn_ident(name)}: #{item.signature()} = const {
-                #{&item}
-                #{&item.ident}
+            const #{app_raw_fn_ident(name)}: #{raw_fn.signature()} = const {
+                #{&raw_fn}
+                #{&raw_fn.ident}
             };
         };
         f(out, ctx, item)
@@ -311,24 +427,13 @@ fn app_fn(
 fn wrap(
     attr: TokenStream,
     item: TokenStream,
+    flags: &[&str],
     f: impl FnOnce(&mut TokenStream, &Context, &mut &[TokenTree]) -> Result<(), Error>,
 ) -> TokenStream {
     let mut ts = TokenStream::new();
-    let ctx = if attr.is_empty() {
-        Context::new()
-    } else {
-        match (|| -> Result<Context, Error> {
-            let attr = input!(attr);
-            let arg = Ident::parse(attr)?;
-            if arg.to_string() != "sdl3_main" {
-                return Err(Error::new(Some(arg.span()), "expected `sdl3_main`"));
-            }
-            parse_op(attr, "=")?;
-            Ok(Context::with_main(Path::parse_all(attr)?))
-        })() {
-            Ok(ctx) => ctx,
-            Err(err) => return err.into_token_stream(),
-        }
+    let ctx = match Context::parse(input!(attr), flags) {
+        Ok(ctx) => ctx,
+        Err(err) => return err.into_token_stream(),
     };
     match f(&mut ts, &ctx, input!(item)) {
         Ok(()) => ts,
@@ -433,9 +538,11 @@ pub fn main(attr: TokenStream, item: TokenStream) -> TokenStream {
 
 #[proc_macro_attribute]
 pub fn app_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
-    wrap(attr, item, |out, ctx, item| {
+    wrap(attr, item, &["main_loop"], |out, ctx, item| {
         let impl_block = ImplBlock::parse_all(item)?;
         let state_t = impl_block.ty.clone();
+        let main_loop = ctx.has_flag("main_loop");
+        let mut main_loop_fns = Vec::new();
         let mut has_init = false;
         let mut has_iterate = false;
         let mut has_event = false;
@@ -505,7 +612,11 @@ pub fn app_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
                     Delimiter::Brace,
                     miniquote!(#{&state_t}::#{&f.ident} #args),
                 ));
-                miniquote_to!(out => #[#{ctx.sdl3_main_path()}::#attr(sdl3_main = #{ctx.sdl3_main_path()})] #wrapper)
+                if main_loop {
+                    main_loop_fns.push(wrapper);
+                } else {
+                    miniquote_to!(out => #[#{ctx.sdl3_main_path()}::#attr(sdl3_main = #{ctx.sdl3_main_path()})] #wrapper)
+                }
             }
         }
         if !has_init {
@@ -519,27 +630,129 @@ pub fn app_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
         }
         if !has_quit {
             let f = Function::new(Ident::new("app_quit", Span::mixed_site()));
-            miniquote_to!(out => #[#{ctx.sdl3_main_path()}::app_quit(sdl3_main = #{ctx.sdl3_main_path()})] #f);
+            if main_loop {
+                main_loop_fns.push(f);
+            } else {
+                miniquote_to!(out => #[#{ctx.sdl3_main_path()}::app_quit(sdl3_main = #{ctx.sdl3_main_path()})] #f);
+            }
+        }
+        if main_loop {
+            app_impl_main_loop(out, ctx, &state_t, main_loop_fns)?;
         }
         miniquote_to!(out => #impl_block);
         Ok(())
     })
 }
 
-#[proc_macro_attribute]
-pub fn app_init(attr: TokenStream, item: TokenStream) -> TokenStream {
-    app_fn("app_init", attr, item, |out, ctx, f| {
-        let mut state = Type::unit();
-        if let Some(rtype) = &f.return_type {
-            if let Some(generics) = rtype.path_generics() {
-                if generics.params.len() == 1 {
-                    if let Generic::Type(t) = &generics.params[0] {
-                        state = t.clone();
-                    }
+fn app_impl_main_loop(
+    out: &mut TokenStream,
+    ctx: &Context,
+    state_t: &Type,
+    fns: Vec<Function>,
+) -> Result<(), Error> {
+    let mut init = None;
+    let mut iterate = None;
+    let mut event = None;
+    let mut quit = None;
+    for f in fns {
+        match f.ident.to_string().as_str() {
+            "app_init" => init = Some(f),
+            "app_iterate" => iterate = Some(f),
+            "app_event" => event = Some(f),
+            _ => quit = Some(f),
+        }
+    }
+    let (init, iterate, event, quit) = (
+        init.unwrap(),
+        iterate.unwrap(),
+        event.unwrap(),
+        quit.unwrap(),
+    );
+
+    let state = app_state_type(&init);
+    let iterate_state_ac = app_iterate_ac(&iterate)?;
+    let (event_state_ac, event_ac) = app_event_ac(&event)?;
+    let quit_state_ac = app_quit_ac(&quit)?;
+    let iterate = erase_impl_return_type(ctx, iterate);
+    let event = erase_impl_return_type(ctx, event);
+
+    let sdl3_main = ctx.sdl3_main_path();
+    let sdl3_sys = ctx.sdl3_sys_path();
+
+    miniquote_to! { out =>
+        impl #{sdl3_main.clone()}::app::AppImpl for #state_t {
+            type State = #state;
+
+            #[inline(always)]
+            unsafe fn init(
+                main_thread: #{sdl3_main.clone()}::MainThreadToken,
+                appstate: *mut *mut ::core::ffi::c_void,
+                argc: ::core::ffi::c_int,
+                argv: *mut *mut ::core::ffi::c_char,
+            ) -> #{sdl3_sys.clone()}::init::SDL_AppResult {
+                #{init.clone()}
+                unsafe {
+                    #{sdl3_main.clone()}::app::AppInit::<Self::State>::init(
+                        #{&init.ident} as #{init.signature()},
+                        main_thread,
+                        appstate,
+                        argc,
+                        argv,
+                    )
+                }
+            }
+
+            #[inline(always)]
+            unsafe fn iterate(
+                appstate: *mut ::core::ffi::c_void,
+            ) -> #{sdl3_sys.clone()}::init::SDL_AppResult {
+                #{iterate.clone()}
+                unsafe {
+                    #{sdl3_main.clone()}::app::AppIterate::<Self::State, #iterate_state_ac>::iterate(
+                        #{&iterate.ident} as #{iterate.signature()},
+                        appstate,
+                    )
+                }
+            }
+
+            #[inline(always)]
+            unsafe fn event(
+                appstate: *mut ::core::ffi::c_void,
+                event: *mut #{sdl3_sys.clone()}::events::SDL_Event,
+            ) -> #{sdl3_sys.clone()}::init::SDL_AppResult {
+                #{event.clone()}
+                unsafe {
+                    #{sdl3_main.clone()}::app::AppEvent::<Self::State, #event_state_ac, #event_ac>::event(
+                        #{&event.ident} as #{event.signature()},
+                        appstate,
+                        event,
+                    )
+                }
+            }
+
+            #[inline(always)]
+            unsafe fn quit(
+                appstate: *mut ::core::ffi::c_void,
+                result: #{sdl3_sys.clone()}::init::SDL_AppResult,
+            ) {
+                #{quit.clone()}
+                unsafe {
+                    #{sdl3_main.clone()}::app::AppQuit::<Self::State, #quit_state_ac>::quit(
+                        #{&quit.ident} as #{quit.signature()},
+                        appstate,
+                        result,
+                    )
                 }
             }
         }
+    }
+    Ok(())
+}
 
+#[proc_macro_attribute]
+pub fn app_init(attr: TokenStream, item: TokenStream) -> TokenStream {
+    app_fn("app_init", attr, item, |out, ctx, f| {
+        let state = app_state_type(&f);
         let state_t = &app_type_ident("AppState");
 
         miniquote_to! { out =>
@@ -591,10 +804,7 @@ pub fn app_init(attr: TokenStream, item: TokenStream) -> TokenStream {
 pub fn app_iterate(attr: TokenStream, item: TokenStream) -> TokenStream {
     let name = "app_iterate";
     app_fn(name, attr, item, |out, ctx, f| {
-        let state_ac = match f.params.len() {
-            1 => f.params[0].ty.classify()? as u8,
-            _ => 0,
-        };
+        let state_ac = app_iterate_ac(&f)?;
         miniquote_to! { out =>
             unsafe extern "C" fn #{app_fn_ident(name)}(
                 appstate: *mut ::core::ffi::c_void
@@ -618,14 +828,7 @@ pub fn app_iterate(attr: TokenStream, item: TokenStream) -> TokenStream {
 pub fn app_event(attr: TokenStream, item: TokenStream) -> TokenStream {
     let name = "app_event";
     app_fn(name, attr, item, |out, ctx, f| {
-        let (state_ac, event_ac) = match f.params.len() {
-            1 => (0, f.params[0].ty.classify()? as u8),
-            2 => (
-                f.params[0].ty.classify()? as u8,
-                f.params[1].ty.classify()? as u8,
-            ),
-            _ => (0, 0),
-        };
+        let (state_ac, event_ac) = app_event_ac(&f)?;
         miniquote_to! { out =>
             unsafe extern "C" fn #{app_fn_ident(name)}(
                 appstate: *mut ::core::ffi::c_void,
@@ -651,10 +854,7 @@ pub fn app_event(attr: TokenStream, item: TokenStream) -> TokenStream {
 pub fn app_quit(attr: TokenStream, item: TokenStream) -> TokenStream {
     let name = "app_quit";
     app_fn(name, attr, item, |out, ctx, f| {
-        let state_ac = match f.params.len() {
-            1 | 2 => f.params[0].ty.classify()? as u8,
-            _ => 0,
-        };
+        let state_ac = app_quit_ac(&f)?;
         miniquote_to! { out =>
             unsafe extern "C" fn #{app_fn_ident(name)}(
                 appstate: *mut ::core::ffi::c_void,
@@ -278,6 +278,10 @@ fn read_balanced_angle_brackets_while(
                     continue;
                 }
                 '>' => {
+                    if depth == 0 {
+                        // closing bracket of an enclosing generic argument list
+                        break;
+                    }
                     depth -= 1;
                     *input = &input[1..];
                     tts.push(tt.clone());
@@ -985,6 +989,10 @@ impl Path {
         self.segments.last().unwrap().generics.as_ref()
     }
 
+    pub fn last_segment_eq(&self, cmp: &str) -> bool {
+        self.segments.last().unwrap().ident.to_string() == cmp
+    }
+
     pub fn last_segment_eq_no_gen(&self, cmp: &str) -> bool {
         let seg = &self.segments.last().unwrap();
         seg.generics.is_none() && seg.ident.to_string() == cmp
@@ -1202,6 +1210,11 @@ impl Type {
         t.last_segment_generics()
     }
 
+    pub fn is_ident(&self, ident: &str) -> bool {
+        let Type::Path(t) = self else { return false };
+        t.last_segment_eq(ident)
+    }
+
     pub fn is_ident_no_gen(&self, ident: &str) -> bool {
         let Type::Path(t) = self else { return false };
         t.last_segment_eq_no_gen(ident)
@@ -1293,9 +1306,12 @@ impl Parse for Type {
             }
 
             TokenTree::Ident(i) => {
-                if i.to_string() == "Self" {
+                let i = i.to_string();
+                if i == "Self" {
                     *input = &input[1..];
                     return Ok(Some(Type::SelfTy));
+                } else if i == "dyn" || i == "impl" {
+                    // trait object or impl trait; read it as an opaque type below
                 } else {
                     return Ok(Some(Type::Path(Path::parse(input)?)));
                 }
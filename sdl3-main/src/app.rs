@@ -1,8 +1,11 @@
 use crate::{
     AppResult, AppResultWithState, AppState, BorrowMut, BorrowRef, BorrowVal, ConsumeMut,
-    ConsumeRef, ConsumeVal, IntoAppResult, MainThreadToken,
+    ConsumeRef, ConsumeVal, IntoAppQuitResult, IntoAppResult, MainThreadToken,
+};
+use core::{
+    ffi::{c_char, c_int, c_void},
+    fmt::Debug,
 };
-use core::ffi::{c_char, c_int, c_void};
 use sdl3_sys::{events::SDL_Event, init::SDL_AppResult};
 #[cfg(feature = "std")]
 use std::process::Termination;
@@ -105,6 +108,48 @@ impl PassEventMut for SDL_Event {
     }
 }
 
+/// An app that can be run with [`run()`](crate::run()) in a classic main loop.
+///
+/// This is implemented by the [`app_impl`](crate::app_impl) attribute macro with the
+/// `main_loop` argument. It dispatches to the same callbacks, with the same supported
+/// signatures, as the SDL callback API does.
+///
+/// The methods of this trait are for internal use only and exempt from semver.
+pub trait AppImpl {
+    /// The app state type, as determined by the return type of `app_init`
+    type State: AppState;
+
+    #[doc(hidden)]
+    /// # Safety
+    /// - See the documentation for [`sdl3_sys::main::SDL_AppInit`]
+    /// - `appstate` must point to memory suitable for writing a pointer to `Self::State`
+    unsafe fn init(
+        main_thread: MainThreadToken,
+        appstate: *mut *mut c_void,
+        argc: c_int,
+        argv: *mut *mut c_char,
+    ) -> SDL_AppResult;
+
+    #[doc(hidden)]
+    /// # Safety
+    /// - See the documentation for [`sdl3_sys::main::SDL_AppIterate`]
+    /// - `appstate` must be the app state written by `init`
+    unsafe fn iterate(appstate: *mut c_void) -> SDL_AppResult;
+
+    #[doc(hidden)]
+    /// # Safety
+    /// - See the documentation for [`sdl3_sys::main::SDL_AppEvent`]
+    /// - `appstate` must be the app state written by `init`
+    /// - `event` must point to a valid [`SDL_Event`]
+    unsafe fn event(appstate: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult;
+
+    #[doc(hidden)]
+    /// # Safety
+    /// - See the documentation for [`sdl3_sys::main::SDL_AppQuit`]
+    /// - `appstate` must either be null, or be the app state written by `init`
+    unsafe fn quit(appstate: *mut c_void, result: SDL_AppResult);
+}
+
 mod sealed {
     macro_rules! traits {
         ($($trait:ident $(($($gen:tt)*))?),* $(,)?) => {
@@ -274,7 +319,8 @@ pub trait AppInit<S>: sealed::AppInit::Sealed<S> {
 }
 
 impl_app! {
-    impl<S: AppState> AppInit<S> for fn(*mut *mut c_void, c_int, *mut *mut c_char) -> SDL_AppResult
+    impl<S: AppState> AppInit<S>
+        for fn(*mut *mut c_void, c_int, *mut *mut c_char) -> SDL_AppResult
     {
         #[inline(always)]
         unsafe fn init(
@@ -288,8 +334,7 @@ impl_app! {
         }
     }
 
-    impl<S: AppState> AppInit<S> for fn(*mut *mut c_void, c_int, *mut *mut c_char) -> AppResult
-    {
+    impl<S: AppState> AppInit<S> for fn(*mut *mut c_void, c_int, *mut *mut c_char) -> AppResult {
         #[inline(always)]
         unsafe fn init(
             self,
@@ -399,6 +444,26 @@ impl_app! {
         }
     }
 
+    impl<S: AppState, E: Debug> AppInit<S> for fn() -> Result<S, E> {
+        #[inline(always)]
+        unsafe fn init(
+            self,
+            _: MainThreadToken,
+            appstate: *mut *mut c_void,
+            _argc: c_int,
+            _argv: *mut *mut c_char,
+        ) -> SDL_AppResult {
+            match unsafe { self() } {
+                Ok(s) => {
+                    unsafe { appstate.write(s.into_raw()) };
+                    SDL_AppResult::CONTINUE
+                }
+                // logs the error like `IntoAppResult` does for other callbacks
+                Err(err) => Err::<(), E>(err).into_sdl_app_result(),
+            }
+        }
+    }
+
     impl<S: AppState> AppInit<S> for fn() -> AppResultWithState<S> {
         #[inline(always)]
         unsafe fn init(
@@ -473,108 +538,182 @@ pub trait AppEvent<S, const STATE_AC: u8, const EVENT_AC: u8>:
     /// - See the documentation for [`sdl3_sys::main::SDL_AppEvent`]
     /// - `appstate` must point to a valid `S`
     /// - `event` must point to a valid [`SDL_Event`]
-    unsafe fn event(self, appstate: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult;
+    #[inline(always)]
+    unsafe fn event(self, appstate: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult {
+        let result = unsafe { self.call(appstate, event) };
+        #[cfg(feature = "alloc")]
+        unsafe {
+            // free the payload of an unhandled user event
+            crate::events::release_user_event(&*event)
+        };
+        result
+    }
+
+    #[doc(hidden)]
+    /// # Safety
+    /// Same as [`AppEvent::event`]
+    unsafe fn call(self, appstate: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult;
 }
 
 impl_app! {
     impl<S: AppState> AppEvent<S, AC_NONE, AC_NONE> for fn() {
         #[inline(always)]
-        unsafe fn event(self, _: *mut c_void, _: *mut SDL_Event) -> SDL_AppResult {
+        unsafe fn call(self, _: *mut c_void, _: *mut SDL_Event) -> SDL_AppResult {
             unsafe { self() }
             SDL_AppResult::CONTINUE
         }
     }
 
-    impl<S: BorrowVal<()>, E: PassEventVal, R: IntoAppResult> AppEvent<S, AC_NONE, AC_VAL> for fn(E) -> R {
+    impl<S: BorrowVal<()>, E: PassEventVal, R: IntoAppResult> AppEvent<S, AC_NONE, AC_VAL>
+        for fn(E) -> R
+    {
         #[inline(always)]
-        unsafe fn event(self, _: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult {
+        unsafe fn call(self, _: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult {
             unsafe { E::pass_event_val(&mut *event, |e| unsafe { self(e) }) }.into_sdl_app_result()
         }
     }
 
-    impl<S: BorrowVal<()>, E: PassEventRef, R: IntoAppResult> AppEvent<S, AC_NONE, AC_REF> for fn(&E) -> R {
+    impl<S: BorrowVal<()>, E: PassEventRef, R: IntoAppResult> AppEvent<S, AC_NONE, AC_REF>
+        for fn(&E) -> R
+    {
         #[inline(always)]
-        unsafe fn event(self, _: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult {
+        unsafe fn call(self, _: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult {
             unsafe { E::pass_event_ref(&mut *event, |e| unsafe { self(e) }) }.into_sdl_app_result()
         }
     }
 
-    impl<S: BorrowVal<()>, E: PassEventMut, R: IntoAppResult> AppEvent<S, AC_NONE, AC_MUT> for fn(&mut E) -> R {
+    impl<S: BorrowVal<()>, E: PassEventMut, R: IntoAppResult> AppEvent<S, AC_NONE, AC_MUT>
+        for fn(&mut E) -> R
+    {
         #[inline(always)]
-        unsafe fn event(self, _: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult {
+        unsafe fn call(self, _: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult {
             unsafe { E::pass_event_mut(&mut *event, |e| unsafe { self(e) }) }.into_sdl_app_result()
         }
     }
 
-    impl<S: BorrowVal<T>, T, E: PassEventVal, R: IntoAppResult> AppEvent<S, AC_VAL, AC_VAL> for fn(T, E) -> R
+    impl<S: BorrowVal<T>, T, E: PassEventVal, R: IntoAppResult> AppEvent<S, AC_VAL, AC_VAL>
+        for fn(T, E) -> R
     {
         #[inline(always)]
-        unsafe fn event(self, appstate: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult {
-            unsafe { S::borrow_val(appstate, |s| E::pass_event_val(&mut *event, |e| unsafe { self(s, e) })) }.into_sdl_app_result()
+        unsafe fn call(self, appstate: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult {
+            unsafe {
+                S::borrow_val(appstate, |s| {
+                    E::pass_event_val(&mut *event, |e| unsafe { self(s, e) })
+                })
+            }
+            .into_sdl_app_result()
         }
     }
 
-    impl<S: BorrowVal<T>, T, E: PassEventRef, R: IntoAppResult> AppEvent<S, AC_VAL, AC_REF> for fn(T, &E) -> R
+    impl<S: BorrowVal<T>, T, E: PassEventRef, R: IntoAppResult> AppEvent<S, AC_VAL, AC_REF>
+        for fn(T, &E) -> R
     {
         #[inline(always)]
-        unsafe fn event(self, appstate: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult {
-            unsafe { S::borrow_val(appstate, |s| E::pass_event_ref(&mut *event, |e| unsafe { self(s, e) })) }.into_sdl_app_result()
+        unsafe fn call(self, appstate: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult {
+            unsafe {
+                S::borrow_val(appstate, |s| {
+                    E::pass_event_ref(&mut *event, |e| unsafe { self(s, e) })
+                })
+            }
+            .into_sdl_app_result()
         }
     }
 
-    impl<S: BorrowVal<T>, T, E: PassEventMut, R: IntoAppResult> AppEvent<S, AC_VAL, AC_MUT> for fn(T, &mut E) -> R
+    impl<S: BorrowVal<T>, T, E: PassEventMut, R: IntoAppResult> AppEvent<S, AC_VAL, AC_MUT>
+        for fn(T, &mut E) -> R
     {
         #[inline(always)]
-        unsafe fn event(self, appstate: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult {
-            unsafe { S::borrow_val(appstate, |s| E::pass_event_mut(&mut *event, |e| unsafe { self(s, e) })) }.into_sdl_app_result()
+        unsafe fn call(self, appstate: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult {
+            unsafe {
+                S::borrow_val(appstate, |s| {
+                    E::pass_event_mut(&mut *event, |e| unsafe { self(s, e) })
+                })
+            }
+            .into_sdl_app_result()
         }
     }
 
-    impl<S: BorrowRef<T>, T, E: PassEventVal, R: IntoAppResult> AppEvent<S, AC_REF, AC_VAL> for fn(&T, E) -> R
+    impl<S: BorrowRef<T>, T, E: PassEventVal, R: IntoAppResult> AppEvent<S, AC_REF, AC_VAL>
+        for fn(&T, E) -> R
     {
         #[inline(always)]
-        unsafe fn event(self, appstate: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult {
-            unsafe { S::borrow_ref(appstate, |s| E::pass_event_val(&mut *event, |e| unsafe { self(s, e) })) }.into_sdl_app_result()
+        unsafe fn call(self, appstate: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult {
+            unsafe {
+                S::borrow_ref(appstate, |s| {
+                    E::pass_event_val(&mut *event, |e| unsafe { self(s, e) })
+                })
+            }
+            .into_sdl_app_result()
         }
     }
 
-    impl<S: BorrowRef<T>, T, E: PassEventRef, R: IntoAppResult> AppEvent<S, AC_REF, AC_REF> for fn(&T, &E) -> R
+    impl<S: BorrowRef<T>, T, E: PassEventRef, R: IntoAppResult> AppEvent<S, AC_REF, AC_REF>
+        for fn(&T, &E) -> R
     {
         #[inline(always)]
-        unsafe fn event(self, appstate: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult {
-            unsafe { S::borrow_ref(appstate, |s| E::pass_event_ref(&mut *event, |e| unsafe { self(s, e) })) }.into_sdl_app_result()
+        unsafe fn call(self, appstate: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult {
+            unsafe {
+                S::borrow_ref(appstate, |s| {
+                    E::pass_event_ref(&mut *event, |e| unsafe { self(s, e) })
+                })
+            }
+            .into_sdl_app_result()
         }
     }
 
-    impl<S: BorrowRef<T>, T, E: PassEventMut, R: IntoAppResult> AppEvent<S, AC_REF, AC_MUT> for fn(&T, &mut E) -> R
+    impl<S: BorrowRef<T>, T, E: PassEventMut, R: IntoAppResult> AppEvent<S, AC_REF, AC_MUT>
+        for fn(&T, &mut E) -> R
     {
         #[inline(always)]
-        unsafe fn event(self, appstate: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult {
-            unsafe { S::borrow_ref(appstate, |s| E::pass_event_mut(&mut *event, |e| unsafe { self(s, e) })) }.into_sdl_app_result()
+        unsafe fn call(self, appstate: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult {
+            unsafe {
+                S::borrow_ref(appstate, |s| {
+                    E::pass_event_mut(&mut *event, |e| unsafe { self(s, e) })
+                })
+            }
+            .into_sdl_app_result()
         }
     }
 
-    impl<S: BorrowMut<T>, T, E: PassEventVal, R: IntoAppResult> AppEvent<S, AC_MUT, AC_VAL> for fn(&mut T, E) -> R
+    impl<S: BorrowMut<T>, T, E: PassEventVal, R: IntoAppResult> AppEvent<S, AC_MUT, AC_VAL>
+        for fn(&mut T, E) -> R
     {
         #[inline(always)]
-        unsafe fn event(self, appstate: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult {
-            unsafe { S::borrow_mut(appstate, |s| E::pass_event_val(&mut *event, |e| unsafe { self(s, e) })) }.into_sdl_app_result()
+        unsafe fn call(self, appstate: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult {
+            unsafe {
+                S::borrow_mut(appstate, |s| {
+                    E::pass_event_val(&mut *event, |e| unsafe { self(s, e) })
+                })
+            }
+            .into_sdl_app_result()
         }
     }
 
-    impl<S: BorrowMut<T>, T, E: PassEventRef, R: IntoAppResult> AppEvent<S, AC_MUT, AC_REF> for fn(&mut T, &E) -> R
+    impl<S: BorrowMut<T>, T, E: PassEventRef, R: IntoAppResult> AppEvent<S, AC_MUT, AC_REF>
+        for fn(&mut T, &E) -> R
     {
         #[inline(always)]
-        unsafe fn event(self, appstate: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult {
-            unsafe { S::borrow_mut(appstate, |s| E::pass_event_ref(&mut *event, |e| unsafe { self(s, e) })) }.into_sdl_app_result()
+        unsafe fn call(self, appstate: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult {
+            unsafe {
+                S::borrow_mut(appstate, |s| {
+                    E::pass_event_ref(&mut *event, |e| unsafe { self(s, e) })
+                })
+            }
+            .into_sdl_app_result()
         }
     }
 
-    impl<S: BorrowMut<T>, T, E: PassEventMut, R: IntoAppResult> AppEvent<S, AC_MUT, AC_MUT> for fn(&mut T, &mut E) -> R
+    impl<S: BorrowMut<T>, T, E: PassEventMut, R: IntoAppResult> AppEvent<S, AC_MUT, AC_MUT>
+        for fn(&mut T, &mut E) -> R
     {
         #[inline(always)]
-        unsafe fn event(self, appstate: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult {
-            unsafe { S::borrow_mut(appstate, |s| E::pass_event_mut(&mut *event, |e| unsafe { self(s, e) })) }.into_sdl_app_result()
+        unsafe fn call(self, appstate: *mut c_void, event: *mut SDL_Event) -> SDL_AppResult {
+            unsafe {
+                S::borrow_mut(appstate, |s| {
+                    E::pass_event_mut(&mut *event, |e| unsafe { self(s, e) })
+                })
+            }
+            .into_sdl_app_result()
         }
     }
 }
@@ -590,15 +729,26 @@ pub trait AppQuit<S, const STATE_AC: u8>: sealed::AppQuit::Sealed<S, STATE_AC> {
     /// # Safety
     /// - See the documentation for [`sdl3_sys::main::SDL_AppQuit`]
     /// - `appstate` must either be null, or point to a valid `S`
-    unsafe fn quit(self, appstate: *mut c_void, result: SDL_AppResult);
+    #[inline(always)]
+    unsafe fn quit(self, appstate: *mut c_void, result: SDL_AppResult) {
+        unsafe { self.call(appstate, result) };
+        // the event queue is about to be destroyed, so free the payloads of pending user events
+        #[cfg(feature = "alloc")]
+        crate::events::release_all_user_events();
+    }
+
+    #[doc(hidden)]
+    /// # Safety
+    /// Same as [`AppQuit::quit`]
+    unsafe fn call(self, appstate: *mut c_void, result: SDL_AppResult);
 }
 
 impl_app! {
-    impl<S: AppState> AppQuit<S, AC_NONE> for fn() {
+    impl<S: AppState, R: IntoAppQuitResult> AppQuit<S, AC_NONE> for fn() -> R {
         #[inline(always)]
-        unsafe fn quit(self, appstate: *mut c_void, _result: SDL_AppResult) {
+        unsafe fn call(self, appstate: *mut c_void, _result: SDL_AppResult) {
+            unsafe { self() }.into_app_quit_result();
             unsafe {
-                self();
                 if !appstate.is_null() {
                     let _ = S::from_raw(appstate);
                 }
@@ -606,164 +756,210 @@ impl_app! {
         }
     }
 
-    impl<S: ConsumeVal<T>, T> AppQuit<S, AC_VAL> for fn(T) {
+    impl<S: ConsumeVal<T>, T, R: IntoAppQuitResult> AppQuit<S, AC_VAL> for fn(T) -> R {
         #[inline(always)]
-        unsafe fn quit(self, appstate: *mut c_void, _result: SDL_AppResult) {
+        unsafe fn call(self, appstate: *mut c_void, _result: SDL_AppResult) {
             unsafe {
-                S::consume_val(appstate, |s| unsafe { self(s) });
+                S::consume_val(appstate, |s| unsafe { self(s) }.into_app_quit_result());
             }
         }
     }
 
-    impl<S: ConsumeRef<T>, T> AppQuit<S, AC_REF> for fn(&T) {
+    impl<S: ConsumeRef<T>, T, R: IntoAppQuitResult> AppQuit<S, AC_REF> for fn(&T) -> R {
         #[inline(always)]
-        unsafe fn quit(self, appstate: *mut c_void, _result: SDL_AppResult) {
+        unsafe fn call(self, appstate: *mut c_void, _result: SDL_AppResult) {
             unsafe {
-                S::consume_ref(appstate, |s| unsafe { self(s) });
+                S::consume_ref(appstate, |s| unsafe { self(s) }.into_app_quit_result());
             }
         }
     }
 
-    impl<S: ConsumeMut<T>, T> AppQuit<S, AC_MUT> for fn(&mut T) {
+    impl<S: ConsumeMut<T>, T, R: IntoAppQuitResult> AppQuit<S, AC_MUT> for fn(&mut T) -> R {
         #[inline(always)]
-        unsafe fn quit(self, appstate: *mut c_void, _result: SDL_AppResult) {
+        unsafe fn call(self, appstate: *mut c_void, _result: SDL_AppResult) {
             unsafe {
-                S::consume_mut(appstate, |s| unsafe { self(s) });
+                S::consume_mut(appstate, |s| unsafe { self(s) }.into_app_quit_result());
             }
         }
     }
 
-    impl<S: ConsumeVal<T>, T> AppQuit<S, AC_VAL> for fn(T, SDL_AppResult) {
+    impl<S: ConsumeVal<T>, T, R: IntoAppQuitResult> AppQuit<S, AC_VAL>
+        for fn(T, SDL_AppResult) -> R
+    {
         #[inline(always)]
-        unsafe fn quit(self, appstate: *mut c_void, result: SDL_AppResult) {
+        unsafe fn call(self, appstate: *mut c_void, result: SDL_AppResult) {
             unsafe {
-                S::consume_val(appstate, |s| unsafe { self(s, result) });
+                S::consume_val(appstate, |s| {
+                    unsafe { self(s, result) }.into_app_quit_result()
+                });
             }
         }
     }
 
-    impl<S: ConsumeRef<T>, T> AppQuit<S, AC_REF> for fn(&T, SDL_AppResult) {
+    impl<S: ConsumeRef<T>, T, R: IntoAppQuitResult> AppQuit<S, AC_REF>
+        for fn(&T, SDL_AppResult) -> R
+    {
         #[inline(always)]
-        unsafe fn quit(self, appstate: *mut c_void, result: SDL_AppResult) {
+        unsafe fn call(self, appstate: *mut c_void, result: SDL_AppResult) {
             unsafe {
-                S::consume_ref(appstate, |s| unsafe { self(s, result) });
+                S::consume_ref(appstate, |s| {
+                    unsafe { self(s, result) }.into_app_quit_result()
+                });
             }
         }
     }
 
-    impl<S: ConsumeMut<T>, T> AppQuit<S, AC_MUT> for fn(&mut T, SDL_AppResult) {
+    impl<S: ConsumeMut<T>, T, R: IntoAppQuitResult> AppQuit<S, AC_MUT>
+        for fn(&mut T, SDL_AppResult) -> R
+    {
         #[inline(always)]
-        unsafe fn quit(self, appstate: *mut c_void, result: SDL_AppResult) {
+        unsafe fn call(self, appstate: *mut c_void, result: SDL_AppResult) {
             unsafe {
-                S::consume_mut(appstate, |s| unsafe { self(s, result) });
+                S::consume_mut(appstate, |s| {
+                    unsafe { self(s, result) }.into_app_quit_result()
+                });
             }
         }
     }
 
-    impl<S: ConsumeVal<T>, T> AppQuit<S, AC_VAL> for fn(T, AppResult) {
+    impl<S: ConsumeVal<T>, T, R: IntoAppQuitResult> AppQuit<S, AC_VAL> for fn(T, AppResult) -> R {
         #[inline(always)]
-        unsafe fn quit(self, appstate: *mut c_void, result: SDL_AppResult) {
+        unsafe fn call(self, appstate: *mut c_void, result: SDL_AppResult) {
             unsafe {
-                S::consume_val(appstate, |s| unsafe { self(s, result.into()) });
+                S::consume_val(appstate, |s| {
+                    unsafe { self(s, result.into()) }.into_app_quit_result()
+                });
             }
         }
     }
 
-    impl<S: ConsumeRef<T>, T> AppQuit<S, AC_REF> for fn(&T, AppResult) {
+    impl<S: ConsumeRef<T>, T, R: IntoAppQuitResult> AppQuit<S, AC_REF> for fn(&T, AppResult) -> R {
         #[inline(always)]
-        unsafe fn quit(self, appstate: *mut c_void, result: SDL_AppResult) {
+        unsafe fn call(self, appstate: *mut c_void, result: SDL_AppResult) {
             unsafe {
-                S::consume_ref(appstate, |s| unsafe { self(s, result.into()) });
+                S::consume_ref(appstate, |s| {
+                    unsafe { self(s, result.into()) }.into_app_quit_result()
+                });
             }
         }
     }
 
-    impl<S: ConsumeMut<T>, T> AppQuit<S, AC_MUT> for fn(&mut T, AppResult) {
+    impl<S: ConsumeMut<T>, T, R: IntoAppQuitResult> AppQuit<S, AC_MUT>
+        for fn(&mut T, AppResult) -> R
+    {
         #[inline(always)]
-        unsafe fn quit(self, appstate: *mut c_void, result: SDL_AppResult) {
+        unsafe fn call(self, appstate: *mut c_void, result: SDL_AppResult) {
             unsafe {
-                S::consume_mut(appstate, |s| unsafe { self(s, result.into()) });
+                S::consume_mut(appstate, |s| {
+                    unsafe { self(s, result.into()) }.into_app_quit_result()
+                });
             }
         }
     }
 
-    impl<S: ConsumeVal<T>, T> AppQuit<S, AC_OPT_VAL> for fn(Option<T>) {
+    impl<S: ConsumeVal<T>, T, R: IntoAppQuitResult> AppQuit<S, AC_OPT_VAL> for fn(Option<T>) -> R {
         #[inline(always)]
-        unsafe fn quit(self, appstate: *mut c_void, _result: SDL_AppResult) {
+        unsafe fn call(self, appstate: *mut c_void, _result: SDL_AppResult) {
             unsafe {
-                consume_opt_val::<S, T>(appstate, |s| unsafe { self(s) });
+                consume_opt_val::<S, T>(appstate, |s| unsafe { self(s) }.into_app_quit_result());
             }
         }
     }
 
-    impl<S: ConsumeRef<T>, T> AppQuit<S, AC_OPT_REF> for fn(Option<&T>) {
+    impl<S: ConsumeRef<T>, T, R: IntoAppQuitResult> AppQuit<S, AC_OPT_REF> for fn(Option<&T>) -> R {
         #[inline(always)]
-        unsafe fn quit(self, appstate: *mut c_void, _result: SDL_AppResult) {
+        unsafe fn call(self, appstate: *mut c_void, _result: SDL_AppResult) {
             unsafe {
-                consume_opt_ref::<S, T>(appstate, |s| unsafe { self(s) });
+                consume_opt_ref::<S, T>(appstate, |s| unsafe { self(s) }.into_app_quit_result());
             }
         }
     }
 
-    impl<S: ConsumeMut<T>, T> AppQuit<S, AC_OPT_MUT> for fn(Option<&mut T>) {
+    impl<S: ConsumeMut<T>, T, R: IntoAppQuitResult> AppQuit<S, AC_OPT_MUT>
+        for fn(Option<&mut T>) -> R
+    {
         #[inline(always)]
-        unsafe fn quit(self, appstate: *mut c_void, _result: SDL_AppResult) {
+        unsafe fn call(self, appstate: *mut c_void, _result: SDL_AppResult) {
             unsafe {
-                consume_opt_mut::<S, T>(appstate, |s| unsafe { self(s) });
+                consume_opt_mut::<S, T>(appstate, |s| unsafe { self(s) }.into_app_quit_result());
             }
         }
     }
 
-    impl<S: ConsumeVal<T>, T> AppQuit<S, AC_OPT_VAL> for fn(Option<T>, SDL_AppResult) {
+    impl<S: ConsumeVal<T>, T, R: IntoAppQuitResult> AppQuit<S, AC_OPT_VAL>
+        for fn(Option<T>, SDL_AppResult) -> R
+    {
         #[inline(always)]
-        unsafe fn quit(self, appstate: *mut c_void, result: SDL_AppResult) {
+        unsafe fn call(self, appstate: *mut c_void, result: SDL_AppResult) {
             unsafe {
-                consume_opt_val::<S, T>(appstate, |s| unsafe { self(s, result) });
+                consume_opt_val::<S, T>(appstate, |s| {
+                    unsafe { self(s, result) }.into_app_quit_result()
+                });
             }
         }
     }
 
-    impl<S: ConsumeRef<T>, T> AppQuit<S, AC_OPT_REF> for fn(Option<&T>, SDL_AppResult) {
+    impl<S: ConsumeRef<T>, T, R: IntoAppQuitResult> AppQuit<S, AC_OPT_REF>
+        for fn(Option<&T>, SDL_AppResult) -> R
+    {
         #[inline(always)]
-        unsafe fn quit(self, appstate: *mut c_void, result: SDL_AppResult) {
+        unsafe fn call(self, appstate: *mut c_void, result: SDL_AppResult) {
             unsafe {
-                consume_opt_ref::<S, T>(appstate, |s| unsafe { self(s, result) });
+                consume_opt_ref::<S, T>(appstate, |s| {
+                    unsafe { self(s, result) }.into_app_quit_result()
+                });
             }
         }
     }
 
-    impl<S: ConsumeMut<T>, T> AppQuit<S, AC_OPT_MUT> for fn(Option<&mut T>, SDL_AppResult) {
+    impl<S: ConsumeMut<T>, T, R: IntoAppQuitResult> AppQuit<S, AC_OPT_MUT>
+        for fn(Option<&mut T>, SDL_AppResult) -> R
+    {
         #[inline(always)]
-        unsafe fn quit(self, appstate: *mut c_void, result: SDL_AppResult) {
+        unsafe fn call(self, appstate: *mut c_void, result: SDL_AppResult) {
             unsafe {
-                consume_opt_mut::<S, T>(appstate, |s| unsafe { self(s, result) });
+                consume_opt_mut::<S, T>(appstate, |s| {
+                    unsafe { self(s, result) }.into_app_quit_result()
+                });
             }
         }
     }
 
-    impl<S: ConsumeVal<T>, T> AppQuit<S, AC_OPT_VAL> for fn(Option<T>, AppResult) {
+    impl<S: ConsumeVal<T>, T, R: IntoAppQuitResult> AppQuit<S, AC_OPT_VAL>
+        for fn(Option<T>, AppResult) -> R
+    {
         #[inline(always)]
-        unsafe fn quit(self, appstate: *mut c_void, result: SDL_AppResult) {
+        unsafe fn call(self, appstate: *mut c_void, result: SDL_AppResult) {
             unsafe {
-                consume_opt_val::<S, T>(appstate, |s| unsafe { self(s, result.into()) });
+                consume_opt_val::<S, T>(appstate, |s| {
+                    unsafe { self(s, result.into()) }.into_app_quit_result()
+                });
             }
         }
     }
 
-    impl<S: ConsumeRef<T>, T> AppQuit<S, AC_OPT_REF> for fn(Option<&T>, AppResult) {
+    impl<S: ConsumeRef<T>, T, R: IntoAppQuitResult> AppQuit<S, AC_OPT_REF>
+        for fn(Option<&T>, AppResult) -> R
+    {
         #[inline(always)]
-        unsafe fn quit(self, appstate: *mut c_void, result: SDL_AppResult) {
+        unsafe fn call(self, appstate: *mut c_void, result: SDL_AppResult) {
             unsafe {
-                consume_opt_ref::<S, T>(appstate, |s| unsafe { self(s, result.into()) });
+                consume_opt_ref::<S, T>(appstate, |s| {
+                    unsafe { self(s, result.into()) }.into_app_quit_result()
+                });
             }
         }
     }
 
-    impl<S: ConsumeMut<T>, T> AppQuit<S, AC_OPT_MUT> for fn(Option<&mut T>, AppResult) {
+    impl<S: ConsumeMut<T>, T, R: IntoAppQuitResult> AppQuit<S, AC_OPT_MUT>
+        for fn(Option<&mut T>, AppResult) -> R
+    {
         #[inline(always)]
-        unsafe fn quit(self, appstate: *mut c_void, result: SDL_AppResult) {
+        unsafe fn call(self, appstate: *mut c_void, result: SDL_AppResult) {
             unsafe {
-                consume_opt_mut::<S, T>(appstate, |s| unsafe { self(s, result.into()) });
+                consume_opt_mut::<S, T>(appstate, |s| {
+                    unsafe { self(s, result.into()) }.into_app_quit_result()
+                });
             }
         }
     }
@@ -0,0 +1,459 @@
+//! Rust futures for SDL's async I/O
+//!
+//! - [`AsyncIOReactor`]\: Owns an `SDL_AsyncIOQueue` and completes the futures of the tasks
+//!   started on it. Call [`AsyncIOReactor::poll()`] from your game loop, or
+//!   [`AsyncIOReactor::wait()`] from a dedicated thread.
+//! - [`AsyncFile`]\: A file opened for async I/O, with methods returning futures.
+//! - [`AsyncIOFuture`]\: A future for the outcome of an async I/O task. It works with any
+//!   executor.
+//!
+//! Buffers are owned by the task until SDL is done with them, so dropping a future before
+//! the task completes is safe.
+//!
+//! Example:
+//! ```rust,ignore
+//! use sdl3_main::asyncio::AsyncIOReactor;
+//!
+//! let reactor = AsyncIOReactor::new().ok_or("couldn't create async I/O queue")?;
+//! let level = reactor.load_file(c"level1.dat");
+//! // ... spawn `level` on your executor, and call `reactor.poll()` once per frame
+//! ```
+
+use crate::events::SpinLock;
+use alloc::{sync::Arc, vec::Vec};
+use core::{
+    ffi::{c_void, CStr},
+    fmt::{self, Display, Formatter},
+    future::Future,
+    mem,
+    ops::{Deref, DerefMut},
+    pin::Pin,
+    ptr, slice,
+    sync::atomic::{AtomicUsize, Ordering},
+    task::{Context, Poll, Waker},
+};
+use sdl3_sys::{
+    asyncio::{
+        SDL_AsyncIO, SDL_AsyncIOFromFile, SDL_AsyncIOOutcome, SDL_AsyncIOQueue, SDL_AsyncIOResult,
+        SDL_CloseAsyncIO, SDL_CreateAsyncIOQueue, SDL_DestroyAsyncIOQueue, SDL_GetAsyncIOResult,
+        SDL_GetAsyncIOSize, SDL_LoadFileAsync, SDL_ReadAsyncIO, SDL_SignalAsyncIOQueue,
+        SDL_WaitAsyncIOResult, SDL_WriteAsyncIO,
+    },
+    stdinc::SDL_free,
+};
+
+/// An error from an async I/O task.
+///
+/// Call [`SDL_GetError`](sdl3_sys::error::SDL_GetError) for more information if the task
+/// failed to start. SDL doesn't report why a task that started failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AsyncIOError {
+    /// The task failed
+    Failed,
+
+    /// The task was canceled
+    Canceled,
+}
+
+impl Display for AsyncIOError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Failed => "async I/O task failed",
+            Self::Canceled => "async I/O task was canceled",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AsyncIOError {}
+
+/// The contents of a file loaded with [`AsyncIOReactor::load_file()`]. The data is allocated
+/// by SDL, and is freed with `SDL_free` when this is dropped.
+pub struct LoadedFile {
+    ptr: *mut u8,
+    len: usize,
+}
+
+// The data is exclusively owned
+unsafe impl Send for LoadedFile {}
+unsafe impl Sync for LoadedFile {}
+
+impl LoadedFile {
+    /// Release ownership of the data and return a pointer to it, and its length. SDL adds a
+    /// zero byte after the data, not included in the length. Free it with `SDL_free`.
+    #[inline]
+    pub fn into_raw(self) -> (*mut u8, usize) {
+        let raw = (self.ptr, self.len);
+        mem::forget(self);
+        raw
+    }
+}
+
+impl Deref for LoadedFile {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        if self.ptr.is_null() {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.ptr, self.len) }
+        }
+    }
+}
+
+impl DerefMut for LoadedFile {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        if self.ptr.is_null() {
+            &mut []
+        } else {
+            unsafe { slice::from_raw_parts_mut(self.ptr, self.len) }
+        }
+    }
+}
+
+impl Drop for LoadedFile {
+    fn drop(&mut self) {
+        unsafe { SDL_free(self.ptr as *mut c_void) };
+    }
+}
+
+enum Buffer {
+    None,
+    Owned(Vec<u8>),
+    Loaded(LoadedFile),
+}
+
+struct TaskState {
+    result: Option<Result<u64, AsyncIOError>>,
+    buffer: Buffer,
+    waker: Option<Waker>,
+}
+
+// Shared between the future and the reactor. A reference is passed to SDL as userdata, and
+// taken back by the reactor when the task completes
+struct Task {
+    state: SpinLock<TaskState>,
+}
+
+impl Task {
+    fn new(buffer: Buffer) -> Arc<Self> {
+        Arc::new(Self {
+            state: SpinLock::new(TaskState {
+                result: None,
+                buffer,
+                waker: None,
+            }),
+        })
+    }
+
+    fn complete(&self, result: Result<u64, AsyncIOError>, loaded: Option<LoadedFile>) {
+        let waker = self.state.with(|state| {
+            state.result = Some(result);
+            if let Some(loaded) = loaded {
+                state.buffer = Buffer::Loaded(loaded);
+            }
+            state.waker.take()
+        });
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// A future for the outcome of an async I/O task, created by [`AsyncIOReactor`] or
+/// [`AsyncFile`].
+///
+/// The future is completed by [`AsyncIOReactor::poll()`] or [`AsyncIOReactor::wait()`].
+/// Dropping it doesn't cancel the task.
+#[must_use = "futures do nothing unless polled"]
+pub struct AsyncIOFuture<T> {
+    task: Arc<Task>,
+    finish: fn(u64, Buffer) -> T,
+}
+
+impl<T> Future for AsyncIOFuture<T> {
+    type Output = Result<T, AsyncIOError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // cloning and dropping wakers runs foreign code that may panic, so do it outside the
+        // lock. After this, `waker` holds the replaced waker, or the clone if it wasn't stored
+        let mut waker = Some(cx.waker().clone());
+        let ready = self.task.state.with(|state| match state.result.take() {
+            Some(result) => Some((result, mem::replace(&mut state.buffer, Buffer::None))),
+            None => {
+                mem::swap(&mut state.waker, &mut waker);
+                None
+            }
+        });
+        drop(waker);
+        match ready {
+            Some((result, buffer)) => Poll::Ready(result.map(|n| (self.finish)(n, buffer))),
+            None => Poll::Pending,
+        }
+    }
+}
+
+/// Owns an `SDL_AsyncIOQueue`, and completes the futures of tasks started on it.
+///
+/// Futures only complete when the reactor processes their outcome, so either call
+/// [`poll()`](Self::poll) regularly, e.g. once per frame, or run a thread that calls
+/// [`wait()`](Self::wait) in a loop. The reactor can be shared between threads with `Arc`.
+///
+/// Dropping the reactor blocks until all pending tasks have completed.
+pub struct AsyncIOReactor {
+    queue: *mut SDL_AsyncIOQueue,
+    pending: AtomicUsize,
+}
+
+// SDL_AsyncIOQueue is thread safe
+unsafe impl Send for AsyncIOReactor {}
+unsafe impl Sync for AsyncIOReactor {}
+
+impl AsyncIOReactor {
+    /// Create a new reactor with its own queue.
+    ///
+    /// Returns `None` on failure; call [`SDL_GetError`](sdl3_sys::error::SDL_GetError) for
+    /// more information.
+    pub fn new() -> Option<Self> {
+        let queue = unsafe { SDL_CreateAsyncIOQueue() };
+        (!queue.is_null()).then(|| Self {
+            queue,
+            pending: AtomicUsize::new(0),
+        })
+    }
+
+    /// Get the queue owned by this reactor.
+    ///
+    /// Don't start tasks on this queue directly; the reactor expects the userdata of every
+    /// outcome to be one of its own tasks.
+    #[inline(always)]
+    pub fn as_raw(&self) -> *mut SDL_AsyncIOQueue {
+        self.queue
+    }
+
+    /// Get the number of tasks that have been started but not processed yet.
+    #[inline]
+    pub fn pending(&self) -> usize {
+        self.pending.load(Ordering::Acquire)
+    }
+
+    /// Process all outcomes that are available without blocking, and wake their futures.
+    /// Returns the number of outcomes processed.
+    pub fn poll(&self) -> usize {
+        let mut count = 0;
+        let mut outcome = SDL_AsyncIOOutcome::default();
+        while unsafe { SDL_GetAsyncIOResult(self.queue, &mut outcome) } {
+            unsafe { self.process(&outcome) };
+            count += 1;
+        }
+        count
+    }
+
+    /// Block until an outcome is available or `timeout_ms` milliseconds have passed, then
+    /// process it and wake its future. A negative timeout waits forever. Returns true if an
+    /// outcome was processed.
+    ///
+    /// This returns early without processing anything if [`signal()`](Self::signal) is
+    /// called from another thread.
+    pub fn wait(&self, timeout_ms: i32) -> bool {
+        let mut outcome = SDL_AsyncIOOutcome::default();
+        if unsafe { SDL_WaitAsyncIOResult(self.queue, &mut outcome, timeout_ms) } {
+            unsafe { self.process(&outcome) };
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Wake all threads blocking in [`wait()`](Self::wait), e.g. to let a dedicated
+    /// thread check if it should stop.
+    #[inline]
+    pub fn signal(&self) {
+        unsafe { SDL_SignalAsyncIOQueue(self.queue) };
+    }
+
+    /// Load all the data from a file asynchronously. See [`SDL_LoadFileAsync`].
+    pub fn load_file(&self, path: &CStr) -> AsyncIOFuture<LoadedFile> {
+        self.start(
+            Buffer::None,
+            |_, buffer| match buffer {
+                Buffer::Loaded(loaded) => loaded,
+                _ => LoadedFile {
+                    ptr: ptr::null_mut(),
+                    len: 0,
+                },
+            },
+            |userdata| unsafe { SDL_LoadFileAsync(path.as_ptr(), self.queue, userdata) },
+        )
+    }
+
+    /// Open a file for async I/O. See [`SDL_AsyncIOFromFile`] for the supported modes.
+    ///
+    /// Returns `None` on failure; call [`SDL_GetError`](sdl3_sys::error::SDL_GetError) for
+    /// more information.
+    pub fn open(&self, path: &CStr, mode: &CStr) -> Option<AsyncFile<'_>> {
+        let asyncio = unsafe { SDL_AsyncIOFromFile(path.as_ptr(), mode.as_ptr()) };
+        (!asyncio.is_null()).then_some(AsyncFile {
+            asyncio,
+            reactor: self,
+        })
+    }
+
+    fn start<T>(
+        &self,
+        buffer: Buffer,
+        finish: fn(u64, Buffer) -> T,
+        start: impl FnOnce(*mut c_void) -> bool,
+    ) -> AsyncIOFuture<T> {
+        let task = Task::new(buffer);
+        let userdata = Arc::into_raw(task.clone()) as *mut c_void;
+        self.pending.fetch_add(1, Ordering::AcqRel);
+        if !start(userdata) {
+            self.pending.fetch_sub(1, Ordering::AcqRel);
+            drop(unsafe { Arc::from_raw(userdata as *const Task) });
+            task.complete(Err(AsyncIOError::Failed), None);
+        }
+        AsyncIOFuture { task, finish }
+    }
+
+    unsafe fn process(&self, outcome: &SDL_AsyncIOOutcome) {
+        let task = unsafe { Arc::from_raw(outcome.userdata as *const Task) };
+        self.pending.fetch_sub(1, Ordering::AcqRel);
+        let result = match outcome.result {
+            SDL_AsyncIOResult::COMPLETE => Ok(outcome.bytes_transferred),
+            SDL_AsyncIOResult::CANCELED => Err(AsyncIOError::Canceled),
+            _ => Err(AsyncIOError::Failed),
+        };
+        // tasks without a buffer of their own are loads, where SDL allocated the buffer, or
+        // closes, where the buffer is null
+        let loaded = task
+            .state
+            .with(|state| matches!(state.buffer, Buffer::None))
+            .then(|| LoadedFile {
+                ptr: outcome.buffer as *mut u8,
+                len: outcome.bytes_transferred as usize,
+            });
+        task.complete(result, loaded);
+    }
+}
+
+impl Drop for AsyncIOReactor {
+    fn drop(&mut self) {
+        // SDL_DestroyAsyncIOQueue would discard the outcomes, leaking their tasks
+        while self.pending() != 0 {
+            self.wait(-1);
+        }
+        unsafe { SDL_DestroyAsyncIOQueue(self.queue) };
+    }
+}
+
+/// A file opened for async I/O with [`AsyncIOReactor::open()`].
+///
+/// Tasks started on the file complete through the reactor it was opened with.
+/// The file is closed without flushing when this is dropped; use [`close()`](Self::close)
+/// to flush it or to check for errors.
+pub struct AsyncFile<'a> {
+    asyncio: *mut SDL_AsyncIO,
+    reactor: &'a AsyncIOReactor,
+}
+
+// SDL_AsyncIO is thread safe
+unsafe impl Send for AsyncFile<'_> {}
+unsafe impl Sync for AsyncFile<'_> {}
+
+impl AsyncFile<'_> {
+    /// Get the wrapped `SDL_AsyncIO`.
+    #[inline(always)]
+    pub fn as_raw(&self) -> *mut SDL_AsyncIO {
+        self.asyncio
+    }
+
+    /// Get the size of the file. See [`SDL_GetAsyncIOSize`].
+    ///
+    /// Returns `None` on failure; call [`SDL_GetError`](sdl3_sys::error::SDL_GetError) for
+    /// more information.
+    pub fn size(&self) -> Option<u64> {
+        u64::try_from(unsafe { SDL_GetAsyncIOSize(self.asyncio) }).ok()
+    }
+
+    /// Read `buf.len()` bytes starting at `offset` into `buf`. The future returns the buffer,
+    /// truncated to the number of bytes that were read. See [`SDL_ReadAsyncIO`].
+    pub fn read(&self, mut buf: Vec<u8>, offset: u64) -> AsyncIOFuture<Vec<u8>> {
+        let (ptr, len) = (buf.as_mut_ptr(), buf.len() as u64);
+        self.reactor.start(
+            Buffer::Owned(buf),
+            |n, buffer| match buffer {
+                Buffer::Owned(mut buf) => {
+                    buf.truncate(n as usize);
+                    buf
+                }
+                _ => Vec::new(),
+            },
+            |userdata| unsafe {
+                SDL_ReadAsyncIO(
+                    self.asyncio,
+                    ptr as *mut c_void,
+                    offset,
+                    len,
+                    self.reactor.queue,
+                    userdata,
+                )
+            },
+        )
+    }
+
+    /// Write all of `buf` to the file starting at `offset`. The future returns the number of
+    /// bytes that were written. See [`SDL_WriteAsyncIO`].
+    pub fn write(&self, mut buf: Vec<u8>, offset: u64) -> AsyncIOFuture<u64> {
+        let (ptr, len) = (buf.as_mut_ptr(), buf.len() as u64);
+        self.reactor.start(
+            Buffer::Owned(buf),
+            |n, _| n,
+            |userdata| unsafe {
+                SDL_WriteAsyncIO(
+                    self.asyncio,
+                    ptr as *mut c_void,
+                    offset,
+                    len,
+                    self.reactor.queue,
+                    userdata,
+                )
+            },
+        )
+    }
+
+    /// Close the file after all pending tasks on it have completed, optionally flushing it
+    /// to physical storage first. See [`SDL_CloseAsyncIO`].
+    pub fn close(self, flush: bool) -> AsyncIOFuture<()> {
+        let this = mem::ManuallyDrop::new(self);
+        let mut started = false;
+        let future = this.reactor.start(
+            Buffer::None,
+            |_, _| (),
+            |userdata| {
+                started =
+                    unsafe { SDL_CloseAsyncIO(this.asyncio, flush, this.reactor.queue, userdata) };
+                started
+            },
+        );
+        if !started {
+            // try again without waiting for the result
+            drop(mem::ManuallyDrop::into_inner(this));
+        }
+        future
+    }
+}
+
+impl Drop for AsyncFile<'_> {
+    fn drop(&mut self) {
+        drop(self.reactor.start(
+            Buffer::None,
+            |_, _| (),
+            |userdata| unsafe {
+                SDL_CloseAsyncIO(self.asyncio, false, self.reactor.queue, userdata)
+            },
+        ));
+    }
+}
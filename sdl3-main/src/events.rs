@@ -0,0 +1,576 @@
+//! Helpers for distributing events to multiple independent handlers.
+//!
+//! - [`EventRouter`]\: Dispatch events to handlers registered for ranges of event types,
+//!   in priority order. Call [`EventRouter::dispatch()`] from `app_event`.
+//! - [`EventWatch`]\: Safe wrapper for [`SDL_AddEventWatch`]. The watch is removed on drop.
+//! - [`EventFilter`]\: Safe wrapper for [`SDL_SetEventFilter`]. The filter is removed on drop.
+//! - [`UserEvent`]\: Typed user events with payloads that are freed automatically.
+//! - [`replay`]\: Record input events and play them back.
+
+use alloc::{boxed::Box, collections::BTreeMap, sync::Arc, vec::Vec};
+use core::{
+    cell::UnsafeCell,
+    ffi::c_void,
+    ops::{RangeFull, RangeInclusive},
+    ptr,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+use sdl3_sys::{
+    atomic::{SDL_LockSpinlock, SDL_SpinLock, SDL_UnlockSpinlock},
+    events::{
+        SDL_AddEventWatch, SDL_Event, SDL_EventFilter, SDL_EventType, SDL_GetEventFilter,
+        SDL_PeepEvents, SDL_PushEvent, SDL_RegisterEvents, SDL_RemoveEventWatch,
+        SDL_SetEventFilter, SDL_UserEvent, SDL_EVENT_USER, SDL_GETEVENT,
+    },
+};
+
+pub mod replay;
+
+/// Whether an event should be passed on to lower priority handlers or not.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Propagation {
+    /// Pass the event on to the next handler
+    Continue,
+
+    /// Consume the event. Lower priority handlers won't see it
+    Stop,
+}
+
+/// An inclusive range of event types, for registering event handlers with [`EventRouter`]
+/// and for [`flush_events()`].
+///
+/// This can be converted from an [`SDL_EventType`], an inclusive range of `SDL_EventType`s,
+/// or `..` for all event types.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EventTypeRange {
+    first: u32,
+    last: u32,
+}
+
+impl EventTypeRange {
+    /// All event types
+    pub const ALL: Self = Self {
+        first: 0,
+        last: u32::MAX,
+    };
+
+    /// Create a new range from `first` to `last`, inclusive
+    #[inline(always)]
+    pub const fn new(first: SDL_EventType, last: SDL_EventType) -> Self {
+        Self {
+            first: first.0,
+            last: last.0,
+        }
+    }
+
+    /// Check if an event type is in this range
+    #[inline(always)]
+    pub const fn contains(&self, event_type: u32) -> bool {
+        self.first <= event_type && event_type <= self.last
+    }
+}
+
+impl From<SDL_EventType> for EventTypeRange {
+    #[inline(always)]
+    fn from(value: SDL_EventType) -> Self {
+        Self::new(value, value)
+    }
+}
+
+impl From<RangeInclusive<SDL_EventType>> for EventTypeRange {
+    #[inline(always)]
+    fn from(value: RangeInclusive<SDL_EventType>) -> Self {
+        Self::new(*value.start(), *value.end())
+    }
+}
+
+impl From<RangeFull> for EventTypeRange {
+    #[inline(always)]
+    fn from(_: RangeFull) -> Self {
+        Self::ALL
+    }
+}
+
+/// Id of a handler registered with [`EventRouter::add_handler()`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EventHandlerId(u64);
+
+struct Handler {
+    id: EventHandlerId,
+    types: EventTypeRange,
+    priority: i32,
+    handler: Box<dyn FnMut(&mut SDL_Event) -> Propagation + Send>,
+}
+
+/// Dispatches events to multiple handlers, each registered for a range of event types.
+///
+/// Handlers are called in order of descending priority. Handlers with the same priority are
+/// called in the order they were added. A handler can consume an event by returning
+/// [`Propagation::Stop`], in which case handlers after it won't see the event.
+///
+/// The router doesn't receive events by itself. Store it in your app state and call
+/// [`EventRouter::dispatch()`] from `app_event`.
+///
+/// Example:
+/// ```rust
+/// use sdl3_main::events::{EventRouter, Propagation};
+/// use sdl3_sys::events::{SDL_EVENT_KEY_DOWN, SDL_EVENT_KEY_UP};
+///
+/// let mut router = EventRouter::new();
+/// router.add_handler(SDL_EVENT_KEY_DOWN..=SDL_EVENT_KEY_UP, 10, |_event| {
+///     // handle input, and hide it from the rest
+///     Propagation::Stop
+/// });
+/// router.add_handler(.., 0, |_event| {
+///     // sees every event that wasn't consumed above
+///     Propagation::Continue
+/// });
+/// ```
+#[derive(Default)]
+pub struct EventRouter {
+    handlers: Vec<Handler>,
+    next_id: u64,
+}
+
+impl EventRouter {
+    /// Create a new router with no handlers
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            handlers: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Add a handler for events in `types`. Handlers with higher `priority` are called first.
+    ///
+    /// Returns an id that can be used to remove the handler again.
+    pub fn add_handler(
+        &mut self,
+        types: impl Into<EventTypeRange>,
+        priority: i32,
+        handler: impl FnMut(&mut SDL_Event) -> Propagation + Send + 'static,
+    ) -> EventHandlerId {
+        let id = EventHandlerId(self.next_id);
+        self.next_id += 1;
+        let index = self.handlers.partition_point(|h| h.priority >= priority);
+        self.handlers.insert(
+            index,
+            Handler {
+                id,
+                types: types.into(),
+                priority,
+                handler: Box::new(handler),
+            },
+        );
+        id
+    }
+
+    /// Remove a handler. Returns false if it was already removed.
+    pub fn remove_handler(&mut self, id: EventHandlerId) -> bool {
+        if let Some(index) = self.handlers.iter().position(|h| h.id == id) {
+            self.handlers.remove(index);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns true if there are no handlers
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.handlers.is_empty()
+    }
+
+    /// Pass an event to the handlers registered for its type.
+    ///
+    /// Returns [`Propagation::Stop`] if a handler consumed the event.
+    pub fn dispatch(&mut self, event: &mut SDL_Event) -> Propagation {
+        let event_type = unsafe { event.r#type };
+        for handler in self.handlers.iter_mut() {
+            if handler.types.contains(event_type) && (handler.handler)(event) == Propagation::Stop {
+                return Propagation::Stop;
+            }
+        }
+        Propagation::Continue
+    }
+}
+
+// Callbacks may be called from any thread, and the watch or filter may be dropped from
+// inside its own callback. The callback holds a strong reference for the duration of the call
+// so the closure stays alive until it returns.
+struct Callback<F>(F);
+
+impl<F> Callback<F> {
+    fn into_raw(self) -> *mut c_void {
+        Arc::into_raw(Arc::new(self)) as *mut c_void
+    }
+
+    /// # Safety
+    /// `userdata` must come from `into_raw` with the same `F` and still have a strong reference
+    unsafe fn call<R>(userdata: *mut c_void, f: impl FnOnce(&F) -> R) -> R {
+        let userdata = userdata as *const Self;
+        let callback = unsafe {
+            Arc::increment_strong_count(userdata);
+            Arc::from_raw(userdata)
+        };
+        f(&callback.0)
+    }
+
+    /// # Safety
+    /// `userdata` must come from `into_raw` with the same `F`, and this must only be called once
+    unsafe fn release(userdata: *mut c_void) {
+        drop(unsafe { Arc::from_raw(userdata as *const Self) });
+    }
+}
+
+/// A callback that's called when an event is added to the event queue, registered with
+/// [`SDL_AddEventWatch`]. The callback is removed when this is dropped.
+///
+/// The callback may be called on any thread, and must not panic.
+#[must_use = "the event watch is removed when this is dropped"]
+pub struct EventWatch {
+    callback: SDL_EventFilter,
+    userdata: *mut c_void,
+    release: unsafe fn(*mut c_void),
+}
+
+unsafe impl Send for EventWatch {}
+unsafe impl Sync for EventWatch {}
+
+impl EventWatch {
+    /// Add an event watch. Returns `None` if SDL failed to add it; call
+    /// [`SDL_GetError`](sdl3_sys::error::SDL_GetError) for more information.
+    pub fn new<F: Fn(&SDL_Event) + Send + Sync + 'static>(callback: F) -> Option<Self> {
+        unsafe extern "C" fn watch<F: Fn(&SDL_Event)>(
+            userdata: *mut c_void,
+            event: *mut SDL_Event,
+        ) -> bool {
+            unsafe { Callback::<F>::call(userdata, |f| f(&*event)) };
+            true
+        }
+        let userdata = Callback(callback).into_raw();
+        if unsafe { SDL_AddEventWatch(Some(watch::<F>), userdata) } {
+            Some(Self {
+                callback: Some(watch::<F>),
+                userdata,
+                release: Callback::<F>::release,
+            })
+        } else {
+            unsafe { Callback::<F>::release(userdata) };
+            None
+        }
+    }
+}
+
+impl Drop for EventWatch {
+    fn drop(&mut self) {
+        unsafe {
+            SDL_RemoveEventWatch(self.callback, self.userdata);
+            (self.release)(self.userdata);
+        }
+    }
+}
+
+/// A filter that's called before an event is added to the event queue, set with
+/// [`SDL_SetEventFilter`]. If the filter returns false, the event is dropped.
+///
+/// SDL only supports one event filter at a time, so setting a new filter replaces the
+/// current one. The filter is removed when this is dropped, unless it has been replaced.
+///
+/// The callback may be called on any thread, and must not panic.
+#[must_use = "the event filter is removed when this is dropped"]
+pub struct EventFilter {
+    userdata: *mut c_void,
+    release: unsafe fn(*mut c_void),
+}
+
+unsafe impl Send for EventFilter {}
+unsafe impl Sync for EventFilter {}
+
+impl EventFilter {
+    /// Set the event filter, replacing the current one.
+    pub fn set<F: Fn(&mut SDL_Event) -> bool + Send + Sync + 'static>(callback: F) -> Self {
+        unsafe extern "C" fn filter<F: Fn(&mut SDL_Event) -> bool>(
+            userdata: *mut c_void,
+            event: *mut SDL_Event,
+        ) -> bool {
+            unsafe { Callback::<F>::call(userdata, |f| f(&mut *event)) }
+        }
+        let userdata = Callback(callback).into_raw();
+        unsafe { SDL_SetEventFilter(Some(filter::<F>), userdata) };
+        Self {
+            userdata,
+            release: Callback::<F>::release,
+        }
+    }
+
+    /// Check if this is still the current event filter
+    pub fn is_current(&self) -> bool {
+        // userdata is unique to each filter, so there's no need to compare the callback
+        let mut callback: SDL_EventFilter = None;
+        let mut userdata = ptr::null_mut();
+        let has_filter = unsafe { SDL_GetEventFilter(&mut callback, &mut userdata) };
+        has_filter && userdata == self.userdata
+    }
+}
+
+impl Drop for EventFilter {
+    fn drop(&mut self) {
+        // there's a window between checking and clearing the filter where another thread
+        // could set a new filter that we'd then clear, but that's not unsound
+        if self.is_current() {
+            unsafe { SDL_SetEventFilter(None, ptr::null_mut()) };
+        }
+        unsafe { (self.release)(self.userdata) };
+    }
+}
+
+pub(crate) struct SpinLock<T> {
+    lock: UnsafeCell<SDL_SpinLock>,
+    data: UnsafeCell<T>,
+}
+
+unsafe impl<T: Send> Sync for SpinLock<T> {}
+
+impl<T> SpinLock<T> {
+    pub(crate) const fn new(data: T) -> Self {
+        Self {
+            lock: UnsafeCell::new(SDL_SpinLock(0)),
+            data: UnsafeCell::new(data),
+        }
+    }
+
+    // `f` must not panic
+    pub(crate) fn with<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        unsafe { SDL_LockSpinlock(self.lock.get()) };
+        let result = f(unsafe { &mut *self.data.get() });
+        unsafe { SDL_UnlockSpinlock(self.lock.get()) };
+        result
+    }
+}
+
+struct Payloads<T> {
+    next_id: usize,
+    values: BTreeMap<usize, T>,
+}
+
+struct UserEventType<T> {
+    event_type: u32,
+    payloads: SpinLock<Payloads<T>>,
+}
+
+trait ReleasePayloads: Sync {
+    fn event_type(&self) -> u32;
+    fn release(&self, id: usize);
+    fn release_all(&self);
+}
+
+impl<T: Send> ReleasePayloads for UserEventType<T> {
+    fn event_type(&self) -> u32 {
+        self.event_type
+    }
+
+    fn release(&self, id: usize) {
+        // drop outside of the lock
+        drop(self.payloads.with(|p| p.values.remove(&id)));
+    }
+
+    fn release_all(&self) {
+        drop(self.payloads.with(|p| core::mem::take(&mut p.values)));
+    }
+}
+
+// Registered user event types. SDL can't unregister event types, so this only grows
+struct Registration {
+    user_event: &'static dyn ReleasePayloads,
+    next: *mut Registration,
+}
+
+static REGISTRATIONS: AtomicPtr<Registration> = AtomicPtr::new(ptr::null_mut());
+
+fn registrations() -> impl Iterator<Item = &'static dyn ReleasePayloads> {
+    let mut next = REGISTRATIONS.load(Ordering::Acquire);
+    core::iter::from_fn(move || {
+        let registration = unsafe { next.as_ref() }?;
+        next = registration.next;
+        Some(registration.user_event)
+    })
+}
+
+/// A user event type with a payload of type `T`, registered with [`SDL_RegisterEvents`].
+///
+/// Push events with [`UserEvent::push()`] from any thread, and get the payload back in
+/// `app_event` with [`UserEvent::take()`]. The payload is stored outside of the event itself,
+/// so copying the event doesn't duplicate it.
+///
+/// If a pushed event isn't taken, its payload is freed:
+/// - after `app_event` returns, if the event was passed to `app_event`
+/// - when the event is removed by [`UserEvent::flush()`] or [`flush_events()`]
+/// - after `app_quit` returns
+///
+/// If you poll events yourself instead of using `app_event`, call [`release_user_event()`]
+/// for each event after handling it. Use [`flush_events()`] instead of
+/// [`SDL_FlushEvents`](sdl3_sys::events::SDL_FlushEvents), which can't free the payloads
+/// of the events it removes.
+///
+/// Example:
+/// ```rust
+/// use sdl3_main::events::UserEvent;
+/// use sdl3_sys::events::SDL_Event;
+///
+/// fn handle_event(downloaded: UserEvent<Vec<u8>>, event: &SDL_Event) {
+///     if let Some(data) = downloaded.take(event) {
+///         println!("downloaded {} bytes", data.len());
+///     }
+/// }
+/// ```
+pub struct UserEvent<T: Send + 'static> {
+    inner: &'static UserEventType<T>,
+}
+
+impl<T: Send + 'static> Clone for UserEvent<T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + 'static> Copy for UserEvent<T> {}
+
+impl<T: Send + 'static> UserEvent<T> {
+    /// Register a new event type. Returns `None` if there are no more user event types available.
+    ///
+    /// Each call registers a new event type, and event types can't be unregistered,
+    /// so call this once for each kind of event and keep the returned handle.
+    pub fn register() -> Option<Self> {
+        let event_type = unsafe { SDL_RegisterEvents(1) };
+        if event_type == 0 {
+            return None;
+        }
+        let inner = Box::leak(Box::new(UserEventType {
+            event_type,
+            payloads: SpinLock::new(Payloads {
+                next_id: 0,
+                values: BTreeMap::new(),
+            }),
+        }));
+        let registration = Box::leak(Box::new(Registration {
+            user_event: inner,
+            next: REGISTRATIONS.load(Ordering::Relaxed),
+        }));
+        while let Err(next) = REGISTRATIONS.compare_exchange_weak(
+            registration.next,
+            registration,
+            Ordering::AcqRel,
+            Ordering::Relaxed,
+        ) {
+            registration.next = next;
+        }
+        Some(Self { inner })
+    }
+
+    /// Get the registered event type
+    #[inline(always)]
+    pub fn event_type(&self) -> u32 {
+        self.inner.event_type
+    }
+
+    /// Check if an event is of this type
+    #[inline(always)]
+    pub fn is(&self, event: &SDL_Event) -> bool {
+        let event_type = unsafe { event.r#type };
+        event_type == self.inner.event_type
+    }
+
+    /// Push an event with `value` as its payload to the event queue.
+    ///
+    /// Returns false if the event was filtered or SDL failed to push it, in which case
+    /// `value` is dropped. Call [`SDL_GetError`](sdl3_sys::error::SDL_GetError) for more information.
+    pub fn push(&self, value: T) -> bool {
+        let id = self.inner.payloads.with(|p| {
+            let id = p.next_id;
+            p.next_id = p.next_id.wrapping_add(1);
+            p.values.insert(id, value);
+            id
+        });
+        let mut event = SDL_Event {
+            user: SDL_UserEvent {
+                r#type: self.inner.event_type,
+                data1: ptr::without_provenance_mut(id),
+                ..Default::default()
+            },
+        };
+        if unsafe { SDL_PushEvent(&mut event) } {
+            true
+        } else {
+            self.inner.release(id);
+            false
+        }
+    }
+
+    /// Take the payload of an event of this type. Returns `None` if the event is of a different
+    /// type, or if the payload has already been taken or freed.
+    pub fn take(&self, event: &SDL_Event) -> Option<T> {
+        if self.is(event) {
+            let id = unsafe { event.user.data1 }.addr();
+            self.inner.payloads.with(|p| p.values.remove(&id))
+        } else {
+            None
+        }
+    }
+
+    /// Remove all events of this type from the event queue and free their payloads
+    #[inline]
+    pub fn flush(&self) {
+        flush_events(SDL_EventType(self.inner.event_type));
+    }
+}
+
+/// Remove all events in a range of event types from the event queue, and free the payloads
+/// of any [`UserEvent`]s among them.
+///
+/// Use this instead of [`SDL_FlushEvent`](sdl3_sys::events::SDL_FlushEvent) or
+/// [`SDL_FlushEvents`](sdl3_sys::events::SDL_FlushEvents) if you use [`UserEvent`], as those
+/// leak the payloads of the user events they remove until `app_quit`.
+pub fn flush_events(types: impl Into<EventTypeRange>) {
+    let types = types.into();
+    let mut events = [SDL_Event::default(); 16];
+    loop {
+        let n = unsafe {
+            SDL_PeepEvents(
+                events.as_mut_ptr(),
+                events.len() as _,
+                SDL_GETEVENT,
+                types.first,
+                types.last,
+            )
+        };
+        if n <= 0 {
+            break;
+        }
+        for event in &events[..n as usize] {
+            release_user_event(event);
+        }
+    }
+}
+
+/// Free the payload of a [`UserEvent`] if it hasn't been taken. Does nothing for other events.
+///
+/// This is called automatically after `app_event` returns. If you poll events yourself,
+/// call this for each event after handling it.
+pub fn release_user_event(event: &SDL_Event) {
+    let event_type = unsafe { event.r#type };
+    if event_type >= SDL_EVENT_USER.0 {
+        if let Some(user_event) = registrations().find(|r| r.event_type() == event_type) {
+            user_event.release(unsafe { event.user.data1 }.addr());
+        }
+    }
+}
+
+// Free all payloads of user events that haven't been taken
+pub(crate) fn release_all_user_events() {
+    for user_event in registrations() {
+        user_event.release_all();
+    }
+}
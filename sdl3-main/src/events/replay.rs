@@ -0,0 +1,811 @@
+//! Record and play back input events.
+//!
+//! - [`EventRecorder`]\: Records input events as they're added to the event queue, using an
+//!   [`EventWatch`].
+//! - [`Recording`]\: A sequence of recorded events with nanosecond timestamps. It can be
+//!   saved and loaded with a compact binary format.
+//! - [`EventPlayer`]\: Pushes the events of a recording back onto the event queue on their
+//!   original schedule.
+//!
+//! Keyboard, text input, mouse, gamepad, touch, pen and drop events are recorded. Other
+//! events, such as window and device events, are ignored. String payloads, like the text of
+//! text input events and the file names of drop events, are copied into the recording.
+//!
+//! Example:
+//! ```rust,ignore
+//! use sdl3_main::events::replay::{EventPlayer, EventRecorder, Recording};
+//!
+//! let recorder = EventRecorder::start().ok_or("couldn't add event watch")?;
+//! // ... run the app
+//! std::fs::write("session.bin", recorder.stop().to_bytes())?;
+//!
+//! // later
+//! let recording = Recording::from_bytes(&std::fs::read("session.bin")?)?;
+//! let mut player = EventPlayer::new(recording);
+//! // ... call `player.pump()` once per frame, before polling events
+//! ```
+
+use super::{EventWatch, SpinLock};
+use alloc::{ffi::CString, sync::Arc, vec::Vec};
+use core::{
+    ffi::{c_char, c_void, CStr},
+    fmt::{self, Debug, Display, Formatter},
+    mem,
+};
+use sdl3_sys::{
+    events::{
+        SDL_Event, SDL_EventType, SDL_FilterEvents, SDL_PushEvent, SDL_EVENT_DROP_BEGIN,
+        SDL_EVENT_DROP_COMPLETE, SDL_EVENT_DROP_FILE, SDL_EVENT_DROP_POSITION, SDL_EVENT_DROP_TEXT,
+        SDL_EVENT_FINGER_CANCELED, SDL_EVENT_FINGER_DOWN, SDL_EVENT_FINGER_MOTION,
+        SDL_EVENT_FINGER_UP, SDL_EVENT_GAMEPAD_ADDED, SDL_EVENT_GAMEPAD_AXIS_MOTION,
+        SDL_EVENT_GAMEPAD_BUTTON_DOWN, SDL_EVENT_GAMEPAD_BUTTON_UP, SDL_EVENT_GAMEPAD_REMAPPED,
+        SDL_EVENT_GAMEPAD_REMOVED, SDL_EVENT_GAMEPAD_SENSOR_UPDATE,
+        SDL_EVENT_GAMEPAD_STEAM_HANDLE_UPDATED, SDL_EVENT_GAMEPAD_TOUCHPAD_DOWN,
+        SDL_EVENT_GAMEPAD_TOUCHPAD_MOTION, SDL_EVENT_GAMEPAD_TOUCHPAD_UP,
+        SDL_EVENT_GAMEPAD_UPDATE_COMPLETE, SDL_EVENT_KEY_DOWN, SDL_EVENT_KEY_UP,
+        SDL_EVENT_MOUSE_BUTTON_DOWN, SDL_EVENT_MOUSE_BUTTON_UP, SDL_EVENT_MOUSE_MOTION,
+        SDL_EVENT_MOUSE_WHEEL, SDL_EVENT_PEN_AXIS, SDL_EVENT_PEN_BUTTON_DOWN,
+        SDL_EVENT_PEN_BUTTON_UP, SDL_EVENT_PEN_DOWN, SDL_EVENT_PEN_MOTION,
+        SDL_EVENT_PEN_PROXIMITY_IN, SDL_EVENT_PEN_PROXIMITY_OUT, SDL_EVENT_PEN_UP,
+        SDL_EVENT_TEXT_EDITING, SDL_EVENT_TEXT_INPUT,
+    },
+    timer::SDL_GetTicksNS,
+};
+
+const MAGIC: &[u8; 7] = b"SDLEVTS";
+const VERSION: u8 = 1;
+
+/// An error from [`Recording::from_bytes()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RecordingError {
+    /// The data isn't an event recording
+    NotARecording,
+
+    /// The recording was made with a newer, incompatible version of this module
+    UnsupportedVersion(u8),
+
+    /// The data ended in the middle of an event
+    Truncated,
+
+    /// The recording contains an event of a type that can't be recorded, or invalid data
+    InvalidEvent(u32),
+}
+
+impl Display for RecordingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotARecording => f.write_str("not an event recording"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported event recording version {version}")
+            }
+            Self::Truncated => f.write_str("truncated event recording"),
+            Self::InvalidEvent(event_type) => {
+                write!(
+                    f,
+                    "invalid event of type {event_type:#x} in event recording"
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RecordingError {}
+
+/// Returns true if events of type `event_type` are recorded by [`EventRecorder`] and can
+/// be added to a [`Recording`].
+pub fn is_recordable(event_type: u32) -> bool {
+    let mut event = SDL_Event::default();
+    event.r#type = event_type;
+    visit_fields(&mut Skip, &mut event)
+}
+
+// Reads or writes the fields of an event, in the order they're stored in a recording
+trait Visitor {
+    fn u8(&mut self, value: &mut u8);
+    fn u16(&mut self, value: &mut u16);
+    fn u32(&mut self, value: &mut u32);
+    fn i32(&mut self, value: &mut i32);
+    fn u64(&mut self, value: &mut u64);
+    fn f32(&mut self, value: &mut f32);
+
+    fn bool(&mut self, value: &mut bool) {
+        let mut byte = *value as u8;
+        self.u8(&mut byte);
+        *value = byte != 0;
+    }
+
+    fn i16(&mut self, value: &mut i16) {
+        let mut wide = *value as i32;
+        self.i32(&mut wide);
+        *value = wide as i16;
+    }
+}
+
+struct Skip;
+
+impl Visitor for Skip {
+    fn u8(&mut self, _: &mut u8) {}
+    fn u16(&mut self, _: &mut u16) {}
+    fn u32(&mut self, _: &mut u32) {}
+    fn i32(&mut self, _: &mut i32) {}
+    fn u64(&mut self, _: &mut u64) {}
+    fn f32(&mut self, _: &mut f32) {}
+}
+
+struct Writer(Vec<u8>);
+
+impl Writer {
+    fn varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.0.push(value as u8 | 0x80);
+            value >>= 7;
+        }
+        self.0.push(value as u8);
+    }
+
+    fn bytes(&mut self, bytes: Option<&[u8]>) {
+        match bytes {
+            Some(bytes) => {
+                self.varint(bytes.len() as u64 + 1);
+                self.0.extend_from_slice(bytes);
+            }
+            None => self.varint(0),
+        }
+    }
+}
+
+impl Visitor for Writer {
+    fn u8(&mut self, value: &mut u8) {
+        self.0.push(*value);
+    }
+
+    fn u16(&mut self, value: &mut u16) {
+        self.varint(*value as u64);
+    }
+
+    fn u32(&mut self, value: &mut u32) {
+        self.varint(*value as u64);
+    }
+
+    fn i32(&mut self, value: &mut i32) {
+        self.varint(zigzag(*value as i64));
+    }
+
+    fn u64(&mut self, value: &mut u64) {
+        self.varint(*value);
+    }
+
+    fn f32(&mut self, value: &mut f32) {
+        self.0.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    truncated: bool,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        if len > self.data.len() {
+            self.truncated = true;
+            self.data = &[];
+            return None;
+        }
+        let (bytes, rest) = self.data.split_at(len);
+        self.data = rest;
+        Some(bytes)
+    }
+
+    fn varint(&mut self) -> u64 {
+        let mut value = 0;
+        for shift in (0..64).step_by(7) {
+            let Some(&[byte]) = self.take(1) else {
+                return 0;
+            };
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+        value
+    }
+
+    fn bytes(&mut self) -> Option<&'a [u8]> {
+        match self.varint() {
+            0 => None,
+            len => self.take(usize::try_from(len - 1).unwrap_or(usize::MAX)),
+        }
+    }
+}
+
+impl Visitor for Reader<'_> {
+    fn u8(&mut self, value: &mut u8) {
+        *value = self.take(1).map_or(0, |bytes| bytes[0]);
+    }
+
+    fn u16(&mut self, value: &mut u16) {
+        *value = self.varint() as u16;
+    }
+
+    fn u32(&mut self, value: &mut u32) {
+        *value = self.varint() as u32;
+    }
+
+    fn i32(&mut self, value: &mut i32) {
+        *value = unzigzag(self.varint()) as i32;
+    }
+
+    fn u64(&mut self, value: &mut u64) {
+        *value = self.varint();
+    }
+
+    fn f32(&mut self, value: &mut f32) {
+        *value = self.take(4).map_or(0.0, |bytes| {
+            f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+        });
+    }
+}
+
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn unzigzag(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
+
+// Visit the fields of a recordable event, except for the type, timestamp and strings.
+// Returns false if the event can't be recorded
+fn visit_fields(v: &mut impl Visitor, event: &mut SDL_Event) -> bool {
+    // SAFETY: the event type determines which union field is valid
+    unsafe {
+        match SDL_EventType(event.r#type) {
+            SDL_EVENT_KEY_DOWN | SDL_EVENT_KEY_UP => {
+                let e = &mut event.key;
+                v.u32(&mut e.windowID.0);
+                v.u32(&mut e.which.0);
+                v.i32(&mut e.scancode.0);
+                v.u32(&mut e.key.0);
+                v.u16(&mut e.r#mod.0);
+                v.u16(&mut e.raw);
+                v.bool(&mut e.down);
+                v.bool(&mut e.repeat);
+            }
+            SDL_EVENT_TEXT_EDITING => {
+                let e = &mut event.edit;
+                v.u32(&mut e.windowID.0);
+                v.i32(&mut e.start);
+                v.i32(&mut e.length);
+            }
+            SDL_EVENT_TEXT_INPUT => v.u32(&mut event.text.windowID.0),
+            SDL_EVENT_MOUSE_MOTION => {
+                let e = &mut event.motion;
+                v.u32(&mut e.windowID.0);
+                v.u32(&mut e.which.0);
+                v.u32(&mut e.state.0);
+                v.f32(&mut e.x);
+                v.f32(&mut e.y);
+                v.f32(&mut e.xrel);
+                v.f32(&mut e.yrel);
+            }
+            SDL_EVENT_MOUSE_BUTTON_DOWN | SDL_EVENT_MOUSE_BUTTON_UP => {
+                let e = &mut event.button;
+                v.u32(&mut e.windowID.0);
+                v.u32(&mut e.which.0);
+                v.u8(&mut e.button);
+                v.bool(&mut e.down);
+                v.u8(&mut e.clicks);
+                v.f32(&mut e.x);
+                v.f32(&mut e.y);
+            }
+            SDL_EVENT_MOUSE_WHEEL => {
+                let e = &mut event.wheel;
+                v.u32(&mut e.windowID.0);
+                v.u32(&mut e.which.0);
+                v.f32(&mut e.x);
+                v.f32(&mut e.y);
+                v.i32(&mut e.direction.0);
+                v.f32(&mut e.mouse_x);
+                v.f32(&mut e.mouse_y);
+                v.i32(&mut e.integer_x);
+                v.i32(&mut e.integer_y);
+            }
+            SDL_EVENT_GAMEPAD_AXIS_MOTION => {
+                let e = &mut event.gaxis;
+                v.u32(&mut e.which.0);
+                v.u8(&mut e.axis);
+                v.i16(&mut e.value);
+            }
+            SDL_EVENT_GAMEPAD_BUTTON_DOWN | SDL_EVENT_GAMEPAD_BUTTON_UP => {
+                let e = &mut event.gbutton;
+                v.u32(&mut e.which.0);
+                v.u8(&mut e.button);
+                v.bool(&mut e.down);
+            }
+            SDL_EVENT_GAMEPAD_ADDED
+            | SDL_EVENT_GAMEPAD_REMOVED
+            | SDL_EVENT_GAMEPAD_REMAPPED
+            | SDL_EVENT_GAMEPAD_UPDATE_COMPLETE
+            | SDL_EVENT_GAMEPAD_STEAM_HANDLE_UPDATED => v.u32(&mut event.gdevice.which.0),
+            SDL_EVENT_GAMEPAD_TOUCHPAD_DOWN
+            | SDL_EVENT_GAMEPAD_TOUCHPAD_MOTION
+            | SDL_EVENT_GAMEPAD_TOUCHPAD_UP => {
+                let e = &mut event.gtouchpad;
+                v.u32(&mut e.which.0);
+                v.i32(&mut e.touchpad);
+                v.i32(&mut e.finger);
+                v.f32(&mut e.x);
+                v.f32(&mut e.y);
+                v.f32(&mut e.pressure);
+            }
+            SDL_EVENT_GAMEPAD_SENSOR_UPDATE => {
+                let e = &mut event.gsensor;
+                v.u32(&mut e.which.0);
+                v.i32(&mut e.sensor);
+                for value in &mut e.data {
+                    v.f32(value);
+                }
+                v.u64(&mut e.sensor_timestamp);
+            }
+            SDL_EVENT_FINGER_DOWN
+            | SDL_EVENT_FINGER_UP
+            | SDL_EVENT_FINGER_MOTION
+            | SDL_EVENT_FINGER_CANCELED => {
+                let e = &mut event.tfinger;
+                v.u64(&mut e.touchID.0);
+                v.u64(&mut e.fingerID.0);
+                v.f32(&mut e.x);
+                v.f32(&mut e.y);
+                v.f32(&mut e.dx);
+                v.f32(&mut e.dy);
+                v.f32(&mut e.pressure);
+                v.u32(&mut e.windowID.0);
+            }
+            SDL_EVENT_PEN_PROXIMITY_IN | SDL_EVENT_PEN_PROXIMITY_OUT => {
+                let e = &mut event.pproximity;
+                v.u32(&mut e.windowID.0);
+                v.u32(&mut e.which.0);
+            }
+            SDL_EVENT_PEN_DOWN | SDL_EVENT_PEN_UP => {
+                let e = &mut event.ptouch;
+                v.u32(&mut e.windowID.0);
+                v.u32(&mut e.which.0);
+                v.u32(&mut e.pen_state.0);
+                v.f32(&mut e.x);
+                v.f32(&mut e.y);
+                v.bool(&mut e.eraser);
+                v.bool(&mut e.down);
+            }
+            SDL_EVENT_PEN_BUTTON_DOWN | SDL_EVENT_PEN_BUTTON_UP => {
+                let e = &mut event.pbutton;
+                v.u32(&mut e.windowID.0);
+                v.u32(&mut e.which.0);
+                v.u32(&mut e.pen_state.0);
+                v.f32(&mut e.x);
+                v.f32(&mut e.y);
+                v.u8(&mut e.button);
+                v.bool(&mut e.down);
+            }
+            SDL_EVENT_PEN_MOTION => {
+                let e = &mut event.pmotion;
+                v.u32(&mut e.windowID.0);
+                v.u32(&mut e.which.0);
+                v.u32(&mut e.pen_state.0);
+                v.f32(&mut e.x);
+                v.f32(&mut e.y);
+            }
+            SDL_EVENT_PEN_AXIS => {
+                let e = &mut event.paxis;
+                v.u32(&mut e.windowID.0);
+                v.u32(&mut e.which.0);
+                v.u32(&mut e.pen_state.0);
+                v.f32(&mut e.x);
+                v.f32(&mut e.y);
+                v.i32(&mut e.axis.0);
+                v.f32(&mut e.value);
+            }
+            SDL_EVENT_DROP_BEGIN
+            | SDL_EVENT_DROP_FILE
+            | SDL_EVENT_DROP_TEXT
+            | SDL_EVENT_DROP_COMPLETE
+            | SDL_EVENT_DROP_POSITION => {
+                let e = &mut event.drop;
+                v.u32(&mut e.windowID.0);
+                v.f32(&mut e.x);
+                v.f32(&mut e.y);
+            }
+            _ => return false,
+        }
+    }
+    true
+}
+
+// The string fields of an event
+fn string_fields(event: &mut SDL_Event) -> [Option<&mut *const c_char>; 2] {
+    unsafe {
+        match SDL_EventType(event.r#type) {
+            SDL_EVENT_TEXT_EDITING => [Some(&mut event.edit.text), None],
+            SDL_EVENT_TEXT_INPUT => [Some(&mut event.text.text), None],
+            SDL_EVENT_DROP_BEGIN
+            | SDL_EVENT_DROP_FILE
+            | SDL_EVENT_DROP_TEXT
+            | SDL_EVENT_DROP_COMPLETE
+            | SDL_EVENT_DROP_POSITION => {
+                let e = &mut event.drop;
+                [Some(&mut e.source), Some(&mut e.data)]
+            }
+            _ => [None, None],
+        }
+    }
+}
+
+fn has_strings(event_type: u32) -> bool {
+    let mut event = SDL_Event::default();
+    event.r#type = event_type;
+    string_fields(&mut event)[0].is_some()
+}
+
+struct Entry {
+    // string fields point into `strings`
+    event: SDL_Event,
+    strings: [Option<CString>; 2],
+}
+
+impl Entry {
+    fn new(mut event: SDL_Event, mut strings: [Option<CString>; 2]) -> Self {
+        for (field, string) in string_fields(&mut event).into_iter().zip(&mut strings) {
+            if let Some(field) = field {
+                *field = string.as_deref().map_or(core::ptr::null(), CStr::as_ptr);
+            }
+        }
+        Self { event, strings }
+    }
+}
+
+/// A sequence of recorded input events.
+///
+/// Events keep their original timestamps, in nanoseconds. The string fields of events in
+/// a recording point to copies owned by the recording.
+#[derive(Default)]
+pub struct Recording {
+    entries: Vec<Entry>,
+}
+
+// The pointers in events only point to strings owned by the recording
+unsafe impl Send for Recording {}
+unsafe impl Sync for Recording {}
+
+impl Clone for Recording {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self
+                .entries
+                .iter()
+                .map(|entry| Entry::new(entry.event, entry.strings.clone()))
+                .collect(),
+        }
+    }
+}
+
+impl Debug for Recording {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Recording")
+            .field("len", &self.len())
+            .field("duration_ns", &self.duration_ns())
+            .finish()
+    }
+}
+
+impl Recording {
+    /// Create an empty recording.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a copy of an event to the recording, including its strings. Returns false if
+    /// events of this type can't be recorded; see [`is_recordable()`].
+    ///
+    /// # Safety
+    /// The string fields of `event` must be null or point to valid C strings, like in events
+    /// from SDL.
+    pub unsafe fn push(&mut self, event: &SDL_Event) -> bool {
+        let mut event = *event;
+        if !visit_fields(&mut Skip, &mut event) {
+            return false;
+        }
+        let mut strings = [None, None];
+        for (field, string) in string_fields(&mut event).into_iter().zip(&mut strings) {
+            if let Some(&mut ptr) = field {
+                if !ptr.is_null() {
+                    *string = Some(unsafe { CStr::from_ptr(ptr) }.into());
+                }
+            }
+        }
+        self.entries.push(Entry::new(event, strings));
+        true
+    }
+
+    /// Get the number of events in the recording.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the recording has no events.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Get the time between the first and the last event, in nanoseconds.
+    pub fn duration_ns(&self) -> u64 {
+        match (self.entries.first(), self.entries.last()) {
+            (Some(first), Some(last)) => unsafe {
+                last.event
+                    .common
+                    .timestamp
+                    .saturating_sub(first.event.common.timestamp)
+            },
+            _ => 0,
+        }
+    }
+
+    /// Iterate over the recorded events.
+    pub fn iter(&self) -> impl Iterator<Item = &SDL_Event> {
+        self.entries.iter().map(|entry| &entry.event)
+    }
+
+    /// Serialize the recording to a compact binary format.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut w = Writer(Vec::new());
+        w.0.extend_from_slice(MAGIC);
+        w.0.push(VERSION);
+        let mut time = 0;
+        for entry in &self.entries {
+            let mut event = entry.event;
+            let timestamp = unsafe { event.common.timestamp };
+            w.varint(zigzag(timestamp.wrapping_sub(time) as i64));
+            time = timestamp;
+            w.varint(unsafe { event.r#type } as u64);
+            visit_fields(&mut w, &mut event);
+            if has_strings(unsafe { event.r#type }) {
+                for string in &entry.strings {
+                    w.bytes(string.as_deref().map(CStr::to_bytes));
+                }
+            }
+        }
+        w.0
+    }
+
+    /// Load a recording serialized with [`to_bytes()`](Recording::to_bytes).
+    pub fn from_bytes(data: &[u8]) -> Result<Self, RecordingError> {
+        let data = data
+            .strip_prefix(MAGIC.as_slice())
+            .ok_or(RecordingError::NotARecording)?;
+        let (&version, data) = data.split_first().ok_or(RecordingError::NotARecording)?;
+        if version != VERSION {
+            return Err(RecordingError::UnsupportedVersion(version));
+        }
+
+        let mut r = Reader {
+            data,
+            truncated: false,
+        };
+        let mut recording = Self::new();
+        let mut time = 0_u64;
+        while !r.data.is_empty() {
+            time = time.wrapping_add(unzigzag(r.varint()) as u64);
+            let event_type = u32::try_from(r.varint()).unwrap_or(u32::MAX);
+            if r.truncated {
+                return Err(RecordingError::Truncated);
+            }
+            let mut event = SDL_Event::default();
+            event.common.r#type = event_type;
+            event.common.timestamp = time;
+            if !visit_fields(&mut r, &mut event) {
+                return Err(RecordingError::InvalidEvent(event_type));
+            }
+            let mut strings = [None, None];
+            if has_strings(event_type) {
+                for string in &mut strings {
+                    if let Some(bytes) = r.bytes() {
+                        *string = Some(
+                            CString::new(bytes)
+                                .map_err(|_| RecordingError::InvalidEvent(event_type))?,
+                        );
+                    }
+                }
+            }
+            if r.truncated {
+                return Err(RecordingError::Truncated);
+            }
+            recording.entries.push(Entry::new(event, strings));
+        }
+        Ok(recording)
+    }
+}
+
+/// Records input events as they're added to the event queue.
+///
+/// Recording starts when this is created and stops when it's dropped or
+/// [`stop()`](EventRecorder::stop) is called. Events that are filtered out by an event
+/// filter are still recorded.
+#[must_use = "recording stops when this is dropped"]
+pub struct EventRecorder {
+    recording: Arc<SpinLock<Recording>>,
+    watch: EventWatch,
+}
+
+impl Debug for EventRecorder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventRecorder")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl EventRecorder {
+    /// Start recording. Returns `None` if SDL failed to add the event watch; call
+    /// [`SDL_GetError`](sdl3_sys::error::SDL_GetError) for more information.
+    pub fn start() -> Option<Self> {
+        let recording = Arc::new(SpinLock::new(Recording::new()));
+        let watch = EventWatch::new({
+            let recording = Arc::clone(&recording);
+            move |event| {
+                recording.with(|recording| unsafe { recording.push(event) });
+            }
+        })?;
+        Some(Self { recording, watch })
+    }
+
+    /// Get the number of events recorded so far.
+    pub fn len(&self) -> usize {
+        self.recording.with(|recording| recording.len())
+    }
+
+    /// Returns true if no events have been recorded yet.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Stop recording and get the recorded events.
+    pub fn stop(self) -> Recording {
+        let Self { recording, watch } = self;
+        drop(watch);
+        recording.with(mem::take)
+    }
+}
+
+/// Plays back a [`Recording`] by pushing its events onto the event queue with
+/// [`SDL_PushEvent`] on their original schedule, relative to the first event.
+///
+/// Call [`pump()`](EventPlayer::pump) regularly, e.g. once per frame before polling events.
+/// Pushed events get new timestamps from SDL. Pushing input events doesn't change the
+/// keyboard or mouse state that SDL tracks.
+///
+/// The string fields of pushed events point to strings owned by the player. When the
+/// player is dropped, the events it pushed that have strings and are still in the queue are
+/// removed, so handle pushed events before dropping it. Other events are left in the queue.
+pub struct EventPlayer {
+    recording: Recording,
+    next: usize,
+    start: Option<u64>,
+    pushed_strings: bool,
+}
+
+impl Debug for EventPlayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventPlayer")
+            .field("recording", &self.recording)
+            .field("next", &self.next)
+            .finish_non_exhaustive()
+    }
+}
+
+impl EventPlayer {
+    /// Create a player for a recording. Playback starts on the first call to
+    /// [`pump()`](EventPlayer::pump).
+    #[inline]
+    pub fn new(recording: Recording) -> Self {
+        Self {
+            recording,
+            next: 0,
+            start: None,
+            pushed_strings: false,
+        }
+    }
+
+    fn first_timestamp(&self) -> u64 {
+        self.recording
+            .entries
+            .first()
+            .map_or(0, |entry| unsafe { entry.event.common.timestamp })
+    }
+
+    fn offset(&self, index: usize) -> u64 {
+        unsafe { self.recording.entries[index].event.common.timestamp }
+            .saturating_sub(self.first_timestamp())
+    }
+
+    /// Push all events that are due. Returns the number of events that were pushed.
+    ///
+    /// Events that SDL fails to push, e.g. because an event filter rejected them, are
+    /// skipped.
+    pub fn pump(&mut self) -> usize {
+        let now = unsafe { SDL_GetTicksNS() };
+        let elapsed = now - *self.start.get_or_insert(now);
+        let mut pushed = 0;
+        while self.next < self.recording.len() && self.offset(self.next) <= elapsed {
+            let mut event = self.recording.entries[self.next].event;
+            self.next += 1;
+            event.common.timestamp = 0;
+            self.pushed_strings |= has_strings(unsafe { event.r#type });
+            if unsafe { SDL_PushEvent(&mut event) } {
+                pushed += 1;
+            }
+        }
+        pushed
+    }
+
+    /// Get the time until the next event is due, in nanoseconds, or `None` if playback is
+    /// finished.
+    pub fn time_until_next_ns(&self) -> Option<u64> {
+        if self.is_finished() {
+            return None;
+        }
+        let elapsed = self
+            .start
+            .map_or(0, |start| unsafe { SDL_GetTicksNS() } - start);
+        Some(self.offset(self.next).saturating_sub(elapsed))
+    }
+
+    /// Returns true if all events have been pushed.
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.next >= self.recording.len()
+    }
+
+    /// Start over from the first event.
+    #[inline]
+    pub fn restart(&mut self) {
+        self.next = 0;
+        self.start = None;
+    }
+
+    /// Get the recording being played.
+    #[inline]
+    pub fn recording(&self) -> &Recording {
+        &self.recording
+    }
+}
+
+impl Drop for EventPlayer {
+    fn drop(&mut self) {
+        // remove the pushed events that point to our strings. Other events can't point to
+        // them, so this leaves events from other sources alone
+        unsafe extern "C" fn keep(userdata: *mut c_void, event: *mut SDL_Event) -> bool {
+            let strings = unsafe { &*(userdata as *const Vec<*const c_char>) };
+            string_fields(unsafe { &mut *event })
+                .into_iter()
+                .flatten()
+                .all(|field| strings.binary_search(field).is_err())
+        }
+
+        if self.pushed_strings {
+            let mut strings: Vec<*const c_char> = self
+                .recording
+                .entries
+                .iter()
+                .flat_map(|entry| &entry.strings)
+                .flatten()
+                .map(|string| string.as_ptr())
+                .collect();
+            strings.sort_unstable();
+            unsafe { SDL_FilterEvents(Some(keep), &mut strings as *mut _ as *mut c_void) };
+        }
+    }
+}
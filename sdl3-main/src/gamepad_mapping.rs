@@ -0,0 +1,930 @@
+//! Parse, check and merge gamepad mappings in the format used by `SDL_AddGamepadMapping` and
+//! `gamecontrollerdb.txt`.
+//!
+//! - [`Mapping`]\: A single mapping, like
+//!   `03000000de280000ff11000001000000,Steam Virtual Gamepad,a:b0,b:b1,...,platform:Linux,`.
+//! - [`MappingDatabase`]\: A list of mappings, one per line, that can be merged and diffed.
+//! - [`MappingIssue`]\: Problems found by [`Mapping::lint`] and [`MappingDatabase::lint`].
+//!
+//! A mapping is a GUID, a name, and a list of `output:input` fields. Outputs are gamepad
+//! buttons (`a`, `leftshoulder`, ...) or axes (`leftx`, `+lefty`, ...), and inputs are
+//! joystick buttons (`b0`), axes (`a0`, `+a1`, `a2~`) or hat directions (`h0.1`). Other
+//! fields, like `platform:Linux` or `crc:1234`, are kept as they are.
+//!
+//! Parsing and formatting don't call SDL, so they can be used in tools without initializing
+//! the gamepad subsystem. [`Mapping::add_to_sdl`], [`Mapping::from_sdl`] and
+//! [`MappingDatabase::add_to_sdl`] pass mappings to and from SDL.
+//!
+//! Example:
+//! ```rust
+//! use sdl3_main::gamepad_mapping::{Input, Mapping, Output};
+//! use sdl3_sys::gamepad::SDL_GAMEPAD_BUTTON_SOUTH;
+//!
+//! let mapping: Mapping = "xinput,XInput Controller,a:b0,b:b1,lefttrigger:a2,platform:Windows,"
+//!     .parse()
+//!     .unwrap();
+//! assert_eq!(mapping.name, "XInput Controller");
+//! assert_eq!(mapping.platform.as_deref(), Some("Windows"));
+//! assert_eq!(
+//!     mapping.input(Output::Button(SDL_GAMEPAD_BUTTON_SOUTH)),
+//!     Some(&Input::Button(0))
+//! );
+//! assert!(mapping.lint().is_empty());
+//! ```
+
+use alloc::{
+    ffi::CString,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    ffi::{c_void, CStr},
+    fmt::{self, Debug, Display, Formatter},
+    str::FromStr,
+};
+use sdl3_sys::{
+    gamepad::{
+        SDL_AddGamepadMapping, SDL_GamepadAxis, SDL_GamepadButton, SDL_GetGamepadMappingForGUID,
+    },
+    guid::SDL_GUID,
+    platform::SDL_GetPlatform,
+    stdinc::SDL_free,
+};
+
+// Names of gamepad buttons, indexed by `SDL_GamepadButton`
+const BUTTON_NAMES: [&str; 26] = [
+    "a",
+    "b",
+    "x",
+    "y",
+    "back",
+    "guide",
+    "start",
+    "leftstick",
+    "rightstick",
+    "leftshoulder",
+    "rightshoulder",
+    "dpup",
+    "dpdown",
+    "dpleft",
+    "dpright",
+    "misc1",
+    "paddle1",
+    "paddle2",
+    "paddle3",
+    "paddle4",
+    "touchpad",
+    "misc2",
+    "misc3",
+    "misc4",
+    "misc5",
+    "misc6",
+];
+
+// Names of gamepad axes, indexed by `SDL_GamepadAxis`
+const AXIS_NAMES: [&str; 6] = [
+    "leftx",
+    "lefty",
+    "rightx",
+    "righty",
+    "lefttrigger",
+    "righttrigger",
+];
+
+// Fields that aren't bindings but are understood by SDL
+const KNOWN_FIELDS: [&str; 6] = ["crc", "face", "hint", "sdk>=", "sdk<=", "type"];
+
+/// Platform names that SDL recognizes in the `platform` field.
+pub const KNOWN_PLATFORMS: [&str; 13] = [
+    "Windows",
+    "Mac OS X",
+    "macOS",
+    "Linux",
+    "iOS",
+    "tvOS",
+    "visionOS",
+    "Android",
+    "Emscripten",
+    "FreeBSD",
+    "NetBSD",
+    "OpenBSD",
+    "Haiku",
+];
+
+/// Get the name of a gamepad button as used in mappings, or `None` if it's not a valid button.
+///
+/// This is like `SDL_GetGamepadStringForButton`.
+pub fn button_name(button: SDL_GamepadButton) -> Option<&'static str> {
+    BUTTON_NAMES.get(usize::try_from(button.0).ok()?).copied()
+}
+
+/// Get a gamepad button from its name in mappings.
+///
+/// This is like `SDL_GetGamepadButtonFromString`.
+pub fn button_from_name(name: &str) -> Option<SDL_GamepadButton> {
+    BUTTON_NAMES
+        .iter()
+        .position(|n| n.eq_ignore_ascii_case(name))
+        .map(|i| SDL_GamepadButton(i as _))
+}
+
+/// Get the name of a gamepad axis as used in mappings, or `None` if it's not a valid axis.
+///
+/// This is like `SDL_GetGamepadStringForAxis`.
+pub fn axis_name(axis: SDL_GamepadAxis) -> Option<&'static str> {
+    AXIS_NAMES.get(usize::try_from(axis.0).ok()?).copied()
+}
+
+/// Get a gamepad axis from its name in mappings.
+///
+/// This is like `SDL_GetGamepadAxisFromString`.
+pub fn axis_from_name(name: &str) -> Option<SDL_GamepadAxis> {
+    AXIS_NAMES
+        .iter()
+        .position(|n| n.eq_ignore_ascii_case(name))
+        .map(|i| SDL_GamepadAxis(i as _))
+}
+
+/// The GUID of a [`Mapping`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MappingGuid {
+    /// The GUID of a joystick, written as 32 hex digits
+    Guid(SDL_GUID),
+
+    /// `default`: The mapping used for joysticks without a mapping of their own
+    Default,
+
+    /// `xinput`: The mapping used for XInput controllers
+    XInput,
+}
+
+impl From<SDL_GUID> for MappingGuid {
+    #[inline]
+    fn from(value: SDL_GUID) -> Self {
+        Self::Guid(value)
+    }
+}
+
+impl Debug for MappingGuid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for MappingGuid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Guid(guid) => {
+                for byte in guid.data {
+                    write!(f, "{byte:02x}")?;
+                }
+                Ok(())
+            }
+            Self::Default => f.write_str("default"),
+            Self::XInput => f.write_str("xinput"),
+        }
+    }
+}
+
+impl FromStr for MappingGuid {
+    type Err = ParseMappingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("default") {
+            return Ok(Self::Default);
+        } else if s.eq_ignore_ascii_case("xinput") {
+            return Ok(Self::XInput);
+        }
+        let s = s.as_bytes();
+        if s.len() != 32 {
+            return Err(ParseMappingError::InvalidGuid);
+        }
+        let mut guid = SDL_GUID::default();
+        for (byte, hex) in guid.data.iter_mut().zip(s.chunks_exact(2)) {
+            let hex = core::str::from_utf8(hex).map_err(|_| ParseMappingError::InvalidGuid)?;
+            *byte = u8::from_str_radix(hex, 16).map_err(|_| ParseMappingError::InvalidGuid)?;
+        }
+        Ok(Self::Guid(guid))
+    }
+}
+
+/// The part of an axis that's used in a binding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AxisRange {
+    /// The whole axis
+    Full,
+
+    /// The positive half of the axis, written with a `+` prefix
+    Positive,
+
+    /// The negative half of the axis, written with a `-` prefix
+    Negative,
+}
+
+impl AxisRange {
+    fn split(s: &str) -> (Self, &str) {
+        if let Some(s) = s.strip_prefix('+') {
+            (Self::Positive, s)
+        } else if let Some(s) = s.strip_prefix('-') {
+            (Self::Negative, s)
+        } else {
+            (Self::Full, s)
+        }
+    }
+
+    fn prefix(&self) -> &'static str {
+        match self {
+            Self::Full => "",
+            Self::Positive => "+",
+            Self::Negative => "-",
+        }
+    }
+}
+
+/// A gamepad button or axis that a joystick input is mapped to.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Output {
+    /// A gamepad button, like `a`
+    Button(SDL_GamepadButton),
+
+    /// A gamepad axis, or half of one, like `leftx` or `+lefty`
+    Axis {
+        /// The axis
+        axis: SDL_GamepadAxis,
+
+        /// The part of the axis
+        range: AxisRange,
+    },
+}
+
+impl Output {
+    fn parse(s: &str) -> Option<Self> {
+        if let Some(button) = button_from_name(s) {
+            return Some(Self::Button(button));
+        }
+        let (range, name) = AxisRange::split(s);
+        axis_from_name(name).map(|axis| Self::Axis { axis, range })
+    }
+}
+
+impl Debug for Output {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for Output {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Button(button) => match button_name(*button) {
+                Some(name) => f.write_str(name),
+                None => write!(f, "button{}", button.0),
+            },
+            Self::Axis { axis, range } => {
+                f.write_str(range.prefix())?;
+                match axis_name(*axis) {
+                    Some(name) => f.write_str(name),
+                    None => write!(f, "axis{}", axis.0),
+                }
+            }
+        }
+    }
+}
+
+/// A joystick input that's mapped to a gamepad [`Output`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Input {
+    /// A joystick button, like `b0`
+    Button(u32),
+
+    /// A joystick axis, like `a0`, `+a1` or `a2~`
+    Axis {
+        /// The index of the axis
+        index: u32,
+
+        /// The part of the axis
+        range: AxisRange,
+
+        /// If the axis is inverted, written with a `~` suffix
+        inverted: bool,
+    },
+
+    /// A direction of a joystick hat, like `h0.1`
+    Hat {
+        /// The index of the hat
+        index: u32,
+
+        /// The direction as a `SDL_HAT_*` mask: 1 for up, 2 for right, 4 for down and
+        /// 8 for left
+        mask: u8,
+    },
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Button(index) => write!(f, "b{index}"),
+            Self::Axis {
+                index,
+                range,
+                inverted,
+            } => {
+                write!(f, "{}a{index}", range.prefix())?;
+                if *inverted {
+                    f.write_str("~")?;
+                }
+                Ok(())
+            }
+            Self::Hat { index, mask } => write!(f, "h{index}.{mask}"),
+        }
+    }
+}
+
+impl FromStr for Input {
+    type Err = ParseMappingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = ParseMappingError::InvalidInput;
+        let (range, rest) = AxisRange::split(s);
+        if let Some(rest) = rest.strip_prefix('a') {
+            let (rest, inverted) = match rest.strip_suffix('~') {
+                Some(rest) => (rest, true),
+                None => (rest, false),
+            };
+            let index = rest.parse().map_err(|_| err)?;
+            return Ok(Self::Axis {
+                index,
+                range,
+                inverted,
+            });
+        }
+        if range != AxisRange::Full {
+            return Err(err);
+        }
+        if let Some(index) = s.strip_prefix('b') {
+            Ok(Self::Button(index.parse().map_err(|_| err)?))
+        } else if let Some(hat) = s.strip_prefix('h') {
+            let (index, mask) = hat.split_once('.').ok_or(err)?;
+            Ok(Self::Hat {
+                index: index.parse().map_err(|_| err)?,
+                mask: mask.parse().map_err(|_| err)?,
+            })
+        } else {
+            Err(err)
+        }
+    }
+}
+
+/// A binding of a joystick [`Input`] to a gamepad [`Output`], written as `output:input`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Binding {
+    /// The gamepad button or axis
+    pub output: Output,
+
+    /// The joystick input
+    pub input: Input,
+}
+
+impl Debug for Binding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for Binding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.output, self.input)
+    }
+}
+
+/// Error returned when parsing a [`Mapping`] fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseMappingError {
+    /// The GUID isn't 32 hex digits, `default` or `xinput`
+    InvalidGuid,
+
+    /// The mapping doesn't have a name after the GUID
+    MissingName,
+
+    /// A field doesn't have a `:` between its name and value
+    MissingSeparator,
+
+    /// The input of a binding isn't valid
+    InvalidInput,
+}
+
+impl Display for ParseMappingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidGuid => f.write_str("invalid GUID"),
+            Self::MissingName => f.write_str("missing name"),
+            Self::MissingSeparator => f.write_str("expected `name:value`"),
+            Self::InvalidInput => f.write_str("invalid input in binding"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseMappingError {}
+
+/// A problem found by [`Mapping::lint`] or [`MappingDatabase::lint`].
+///
+/// These don't prevent SDL from using the mapping, but they're likely mistakes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MappingIssue {
+    /// The name is empty
+    EmptyName,
+
+    /// The mapping doesn't bind any inputs
+    NoBindings,
+
+    /// The output is bound more than once
+    DuplicateOutput(Output),
+
+    /// The input is bound to more than one output
+    DuplicateInput(Input),
+
+    /// The hat direction isn't one of 1, 2, 4 or 8
+    InvalidHatMask(Input),
+
+    /// The field isn't a gamepad button or axis, or another field known to SDL
+    UnknownField(String),
+
+    /// The mapping doesn't have a `platform` field
+    MissingPlatform,
+
+    /// The platform isn't in [`KNOWN_PLATFORMS`]
+    UnknownPlatform(String),
+
+    /// An earlier mapping in the database has the same GUID and platform. The value is its
+    /// index in the database
+    DuplicateMapping(usize),
+}
+
+impl Display for MappingIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyName => f.write_str("empty name"),
+            Self::NoBindings => f.write_str("no bindings"),
+            Self::DuplicateOutput(output) => write!(f, "`{output}` is bound more than once"),
+            Self::DuplicateInput(input) => write!(f, "`{input}` is bound more than once"),
+            Self::InvalidHatMask(input) => write!(f, "invalid hat direction in `{input}`"),
+            Self::UnknownField(name) => write!(f, "unknown field `{name}`"),
+            Self::MissingPlatform => f.write_str("missing platform"),
+            Self::UnknownPlatform(platform) => write!(f, "unknown platform `{platform}`"),
+            Self::DuplicateMapping(index) => {
+                write!(f, "same GUID and platform as mapping {index}")
+            }
+        }
+    }
+}
+
+/// A gamepad mapping, as used by `SDL_AddGamepadMapping`.
+///
+/// When formatted, the bindings are written first, then the other fields, and the platform
+/// last, each followed by a comma.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Mapping {
+    /// The GUID of the joystick this mapping is for
+    pub guid: MappingGuid,
+
+    /// The name of the gamepad
+    pub name: String,
+
+    /// The bindings of joystick inputs to gamepad outputs
+    pub bindings: Vec<Binding>,
+
+    /// The value of the `platform` field
+    pub platform: Option<String>,
+
+    /// Other fields, like `crc` or `hint`, as name and value
+    pub fields: Vec<(String, String)>,
+}
+
+impl Mapping {
+    /// Create a new mapping without bindings.
+    pub fn new(guid: impl Into<MappingGuid>, name: &str) -> Self {
+        Self {
+            guid: guid.into(),
+            name: name.into(),
+            bindings: Vec::new(),
+            platform: None,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Get the input bound to an output.
+    pub fn input(&self, output: Output) -> Option<&Input> {
+        self.bindings
+            .iter()
+            .find(|b| b.output == output)
+            .map(|b| &b.input)
+    }
+
+    /// Get the value of a field that isn't a binding or the platform.
+    pub fn field(&self, name: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Returns true if this mapping applies to `platform`, as returned by `SDL_GetPlatform`.
+    /// Mappings without a platform apply to all platforms.
+    pub fn is_for_platform(&self, platform: &str) -> bool {
+        self.platform
+            .as_deref()
+            .is_none_or(|p| platform_eq(p, platform))
+    }
+
+    /// Returns true if this mapping is the same as `other`, ignoring the order of bindings
+    /// and other fields.
+    pub fn is_equivalent(&self, other: &Self) -> bool {
+        fn sorted<T: Clone + Ord>(v: &[T]) -> Vec<T> {
+            let mut v = v.to_vec();
+            v.sort_unstable();
+            v
+        }
+        self.guid == other.guid
+            && self.name == other.name
+            && self.platform == other.platform
+            && sorted(&self.bindings) == sorted(&other.bindings)
+            && sorted(&self.fields) == sorted(&other.fields)
+    }
+
+    /// Check the mapping for likely mistakes.
+    pub fn lint(&self) -> Vec<MappingIssue> {
+        let mut issues = Vec::new();
+        if self.name.trim().is_empty() {
+            issues.push(MappingIssue::EmptyName);
+        }
+        if self.bindings.is_empty() {
+            issues.push(MappingIssue::NoBindings);
+        }
+        for (i, binding) in self.bindings.iter().enumerate() {
+            let earlier = &self.bindings[..i];
+            if earlier.iter().any(|b| b.output == binding.output) {
+                issues.push(MappingIssue::DuplicateOutput(binding.output));
+            }
+            if earlier.iter().any(|b| b.input == binding.input) {
+                issues.push(MappingIssue::DuplicateInput(binding.input));
+            }
+            if let Input::Hat { mask, .. } = binding.input {
+                if !matches!(mask, 1 | 2 | 4 | 8) {
+                    issues.push(MappingIssue::InvalidHatMask(binding.input));
+                }
+            }
+        }
+        for (name, _) in &self.fields {
+            if !KNOWN_FIELDS.contains(&name.as_str()) {
+                issues.push(MappingIssue::UnknownField(name.clone()));
+            }
+        }
+        match &self.platform {
+            None => issues.push(MappingIssue::MissingPlatform),
+            Some(platform) if !KNOWN_PLATFORMS.contains(&platform.as_str()) => {
+                issues.push(MappingIssue::UnknownPlatform(platform.clone()))
+            }
+            Some(_) => (),
+        }
+        issues
+    }
+
+    /// Add this mapping to SDL with `SDL_AddGamepadMapping`, replacing any existing mapping
+    /// for the same GUID.
+    ///
+    /// Returns `Some(true)` if the mapping was added, `Some(false)` if an existing mapping
+    /// was updated, or `None` on error. Call `SDL_GetError` for more information.
+    pub fn add_to_sdl(&self) -> Option<bool> {
+        let mapping = CString::new(self.to_string()).ok()?;
+        match unsafe { SDL_AddGamepadMapping(mapping.as_ptr()) } {
+            1 => Some(true),
+            0 => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Get the mapping SDL uses for a joystick GUID with `SDL_GetGamepadMappingForGUID`, or
+    /// `None` if there isn't one.
+    pub fn from_sdl(guid: SDL_GUID) -> Option<Self> {
+        let ptr = unsafe { SDL_GetGamepadMappingForGUID(guid) };
+        if ptr.is_null() {
+            return None;
+        }
+        let mapping = unsafe { CStr::from_ptr(ptr) }
+            .to_str()
+            .ok()
+            .and_then(|mapping| mapping.parse().ok());
+        unsafe { SDL_free(ptr as *mut c_void) };
+        mapping
+    }
+}
+
+fn platform_eq(a: &str, b: &str) -> bool {
+    let mac = |p: &str| p == "Mac OS X" || p == "macOS";
+    a == b || (mac(a) && mac(b))
+}
+
+impl Display for Mapping {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},", self.guid, self.name)?;
+        for binding in &self.bindings {
+            write!(f, "{binding},")?;
+        }
+        for (name, value) in &self.fields {
+            write!(f, "{name}:{value},")?;
+        }
+        if let Some(platform) = &self.platform {
+            write!(f, "platform:{platform},")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Mapping {
+    type Err = ParseMappingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.trim().split(',');
+        let guid: MappingGuid = fields.next().unwrap_or_default().trim().parse()?;
+        let name = fields.next().ok_or(ParseMappingError::MissingName)?;
+        let mut mapping = Self::new(guid, name);
+        for field in fields {
+            let field = field.trim();
+            if field.is_empty() {
+                continue;
+            }
+            let (name, value) = field
+                .split_once(':')
+                .ok_or(ParseMappingError::MissingSeparator)?;
+            if name == "platform" {
+                mapping.platform = Some(value.into());
+            } else if let (Some(output), false) = (Output::parse(name), value.is_empty()) {
+                let input = value.parse()?;
+                mapping.bindings.push(Binding { output, input });
+            } else {
+                mapping.fields.push((name.into(), value.into()));
+            }
+        }
+        Ok(mapping)
+    }
+}
+
+/// A list of [`Mapping`]s, like `gamecontrollerdb.txt`.
+///
+/// Mappings are identified by their GUID and platform. A database can contain more than one
+/// mapping for the same GUID and platform; the last one is used, like when adding them to
+/// SDL in order.
+///
+/// The text format has one mapping per line. Empty lines and lines starting with `#` are
+/// ignored when parsing, and aren't preserved.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MappingDatabase {
+    mappings: Vec<Mapping>,
+}
+
+impl MappingDatabase {
+    /// Create a new empty database.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            mappings: Vec::new(),
+        }
+    }
+
+    /// Returns true if the database has no mappings.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.mappings.is_empty()
+    }
+
+    /// Get the number of mappings, including duplicates.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.mappings.len()
+    }
+
+    /// Iterate over the mappings in order.
+    pub fn iter(&self) -> impl Iterator<Item = &Mapping> {
+        self.mappings.iter()
+    }
+
+    /// Iterate over the mappings that apply to `platform`. See [`Mapping::is_for_platform`].
+    pub fn for_platform<'a>(&'a self, platform: &'a str) -> impl Iterator<Item = &'a Mapping> {
+        self.mappings
+            .iter()
+            .filter(move |m| m.is_for_platform(platform))
+    }
+
+    fn position(&self, guid: &MappingGuid, platform: Option<&str>) -> Option<usize> {
+        self.mappings
+            .iter()
+            .rposition(|m| m.guid == *guid && m.platform.as_deref() == platform)
+    }
+
+    /// Get the mapping for a GUID and platform.
+    pub fn get(&self, guid: &MappingGuid, platform: Option<&str>) -> Option<&Mapping> {
+        self.position(guid, platform).map(|i| &self.mappings[i])
+    }
+
+    /// Add a mapping, replacing any mappings with the same GUID and platform. Returns the
+    /// mapping that was replaced, if any.
+    ///
+    /// A replaced mapping keeps its position in the database.
+    pub fn insert(&mut self, mapping: Mapping) -> Option<Mapping> {
+        let Some(i) = self.position(&mapping.guid, mapping.platform.as_deref()) else {
+            self.mappings.push(mapping);
+            return None;
+        };
+        let old = core::mem::replace(&mut self.mappings[i], mapping);
+        let new = &self.mappings[i];
+        let (guid, platform) = (new.guid, new.platform.clone());
+        let mut j = 0;
+        self.mappings.retain(|m| {
+            let keep = j == i || !(m.guid == guid && m.platform == platform);
+            j += 1;
+            keep
+        });
+        Some(old)
+    }
+
+    /// Remove the mappings for a GUID and platform. Returns the last one, if any.
+    pub fn remove(&mut self, guid: &MappingGuid, platform: Option<&str>) -> Option<Mapping> {
+        let i = self.position(guid, platform)?;
+        let old = self.mappings.remove(i);
+        self.mappings
+            .retain(|m| !(m.guid == *guid && m.platform.as_deref() == platform));
+        Some(old)
+    }
+
+    /// Add all mappings from `other`, replacing mappings with the same GUID and platform.
+    pub fn merge(&mut self, other: Self) {
+        for mapping in other.mappings {
+            self.insert(mapping);
+        }
+    }
+
+    /// Add the mappings from `other` that don't have the same GUID and platform as a mapping
+    /// that's already in the database.
+    pub fn merge_missing(&mut self, other: Self) {
+        for mapping in other.mappings {
+            if self
+                .position(&mapping.guid, mapping.platform.as_deref())
+                .is_none()
+            {
+                self.mappings.push(mapping);
+            }
+        }
+    }
+
+    /// Check all mappings for likely mistakes, including duplicates. Returns the index of
+    /// each mapping with an issue together with the issue.
+    pub fn lint(&self) -> Vec<(usize, MappingIssue)> {
+        let mut issues = Vec::new();
+        for (i, mapping) in self.mappings.iter().enumerate() {
+            if let Some(j) = self.mappings[..i]
+                .iter()
+                .rposition(|m| m.guid == mapping.guid && m.platform == mapping.platform)
+            {
+                issues.push((i, MappingIssue::DuplicateMapping(j)));
+            }
+            issues.extend(mapping.lint().into_iter().map(|issue| (i, issue)));
+        }
+        issues
+    }
+
+    /// Compare this database with a newer version of it. Mappings are matched by GUID and
+    /// platform, and compared with [`Mapping::is_equivalent`].
+    ///
+    /// Removed and changed mappings are returned first, in the order of this database,
+    /// followed by added mappings in the order of `new`.
+    pub fn diff<'a>(&'a self, new: &'a Self) -> Vec<MappingChange<'a>> {
+        let mut changes = Vec::new();
+        for (i, old) in self.mappings.iter().enumerate() {
+            if self.position(&old.guid, old.platform.as_deref()) != Some(i) {
+                continue;
+            }
+            match new.get(&old.guid, old.platform.as_deref()) {
+                None => changes.push(MappingChange::Removed(old)),
+                Some(new) if !old.is_equivalent(new) => {
+                    changes.push(MappingChange::Changed { old, new })
+                }
+                Some(_) => (),
+            }
+        }
+        for (i, mapping) in new.mappings.iter().enumerate() {
+            if new.position(&mapping.guid, mapping.platform.as_deref()) == Some(i)
+                && self
+                    .position(&mapping.guid, mapping.platform.as_deref())
+                    .is_none()
+            {
+                changes.push(MappingChange::Added(mapping));
+            }
+        }
+        changes
+    }
+
+    /// Add the mappings that apply to the current platform to SDL. See
+    /// [`Mapping::add_to_sdl`].
+    ///
+    /// Returns the number of mappings that were added or updated, or `None` if adding a
+    /// mapping failed.
+    pub fn add_to_sdl(&self) -> Option<usize> {
+        let platform = unsafe { CStr::from_ptr(SDL_GetPlatform()) }
+            .to_str()
+            .unwrap_or_default();
+        let mut count = 0;
+        for mapping in self.for_platform(platform) {
+            mapping.add_to_sdl()?;
+            count += 1;
+        }
+        Some(count)
+    }
+}
+
+impl Display for MappingDatabase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for mapping in &self.mappings {
+            writeln!(f, "{mapping}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for MappingDatabase {
+    type Err = ParseDatabaseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut db = Self::new();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mapping = line
+                .parse()
+                .map_err(|kind| ParseDatabaseError { line: i + 1, kind })?;
+            db.mappings.push(mapping);
+        }
+        Ok(db)
+    }
+}
+
+impl FromIterator<Mapping> for MappingDatabase {
+    fn from_iter<T: IntoIterator<Item = Mapping>>(iter: T) -> Self {
+        Self {
+            mappings: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Error returned when parsing a [`MappingDatabase`] fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseDatabaseError {
+    /// The line number of the error, starting at 1
+    pub line: usize,
+
+    /// What went wrong
+    pub kind: ParseMappingError,
+}
+
+impl Display for ParseDatabaseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.kind)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseDatabaseError {}
+
+/// A difference between two [`MappingDatabase`]s, returned by [`MappingDatabase::diff`].
+///
+/// When formatted, removed mappings are written with a `-` prefix and added mappings with
+/// a `+` prefix. Changed mappings are written as both.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MappingChange<'a> {
+    /// The mapping was added
+    Added(&'a Mapping),
+
+    /// The mapping was removed
+    Removed(&'a Mapping),
+
+    /// The mapping was changed
+    Changed {
+        /// The old mapping
+        old: &'a Mapping,
+
+        /// The new mapping
+        new: &'a Mapping,
+    },
+}
+
+impl Display for MappingChange<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Added(mapping) => writeln!(f, "+{mapping}"),
+            Self::Removed(mapping) => writeln!(f, "-{mapping}"),
+            Self::Changed { old, new } => writeln!(f, "-{old}\n+{new}"),
+        }
+    }
+}
@@ -0,0 +1,736 @@
+//! Adapters between [`SDL_IOStream`] and Rust I/O traits
+//!
+//! - [`IoStream`] wraps an `SDL_IOStream` and implements `Read`, `Write`, `Seek` and `BufRead`
+//!   from `std::io` (with the `std` feature) and from `embedded-io` (with the
+//!   `use-embedded-io-v0-6` feature).
+//! - [`io_from_rust()`] and [`io_from_rust_rw()`] (requires the `std` feature) create an
+//!   `SDL_IOStream` from a Rust stream, e.g. for passing to `IMG_Load_IO` or `TTF_OpenFontIO`.
+//!   [`io_from_embedded()`] and [`io_from_embedded_rw()`] do the same for `embedded-io` streams.
+
+use core::{
+    ffi::c_void,
+    fmt::{self, Debug, Display, Formatter},
+    ptr, slice,
+};
+use sdl3_sys::{
+    iostream::{
+        SDL_CloseIO, SDL_FlushIO, SDL_GetIOStatus, SDL_IOStatus, SDL_IOStream, SDL_IOWhence,
+        SDL_ReadIO, SDL_SeekIO, SDL_WriteIO,
+    },
+    stdinc::{SDL_free, SDL_malloc},
+};
+
+#[cfg(any(
+    feature = "std",
+    all(feature = "use-embedded-io-v0-6", feature = "alloc")
+))]
+use {
+    alloc::{boxed::Box, string::String},
+    sdl3_sys::{
+        error::SDL_SetError,
+        iostream::{SDL_IOStreamInterface, SDL_OpenIO},
+    },
+};
+
+#[cfg(feature = "use-embedded-io-v0-6")]
+use embedded_io_v0_6 as embedded_io;
+
+const BUFFER_SIZE: usize = 8192;
+
+/// An error from an [`IoStream`].
+///
+/// Call [`SDL_GetError`](sdl3_sys::error::SDL_GetError) for more information.
+/// When converted to a `std::io::Error`, the message from `SDL_GetError` is included.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct IoError {
+    status: SDL_IOStatus,
+}
+
+impl IoError {
+    /// The status of the stream when the error happened
+    #[inline(always)]
+    pub fn status(&self) -> SDL_IOStatus {
+        self.status
+    }
+
+    fn description(&self) -> &'static str {
+        match self.status {
+            SDL_IOStatus::NOT_READY => "stream not ready",
+            SDL_IOStatus::READONLY => "stream is read-only",
+            SDL_IOStatus::WRITEONLY => "stream is write-only",
+            _ => "I/O error",
+        }
+    }
+}
+
+impl Debug for IoError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IoError")
+            .field("status", &self.status.0)
+            .field("description", &self.description())
+            .finish()
+    }
+}
+
+impl Display for IoError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IoError {}
+
+#[cfg(feature = "std")]
+impl From<IoError> for std::io::Error {
+    fn from(value: IoError) -> Self {
+        use std::io::ErrorKind;
+        let kind = match value.status {
+            SDL_IOStatus::NOT_READY => ErrorKind::WouldBlock,
+            SDL_IOStatus::READONLY | SDL_IOStatus::WRITEONLY => ErrorKind::Unsupported,
+            _ => ErrorKind::Other,
+        };
+        let message = unsafe { core::ffi::CStr::from_ptr(sdl3_sys::error::SDL_GetError()) };
+        if message.is_empty() {
+            std::io::Error::new(kind, value)
+        } else {
+            std::io::Error::new(kind, std::format!("{value}: {}", message.to_string_lossy()))
+        }
+    }
+}
+
+#[cfg(feature = "use-embedded-io-v0-6")]
+impl embedded_io::Error for IoError {
+    fn kind(&self) -> embedded_io::ErrorKind {
+        match self.status {
+            SDL_IOStatus::READONLY | SDL_IOStatus::WRITEONLY => embedded_io::ErrorKind::Unsupported,
+            _ => embedded_io::ErrorKind::Other,
+        }
+    }
+}
+
+/// A wrapper for an [`SDL_IOStream`] that implements Rust I/O traits.
+///
+/// Reads are buffered, so that `BufRead` can be implemented. The buffer is discarded before
+/// writing or seeking, by seeking back over the data that hasn't been consumed yet.
+pub struct IoStream {
+    stream: *mut SDL_IOStream,
+    close_on_drop: bool,
+    buffer: *mut u8,
+    pos: usize,
+    filled: usize,
+}
+
+// SDL_IOStream can be used from any thread, as long as it's not used from multiple threads at once
+unsafe impl Send for IoStream {}
+
+impl IoStream {
+    /// Take ownership of an [`SDL_IOStream`]. The stream is closed when this is dropped.
+    ///
+    /// # Safety
+    /// `stream` must be a valid `SDL_IOStream` that isn't used or closed elsewhere.
+    #[inline]
+    pub unsafe fn from_raw(stream: *mut SDL_IOStream) -> Self {
+        Self {
+            stream,
+            close_on_drop: true,
+            buffer: ptr::null_mut(),
+            pos: 0,
+            filled: 0,
+        }
+    }
+
+    /// Wrap an [`SDL_IOStream`] without taking ownership of it. The stream isn't closed when
+    /// this is dropped, but any data that was buffered but not consumed is seeked back over.
+    ///
+    /// # Safety
+    /// `stream` must be a valid `SDL_IOStream` that isn't used elsewhere while this exists,
+    /// and that isn't closed before this is dropped.
+    #[inline]
+    pub unsafe fn from_raw_borrowed(stream: *mut SDL_IOStream) -> Self {
+        Self {
+            close_on_drop: false,
+            ..unsafe { Self::from_raw(stream) }
+        }
+    }
+
+    /// Get the wrapped stream.
+    ///
+    /// The stream may be positioned past the data that has been consumed, because of buffering.
+    #[inline(always)]
+    pub fn as_raw(&self) -> *mut SDL_IOStream {
+        self.stream
+    }
+
+    /// Release ownership of the wrapped stream and return it. Data that was buffered but not
+    /// consumed is seeked back over if possible.
+    pub fn into_raw(mut self) -> *mut SDL_IOStream {
+        let _ = self.discard_buffer();
+        self.close_on_drop = false;
+        self.stream
+    }
+
+    fn error(&self) -> IoError {
+        IoError {
+            status: unsafe { SDL_GetIOStatus(self.stream) },
+        }
+    }
+
+    fn read_unbuffered(&mut self, buf: &mut [u8]) -> Result<usize, IoError> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        let n = unsafe { SDL_ReadIO(self.stream, buf.as_mut_ptr() as *mut c_void, buf.len()) };
+        if n == 0 {
+            let error = self.error();
+            if !matches!(error.status, SDL_IOStatus::READY | SDL_IOStatus::EOF) {
+                return Err(error);
+            }
+        }
+        Ok(n)
+    }
+
+    fn fill_buffer(&mut self) -> Result<&[u8], IoError> {
+        if self.pos >= self.filled {
+            if self.buffer.is_null() {
+                self.buffer = unsafe { SDL_malloc(BUFFER_SIZE) } as *mut u8;
+                if self.buffer.is_null() {
+                    return Err(IoError {
+                        status: SDL_IOStatus::ERROR,
+                    });
+                }
+            }
+            let buffer = unsafe { slice::from_raw_parts_mut(self.buffer, BUFFER_SIZE) };
+            self.filled = self.read_unbuffered(buffer)?;
+            self.pos = 0;
+        }
+        Ok(unsafe { slice::from_raw_parts(self.buffer.add(self.pos), self.filled - self.pos) })
+    }
+
+    fn consume_buffer(&mut self, amt: usize) {
+        self.pos = (self.pos + amt).min(self.filled);
+    }
+
+    fn read_buffered(&mut self, buf: &mut [u8]) -> Result<usize, IoError> {
+        if self.pos >= self.filled && buf.len() >= BUFFER_SIZE {
+            return self.read_unbuffered(buf);
+        }
+        let available = self.fill_buffer()?;
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.consume_buffer(n);
+        Ok(n)
+    }
+
+    // Seek back over buffered data that hasn't been consumed
+    fn discard_buffer(&mut self) -> Result<(), IoError> {
+        let unread = self.filled - self.pos;
+        self.pos = 0;
+        self.filled = 0;
+        if unread != 0 {
+            self.seek_raw(-(unread as i64), SDL_IOWhence::CUR)?;
+        }
+        Ok(())
+    }
+
+    fn seek_raw(&mut self, offset: i64, whence: SDL_IOWhence) -> Result<u64, IoError> {
+        let pos = unsafe { SDL_SeekIO(self.stream, offset, whence) };
+        if pos < 0 {
+            Err(IoError {
+                status: SDL_IOStatus::ERROR,
+            })
+        } else {
+            Ok(pos as u64)
+        }
+    }
+
+    fn seek_buffered(&mut self, offset: i64, whence: SDL_IOWhence) -> Result<u64, IoError> {
+        let offset = if whence == SDL_IOWhence::CUR {
+            offset - (self.filled - self.pos) as i64
+        } else {
+            offset
+        };
+        self.pos = 0;
+        self.filled = 0;
+        self.seek_raw(offset, whence)
+    }
+
+    fn write_unbuffered(&mut self, buf: &[u8]) -> Result<usize, IoError> {
+        self.discard_buffer()?;
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        let n = unsafe { SDL_WriteIO(self.stream, buf.as_ptr() as *const c_void, buf.len()) };
+        if n == 0 {
+            return Err(self.error());
+        }
+        Ok(n)
+    }
+
+    fn flush_raw(&mut self) -> Result<(), IoError> {
+        if unsafe { SDL_FlushIO(self.stream) } {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+}
+
+impl Drop for IoStream {
+    fn drop(&mut self) {
+        if self.close_on_drop {
+            unsafe { SDL_CloseIO(self.stream) };
+        } else {
+            let _ = self.discard_buffer();
+        }
+        unsafe { SDL_free(self.buffer as *mut c_void) };
+    }
+}
+
+#[cfg(feature = "std")]
+const _: () = {
+    use std::io::{self, BufRead, Read, Seek, SeekFrom, Write};
+
+    impl Read for IoStream {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            Ok(self.read_buffered(buf)?)
+        }
+    }
+
+    impl BufRead for IoStream {
+        fn fill_buf(&mut self) -> io::Result<&[u8]> {
+            Ok(self.fill_buffer()?)
+        }
+
+        fn consume(&mut self, amt: usize) {
+            self.consume_buffer(amt);
+        }
+    }
+
+    impl Write for IoStream {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            Ok(self.write_unbuffered(buf)?)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(self.flush_raw()?)
+        }
+    }
+
+    impl Seek for IoStream {
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            let (offset, whence) = match pos {
+                SeekFrom::Start(offset) => (
+                    i64::try_from(offset).map_err(|_| io::ErrorKind::InvalidInput)?,
+                    SDL_IOWhence::SET,
+                ),
+                SeekFrom::Current(offset) => (offset, SDL_IOWhence::CUR),
+                SeekFrom::End(offset) => (offset, SDL_IOWhence::END),
+            };
+            Ok(self.seek_buffered(offset, whence)?)
+        }
+    }
+};
+
+#[cfg(feature = "use-embedded-io-v0-6")]
+const _: () = {
+    use embedded_io::{BufRead, ErrorType, Read, Seek, SeekFrom, Write};
+
+    impl ErrorType for IoStream {
+        type Error = IoError;
+    }
+
+    impl Read for IoStream {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, IoError> {
+            self.read_buffered(buf)
+        }
+    }
+
+    impl BufRead for IoStream {
+        fn fill_buf(&mut self) -> Result<&[u8], IoError> {
+            self.fill_buffer()
+        }
+
+        fn consume(&mut self, amt: usize) {
+            self.consume_buffer(amt);
+        }
+    }
+
+    impl Write for IoStream {
+        fn write(&mut self, buf: &[u8]) -> Result<usize, IoError> {
+            self.write_unbuffered(buf)
+        }
+
+        fn flush(&mut self) -> Result<(), IoError> {
+            self.flush_raw()
+        }
+    }
+
+    impl Seek for IoStream {
+        fn seek(&mut self, pos: SeekFrom) -> Result<u64, IoError> {
+            let (offset, whence) = match pos {
+                SeekFrom::Start(offset) => (
+                    i64::try_from(offset).map_err(|_| IoError {
+                        status: SDL_IOStatus::ERROR,
+                    })?,
+                    SDL_IOWhence::SET,
+                ),
+                SeekFrom::Current(offset) => (offset, SDL_IOWhence::CUR),
+                SeekFrom::End(offset) => (offset, SDL_IOWhence::END),
+            };
+            self.seek_buffered(offset, whence)
+        }
+    }
+};
+
+// An error from a Rust stream, to be reported to SDL
+#[cfg(any(
+    feature = "std",
+    all(feature = "use-embedded-io-v0-6", feature = "alloc")
+))]
+struct StreamError {
+    status: SDL_IOStatus,
+    message: String,
+}
+
+// A Rust stream that can be used as an SDL_IOStream
+#[cfg(any(
+    feature = "std",
+    all(feature = "use-embedded-io-v0-6", feature = "alloc")
+))]
+trait Stream: Send + 'static {
+    const WRITABLE: bool;
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, StreamError>;
+
+    fn write(&mut self, buf: &[u8]) -> Result<usize, StreamError>;
+
+    fn flush(&mut self) -> Result<(), StreamError>;
+
+    fn seek(&mut self, offset: i64, whence: SDL_IOWhence) -> Result<u64, StreamError>;
+}
+
+#[cfg(any(
+    feature = "std",
+    all(feature = "use-embedded-io-v0-6", feature = "alloc")
+))]
+fn open_stream<S: Stream>(stream: S) -> *mut SDL_IOStream {
+    unsafe fn report<T: Default>(result: Result<T, StreamError>, status: *mut SDL_IOStatus) -> T {
+        result.unwrap_or_else(|e| {
+            let message = alloc::format!("{}\0", e.message);
+            unsafe {
+                SDL_SetError(c"%s".as_ptr(), message.as_ptr());
+                *status = e.status;
+            }
+            T::default()
+        })
+    }
+
+    unsafe extern "C" fn size<S: Stream>(userdata: *mut c_void) -> i64 {
+        let stream = unsafe { &mut *(userdata as *mut S) };
+        let Ok(pos) = stream.seek(0, SDL_IOWhence::CUR) else {
+            return -1;
+        };
+        let size = stream.seek(0, SDL_IOWhence::END);
+        match (size, stream.seek(pos as i64, SDL_IOWhence::SET)) {
+            (Ok(size), Ok(_)) => size as i64,
+            _ => -1,
+        }
+    }
+
+    unsafe extern "C" fn seek<S: Stream>(
+        userdata: *mut c_void,
+        offset: i64,
+        whence: SDL_IOWhence,
+    ) -> i64 {
+        let stream = unsafe { &mut *(userdata as *mut S) };
+        match stream.seek(offset, whence) {
+            Ok(pos) => pos as i64,
+            Err(e) => {
+                let message = alloc::format!("{}\0", e.message);
+                unsafe { SDL_SetError(c"%s".as_ptr(), message.as_ptr()) };
+                -1
+            }
+        }
+    }
+
+    unsafe extern "C" fn read<S: Stream>(
+        userdata: *mut c_void,
+        ptr: *mut c_void,
+        size: usize,
+        status: *mut SDL_IOStatus,
+    ) -> usize {
+        let stream = unsafe { &mut *(userdata as *mut S) };
+        let buf = unsafe { slice::from_raw_parts_mut(ptr as *mut u8, size) };
+        let n = unsafe { report(stream.read(buf), status) };
+        if n == 0 && size != 0 && unsafe { *status } == SDL_IOStatus::READY {
+            unsafe { *status = SDL_IOStatus::EOF };
+        }
+        n
+    }
+
+    unsafe extern "C" fn write<S: Stream>(
+        userdata: *mut c_void,
+        ptr: *const c_void,
+        size: usize,
+        status: *mut SDL_IOStatus,
+    ) -> usize {
+        let stream = unsafe { &mut *(userdata as *mut S) };
+        let buf = unsafe { slice::from_raw_parts(ptr as *const u8, size) };
+        unsafe { report(stream.write(buf), status) }
+    }
+
+    unsafe extern "C" fn flush<S: Stream>(
+        userdata: *mut c_void,
+        status: *mut SDL_IOStatus,
+    ) -> bool {
+        let stream = unsafe { &mut *(userdata as *mut S) };
+        unsafe { report(stream.flush().map(|()| true), status) }
+    }
+
+    unsafe extern "C" fn close<S: Stream>(userdata: *mut c_void) -> bool {
+        let mut stream = unsafe { Box::from_raw(userdata as *mut S) };
+        if S::WRITABLE {
+            let mut status = SDL_IOStatus::READY;
+            unsafe { report(stream.flush().map(|()| true), &mut status) }
+        } else {
+            true
+        }
+    }
+
+    let mut iface = SDL_IOStreamInterface::new();
+    iface.size = Some(size::<S>);
+    iface.seek = Some(seek::<S>);
+    iface.read = Some(read::<S>);
+    if S::WRITABLE {
+        // SDL reports read-only streams as such if these aren't set
+        iface.write = Some(write::<S>);
+        iface.flush = Some(flush::<S>);
+    }
+    iface.close = Some(close::<S>);
+
+    let userdata = Box::into_raw(Box::new(stream));
+    let stream = unsafe { SDL_OpenIO(&iface, userdata as *mut c_void) };
+    if stream.is_null() {
+        drop(unsafe { Box::from_raw(userdata) });
+    }
+    stream
+}
+
+#[cfg(feature = "std")]
+const _: () = {
+    use std::io::{self, ErrorKind, Read, Seek, SeekFrom, Write};
+
+    impl From<io::Error> for StreamError {
+        fn from(value: io::Error) -> Self {
+            Self {
+                status: if value.kind() == ErrorKind::WouldBlock {
+                    SDL_IOStatus::NOT_READY
+                } else {
+                    SDL_IOStatus::ERROR
+                },
+                message: std::string::ToString::to_string(&value),
+            }
+        }
+    }
+
+    fn seek_from(offset: i64, whence: SDL_IOWhence) -> io::Result<SeekFrom> {
+        Ok(match whence {
+            SDL_IOWhence::SET => SeekFrom::Start(
+                u64::try_from(offset).map_err(|_| io::Error::from(ErrorKind::InvalidInput))?,
+            ),
+            SDL_IOWhence::CUR => SeekFrom::Current(offset),
+            SDL_IOWhence::END => SeekFrom::End(offset),
+            _ => return Err(ErrorKind::InvalidInput.into()),
+        })
+    }
+
+    // retry reads and writes that were interrupted
+    fn retry<T>(mut f: impl FnMut() -> io::Result<T>) -> io::Result<T> {
+        loop {
+            match f() {
+                Err(e) if e.kind() == ErrorKind::Interrupted => (),
+                result => return result,
+            }
+        }
+    }
+
+    impl<T: Read + Seek + Send + 'static> Stream for ReadStream<T> {
+        const WRITABLE: bool = false;
+
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, StreamError> {
+            Ok(retry(|| self.0.read(buf))?)
+        }
+
+        fn write(&mut self, _: &[u8]) -> Result<usize, StreamError> {
+            unreachable!()
+        }
+
+        fn flush(&mut self) -> Result<(), StreamError> {
+            unreachable!()
+        }
+
+        fn seek(&mut self, offset: i64, whence: SDL_IOWhence) -> Result<u64, StreamError> {
+            Ok(self.0.seek(seek_from(offset, whence)?)?)
+        }
+    }
+
+    impl<T: Read + Write + Seek + Send + 'static> Stream for ReadWriteStream<T> {
+        const WRITABLE: bool = true;
+
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, StreamError> {
+            Ok(retry(|| self.0.read(buf))?)
+        }
+
+        fn write(&mut self, buf: &[u8]) -> Result<usize, StreamError> {
+            Ok(retry(|| self.0.write(buf))?)
+        }
+
+        fn flush(&mut self) -> Result<(), StreamError> {
+            Ok(self.0.flush()?)
+        }
+
+        fn seek(&mut self, offset: i64, whence: SDL_IOWhence) -> Result<u64, StreamError> {
+            Ok(self.0.seek(seek_from(offset, whence)?)?)
+        }
+    }
+};
+
+#[cfg(feature = "std")]
+struct ReadStream<T>(T);
+
+#[cfg(feature = "std")]
+struct ReadWriteStream<T>(T);
+
+/// Create a read-only [`SDL_IOStream`] that reads from a Rust stream.
+///
+/// The stream is dropped when the `SDL_IOStream` is closed. I/O errors are reported as
+/// [`SDL_IO_STATUS_ERROR`](sdl3_sys::iostream::SDL_IO_STATUS_ERROR) (or
+/// [`SDL_IO_STATUS_NOT_READY`](sdl3_sys::iostream::SDL_IO_STATUS_NOT_READY) for
+/// `ErrorKind::WouldBlock`), and their message is set with `SDL_SetError`.
+///
+/// Returns null on failure; call [`SDL_GetError`](sdl3_sys::error::SDL_GetError) for more
+/// information.
+///
+/// Example:
+/// ```rust,ignore
+/// let file = std::fs::File::open("image.png")?;
+/// let surface = unsafe { IMG_Load_IO(sdl3_main::io::io_from_rust(file), true) };
+/// ```
+#[cfg(feature = "std")]
+pub fn io_from_rust<T: std::io::Read + std::io::Seek + Send + 'static>(
+    stream: T,
+) -> *mut SDL_IOStream {
+    open_stream(ReadStream(stream))
+}
+
+/// Create an [`SDL_IOStream`] that reads from and writes to a Rust stream.
+///
+/// The stream is flushed and dropped when the `SDL_IOStream` is closed.
+/// See [`io_from_rust()`] for details.
+#[cfg(feature = "std")]
+pub fn io_from_rust_rw<T: std::io::Read + std::io::Write + std::io::Seek + Send + 'static>(
+    stream: T,
+) -> *mut SDL_IOStream {
+    open_stream(ReadWriteStream(stream))
+}
+
+#[cfg(all(feature = "use-embedded-io-v0-6", feature = "alloc"))]
+const _: () = {
+    use embedded_io::{Error, ErrorKind, Read, Seek, SeekFrom, Write};
+
+    fn stream_error(e: impl Error) -> StreamError {
+        StreamError {
+            status: SDL_IOStatus::ERROR,
+            message: alloc::format!("{:?}", e.kind()),
+        }
+    }
+
+    fn seek_from(offset: i64, whence: SDL_IOWhence) -> Result<SeekFrom, StreamError> {
+        let invalid = || stream_error(ErrorKind::InvalidInput);
+        Ok(match whence {
+            SDL_IOWhence::SET => SeekFrom::Start(u64::try_from(offset).map_err(|_| invalid())?),
+            SDL_IOWhence::CUR => SeekFrom::Current(offset),
+            SDL_IOWhence::END => SeekFrom::End(offset),
+            _ => return Err(invalid()),
+        })
+    }
+
+    impl<T: Read + Seek + Send + 'static> Stream for EmbeddedReadStream<T> {
+        const WRITABLE: bool = false;
+
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, StreamError> {
+            self.0.read(buf).map_err(stream_error)
+        }
+
+        fn write(&mut self, _: &[u8]) -> Result<usize, StreamError> {
+            unreachable!()
+        }
+
+        fn flush(&mut self) -> Result<(), StreamError> {
+            unreachable!()
+        }
+
+        fn seek(&mut self, offset: i64, whence: SDL_IOWhence) -> Result<u64, StreamError> {
+            self.0
+                .seek(seek_from(offset, whence)?)
+                .map_err(stream_error)
+        }
+    }
+
+    impl<T: Read + Write + Seek + Send + 'static> Stream for EmbeddedReadWriteStream<T> {
+        const WRITABLE: bool = true;
+
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, StreamError> {
+            self.0.read(buf).map_err(stream_error)
+        }
+
+        fn write(&mut self, buf: &[u8]) -> Result<usize, StreamError> {
+            self.0.write(buf).map_err(stream_error)
+        }
+
+        fn flush(&mut self) -> Result<(), StreamError> {
+            self.0.flush().map_err(stream_error)
+        }
+
+        fn seek(&mut self, offset: i64, whence: SDL_IOWhence) -> Result<u64, StreamError> {
+            self.0
+                .seek(seek_from(offset, whence)?)
+                .map_err(stream_error)
+        }
+    }
+};
+
+#[cfg(all(feature = "use-embedded-io-v0-6", feature = "alloc"))]
+struct EmbeddedReadStream<T>(T);
+
+#[cfg(all(feature = "use-embedded-io-v0-6", feature = "alloc"))]
+struct EmbeddedReadWriteStream<T>(T);
+
+/// Create a read-only [`SDL_IOStream`] that reads from an `embedded-io` stream.
+///
+/// The stream is dropped when the `SDL_IOStream` is closed. Errors are reported as
+/// [`SDL_IO_STATUS_ERROR`](sdl3_sys::iostream::SDL_IO_STATUS_ERROR), and their kind is set
+/// with `SDL_SetError`.
+///
+/// Returns null on failure; call [`SDL_GetError`](sdl3_sys::error::SDL_GetError) for more
+/// information.
+#[cfg(all(feature = "use-embedded-io-v0-6", feature = "alloc"))]
+pub fn io_from_embedded<T: embedded_io::Read + embedded_io::Seek + Send + 'static>(
+    stream: T,
+) -> *mut SDL_IOStream {
+    open_stream(EmbeddedReadStream(stream))
+}
+
+/// Create an [`SDL_IOStream`] that reads from and writes to an `embedded-io` stream.
+///
+/// The stream is flushed and dropped when the `SDL_IOStream` is closed.
+/// See [`io_from_embedded()`] for details.
+#[cfg(all(feature = "use-embedded-io-v0-6", feature = "alloc"))]
+pub fn io_from_embedded_rw<
+    T: embedded_io::Read + embedded_io::Write + embedded_io::Seek + Send + 'static,
+>(
+    stream: T,
+) -> *mut SDL_IOStream {
+    open_stream(EmbeddedReadWriteStream(stream))
+}
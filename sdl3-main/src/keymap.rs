@@ -0,0 +1,1090 @@
+//! Key names, a default keymap and keyboard shortcuts that don't depend on SDL being initialized.
+//!
+//! - [`scancode_name()`]/[`scancode_from_name()`]\: Convert between scancodes and their names.
+//! - [`key_name()`]/[`key_from_name()`]\: Convert between keycodes and their names.
+//! - [`default_keycode()`]/[`default_scancode()`]\: Convert between scancodes and keycodes
+//!   with a US QWERTY layout.
+//! - [`Key`]\: A key identified by either its keycode or its scancode.
+//! - [`Shortcut`]\: A key together with a combination of modifiers, like `Ctrl+Shift+F5`.
+//! - [`KeyBindings`]\: A list of shortcuts bound to named actions, with a text format for
+//!   config files.
+//!
+//! The names mostly match the names SDL uses for `SDL_GetScancodeName` and `SDL_GetKeyName`,
+//! but they don't depend on the current keyboard layout. Everything here is plain Rust, so
+//! it can be used without initializing the video subsystem, e.g. to validate config files
+//! in tools.
+//!
+//! `Display` and `FromStr` implementations round trip, so these types can be stored as
+//! strings with any serialization framework.
+//!
+//! Example:
+//! ```rust
+//! use sdl3_main::keymap::{Key, Shortcut};
+//! use sdl3_sys::keycode::{SDLK_F5, SDL_KMOD_CTRL, SDL_KMOD_LSHIFT, SDL_KMOD_SHIFT};
+//! use sdl3_sys::scancode::SDL_SCANCODE_F5;
+//!
+//! let shortcut: Shortcut = "Ctrl+Shift+F5".parse().unwrap();
+//! assert_eq!(shortcut.key, Key::Keycode(SDLK_F5));
+//! assert!(shortcut.mods == SDL_KMOD_CTRL | SDL_KMOD_SHIFT);
+//! assert_eq!(shortcut.to_string(), "Ctrl+Shift+F5");
+//! assert!(shortcut.matches(SDLK_F5, SDL_SCANCODE_F5, SDL_KMOD_CTRL | SDL_KMOD_LSHIFT));
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::{
+    fmt::{self, Debug, Display, Formatter},
+    str::FromStr,
+};
+use sdl3_sys::{
+    events::SDL_KeyboardEvent,
+    keycode::{
+        SDL_Keycode, SDL_Keymod, SDLK_BACKSPACE, SDLK_DELETE, SDLK_ESCAPE, SDLK_EXTENDED_MASK,
+        SDLK_LEFT_TAB, SDLK_LEVEL5_SHIFT, SDLK_LHYPER, SDLK_LMETA, SDLK_MULTI_KEY_COMPOSE,
+        SDLK_RETURN, SDLK_RHYPER, SDLK_RMETA, SDLK_SCANCODE_MASK, SDLK_SPACE, SDLK_TAB,
+        SDLK_UNKNOWN, SDL_KMOD_ALT, SDL_KMOD_CAPS, SDL_KMOD_CTRL, SDL_KMOD_GUI, SDL_KMOD_LALT,
+        SDL_KMOD_LCTRL, SDL_KMOD_LEVEL5, SDL_KMOD_LGUI, SDL_KMOD_LSHIFT, SDL_KMOD_MODE,
+        SDL_KMOD_NONE, SDL_KMOD_RALT, SDL_KMOD_RCTRL, SDL_KMOD_RGUI, SDL_KMOD_RSHIFT,
+        SDL_KMOD_SHIFT,
+    },
+    scancode::{
+        SDL_Scancode, SDL_SCANCODE_A, SDL_SCANCODE_COUNT, SDL_SCANCODE_DELETE,
+        SDL_SCANCODE_NONUSHASH,
+    },
+};
+
+// Names of scancodes, indexed by scancode. Empty for scancodes without a name
+const SCANCODE_NAMES: [&str; 291] = [
+    "",
+    "",
+    "",
+    "",
+    "A",
+    "B",
+    "C",
+    "D",
+    "E",
+    "F",
+    "G",
+    "H",
+    "I",
+    "J",
+    "K",
+    "L",
+    "M",
+    "N",
+    "O",
+    "P",
+    "Q",
+    "R",
+    "S",
+    "T",
+    "U",
+    "V",
+    "W",
+    "X",
+    "Y",
+    "Z",
+    "1",
+    "2",
+    "3",
+    "4",
+    "5",
+    "6",
+    "7",
+    "8",
+    "9",
+    "0",
+    "Return",
+    "Escape",
+    "Backspace",
+    "Tab",
+    "Space",
+    "-",
+    "=",
+    "[",
+    "]",
+    "\\",
+    "#",
+    ";",
+    "'",
+    "`",
+    ",",
+    ".",
+    "/",
+    "CapsLock",
+    "F1",
+    "F2",
+    "F3",
+    "F4",
+    "F5",
+    "F6",
+    "F7",
+    "F8",
+    "F9",
+    "F10",
+    "F11",
+    "F12",
+    "PrintScreen",
+    "ScrollLock",
+    "Pause",
+    "Insert",
+    "Home",
+    "PageUp",
+    "Delete",
+    "End",
+    "PageDown",
+    "Right",
+    "Left",
+    "Down",
+    "Up",
+    "Numlock",
+    "Keypad /",
+    "Keypad *",
+    "Keypad -",
+    "Keypad +",
+    "Keypad Enter",
+    "Keypad 1",
+    "Keypad 2",
+    "Keypad 3",
+    "Keypad 4",
+    "Keypad 5",
+    "Keypad 6",
+    "Keypad 7",
+    "Keypad 8",
+    "Keypad 9",
+    "Keypad 0",
+    "Keypad .",
+    "NonUSBackslash",
+    "Application",
+    "Power",
+    "Keypad =",
+    "F13",
+    "F14",
+    "F15",
+    "F16",
+    "F17",
+    "F18",
+    "F19",
+    "F20",
+    "F21",
+    "F22",
+    "F23",
+    "F24",
+    "Execute",
+    "Help",
+    "Menu",
+    "Select",
+    "Stop",
+    "Again",
+    "Undo",
+    "Cut",
+    "Copy",
+    "Paste",
+    "Find",
+    "Mute",
+    "VolumeUp",
+    "VolumeDown",
+    "",
+    "",
+    "",
+    "Keypad ,",
+    "Keypad = (AS400)",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "AltErase",
+    "SysReq",
+    "Cancel",
+    "Clear",
+    "Prior",
+    "Return2",
+    "Separator",
+    "Out",
+    "Oper",
+    "Clear / Again",
+    "CrSel",
+    "ExSel",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "Keypad 00",
+    "Keypad 000",
+    "ThousandsSeparator",
+    "DecimalSeparator",
+    "CurrencyUnit",
+    "CurrencySubUnit",
+    "Keypad (",
+    "Keypad )",
+    "Keypad {",
+    "Keypad }",
+    "Keypad Tab",
+    "Keypad Backspace",
+    "Keypad A",
+    "Keypad B",
+    "Keypad C",
+    "Keypad D",
+    "Keypad E",
+    "Keypad F",
+    "Keypad XOR",
+    "Keypad ^",
+    "Keypad %",
+    "Keypad <",
+    "Keypad >",
+    "Keypad &",
+    "Keypad &&",
+    "Keypad |",
+    "Keypad ||",
+    "Keypad :",
+    "Keypad #",
+    "Keypad Space",
+    "Keypad @",
+    "Keypad !",
+    "Keypad MemStore",
+    "Keypad MemRecall",
+    "Keypad MemClear",
+    "Keypad MemAdd",
+    "Keypad MemSubtract",
+    "Keypad MemMultiply",
+    "Keypad MemDivide",
+    "Keypad +/-",
+    "Keypad Clear",
+    "Keypad ClearEntry",
+    "Keypad Binary",
+    "Keypad Octal",
+    "Keypad Decimal",
+    "Keypad Hexadecimal",
+    "",
+    "",
+    "Left Ctrl",
+    "Left Shift",
+    "Left Alt",
+    "Left GUI",
+    "Right Ctrl",
+    "Right Shift",
+    "Right Alt",
+    "Right GUI",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "",
+    "ModeSwitch",
+    "Sleep",
+    "Wake",
+    "ChannelUp",
+    "ChannelDown",
+    "MediaPlay",
+    "MediaPause",
+    "MediaRecord",
+    "MediaFastForward",
+    "MediaRewind",
+    "MediaTrackNext",
+    "MediaTrackPrevious",
+    "MediaStop",
+    "Eject",
+    "MediaPlayPause",
+    "MediaSelect",
+    "AC New",
+    "AC Open",
+    "AC Close",
+    "AC Exit",
+    "AC Save",
+    "AC Print",
+    "AC Properties",
+    "AC Search",
+    "AC Home",
+    "AC Back",
+    "AC Forward",
+    "AC Stop",
+    "AC Refresh",
+    "AC Bookmarks",
+    "SoftLeft",
+    "SoftRight",
+    "Call",
+    "EndCall",
+];
+
+const FIRST_SYMBOL_SCANCODE: usize = SDL_SCANCODE_A.0 as usize;
+
+// Keycodes for scancodes A through SLASH on a US QWERTY keyboard, without and with shift
+const US_SYMBOLS: &[u8; 53] = b"abcdefghijklmnopqrstuvwxyz1234567890\r\x1b\x08\t -=[]\\#;'`,./";
+const US_SHIFTED_SYMBOLS: &[u8; 53] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZ!@#$%^&*()\r\x1b\x08\t _+{}|~:\"~<>?";
+
+const EXTENDED_KEY_NAMES: [(SDL_Keycode, &str); 7] = [
+    (SDLK_LEFT_TAB, "Left Tab"),
+    (SDLK_LEVEL5_SHIFT, "Level5 Shift"),
+    (SDLK_MULTI_KEY_COMPOSE, "Multi-key Compose"),
+    (SDLK_LMETA, "Left Meta"),
+    (SDLK_RMETA, "Right Meta"),
+    (SDLK_LHYPER, "Left Hyper"),
+    (SDLK_RHYPER, "Right Hyper"),
+];
+
+const CONTROL_KEY_NAMES: [(SDL_Keycode, &str); 6] = [
+    (SDLK_RETURN, "Return"),
+    (SDLK_ESCAPE, "Escape"),
+    (SDLK_BACKSPACE, "Backspace"),
+    (SDLK_TAB, "Tab"),
+    (SDLK_SPACE, "Space"),
+    (SDLK_DELETE, "Delete"),
+];
+
+// Modifier names in the order they're written in shortcuts. Each entry has the name for
+// either key, the names for the left and right keys, and the modifier bits for each
+const MODIFIERS: [(SDL_Keymod, &str, SDL_Keymod, &str, SDL_Keymod, &str); 4] = [
+    (
+        SDL_KMOD_CTRL,
+        "Ctrl",
+        SDL_KMOD_LCTRL,
+        "LCtrl",
+        SDL_KMOD_RCTRL,
+        "RCtrl",
+    ),
+    (
+        SDL_KMOD_ALT,
+        "Alt",
+        SDL_KMOD_LALT,
+        "LAlt",
+        SDL_KMOD_RALT,
+        "RAlt",
+    ),
+    (
+        SDL_KMOD_SHIFT,
+        "Shift",
+        SDL_KMOD_LSHIFT,
+        "LShift",
+        SDL_KMOD_RSHIFT,
+        "RShift",
+    ),
+    (
+        SDL_KMOD_GUI,
+        "GUI",
+        SDL_KMOD_LGUI,
+        "LGUI",
+        SDL_KMOD_RGUI,
+        "RGUI",
+    ),
+];
+
+// Modifiers that aren't pairs of left and right keys
+const SINGLE_MODIFIERS: [(SDL_Keymod, &str); 2] =
+    [(SDL_KMOD_MODE, "AltGr"), (SDL_KMOD_LEVEL5, "Level5")];
+
+// Alternative names for modifiers that are accepted when parsing shortcuts
+const MODIFIER_ALIASES: [(SDL_Keymod, &str); 7] = [
+    (SDL_KMOD_CTRL, "Control"),
+    (SDL_KMOD_ALT, "Option"),
+    (SDL_KMOD_GUI, "Cmd"),
+    (SDL_KMOD_GUI, "Command"),
+    (SDL_KMOD_GUI, "Super"),
+    (SDL_KMOD_GUI, "Win"),
+    (SDL_KMOD_MODE, "Mode"),
+];
+
+/// Modifiers that are part of shortcuts. Lock keys (Num Lock, Caps Lock and Scroll Lock)
+/// are ignored.
+pub const SHORTCUT_MODS: SDL_Keymod = SDL_Keymod(
+    SDL_KMOD_CTRL.0
+        | SDL_KMOD_ALT.0
+        | SDL_KMOD_SHIFT.0
+        | SDL_KMOD_GUI.0
+        | SDL_KMOD_MODE.0
+        | SDL_KMOD_LEVEL5.0,
+);
+
+/// Get the name of a scancode, or `None` if it doesn't have one.
+///
+/// This is like `SDL_GetScancodeName`.
+pub fn scancode_name(scancode: SDL_Scancode) -> Option<&'static str> {
+    let name = *SCANCODE_NAMES.get(usize::try_from(scancode.0).ok()?)?;
+    (!name.is_empty()).then_some(name)
+}
+
+/// Get a scancode from its name. Names are case insensitive.
+///
+/// This is like `SDL_GetScancodeFromName`.
+pub fn scancode_from_name(name: &str) -> Option<SDL_Scancode> {
+    if name.is_empty() {
+        return None;
+    }
+    SCANCODE_NAMES
+        .iter()
+        .position(|n| n.eq_ignore_ascii_case(name))
+        .map(|i| SDL_Scancode(i as _))
+}
+
+/// Get the keycode that a scancode produces on a US QWERTY keyboard with the modifiers in
+/// `mods`. Shift (or Caps Lock for letters) selects the shifted symbol.
+///
+/// This is like `SDL_GetKeyFromScancode(scancode, mods, false)` with a US keyboard layout.
+pub fn default_keycode(scancode: SDL_Scancode, mods: SDL_Keymod) -> SDL_Keycode {
+    let Ok(index) = usize::try_from(scancode.0) else {
+        return SDLK_UNKNOWN;
+    };
+    if index == 0 || index >= SDL_SCANCODE_COUNT.0 as usize {
+        SDLK_UNKNOWN
+    } else if let Some(&symbol) = US_SYMBOLS.get(index.wrapping_sub(FIRST_SYMBOL_SCANCODE)) {
+        let mut shift = (mods & SDL_KMOD_SHIFT) != SDL_KMOD_NONE;
+        if symbol.is_ascii_lowercase() && (mods & SDL_KMOD_CAPS) != SDL_KMOD_NONE {
+            shift = !shift;
+        }
+        if shift {
+            SDL_Keycode(US_SHIFTED_SYMBOLS[index - FIRST_SYMBOL_SCANCODE] as u32)
+        } else {
+            SDL_Keycode(symbol as u32)
+        }
+    } else if scancode == SDL_SCANCODE_DELETE {
+        SDLK_DELETE
+    } else {
+        SDL_Keycode(scancode.0 as u32 | SDLK_SCANCODE_MASK.0)
+    }
+}
+
+/// Get the scancode and modifiers that produce a keycode on a US QWERTY keyboard, or
+/// `None` if no key produces it.
+///
+/// The non-US hash key is never returned, because other keys on a US keyboard produce the
+/// same symbols.
+///
+/// This is like `SDL_GetScancodeFromKey` with a US keyboard layout.
+pub fn default_scancode(key: SDL_Keycode) -> Option<(SDL_Scancode, SDL_Keymod)> {
+    if key.0 & SDLK_SCANCODE_MASK.0 != 0 {
+        let scancode = key.0 & !SDLK_SCANCODE_MASK.0;
+        if scancode != 0 && scancode < SDL_SCANCODE_COUNT.0 as u32 {
+            return Some((SDL_Scancode(scancode as _), SDL_KMOD_NONE));
+        }
+        return None;
+    }
+    if key == SDLK_DELETE {
+        return Some((SDL_SCANCODE_DELETE, SDL_KMOD_NONE));
+    }
+    let symbol = u8::try_from(key.0).ok().filter(|&s| s != 0)?;
+    let find = |symbols: &[u8; 53]| {
+        (0..symbols.len())
+            .map(|i| SDL_Scancode((FIRST_SYMBOL_SCANCODE + i) as _))
+            .zip(symbols)
+            .find(|&(scancode, &s)| s == symbol && scancode != SDL_SCANCODE_NONUSHASH)
+            .map(|(scancode, _)| scancode)
+    };
+    if let Some(scancode) = find(US_SYMBOLS) {
+        Some((scancode, SDL_KMOD_NONE))
+    } else {
+        find(US_SHIFTED_SYMBOLS).map(|scancode| (scancode, SDL_KMOD_SHIFT))
+    }
+}
+
+/// The name of a key, returned by [`key_name()`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyName(KeyNameInner);
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum KeyNameInner {
+    Static(&'static str),
+    Char([u8; 4], u8),
+}
+
+impl KeyName {
+    /// Get the name as a string slice.
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            KeyNameInner::Static(name) => name,
+            KeyNameInner::Char(buf, len) => {
+                core::str::from_utf8(&buf[..*len as usize]).unwrap_or_default()
+            }
+        }
+    }
+}
+
+impl AsRef<str> for KeyName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Debug for KeyName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for KeyName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Get the name of a keycode, or `None` if it doesn't have one.
+///
+/// Keys that produce a character are named by that character, in upper case for letters.
+/// Other keys have the name of their scancode, like `F5` or `Keypad Enter`.
+///
+/// This is like `SDL_GetKeyName`.
+pub fn key_name(key: SDL_Keycode) -> Option<KeyName> {
+    if key.0 & SDLK_SCANCODE_MASK.0 != 0 {
+        let scancode = SDL_Scancode((key.0 & !SDLK_SCANCODE_MASK.0) as _);
+        return scancode_name(scancode).map(|name| KeyName(KeyNameInner::Static(name)));
+    }
+    if key.0 & SDLK_EXTENDED_MASK.0 != 0 {
+        return EXTENDED_KEY_NAMES
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, name)| KeyName(KeyNameInner::Static(name)));
+    }
+    if let Some((_, name)) = CONTROL_KEY_NAMES.iter().find(|(k, _)| *k == key) {
+        return Some(KeyName(KeyNameInner::Static(name)));
+    }
+    let ch = char::from_u32(key.0).filter(|ch| !ch.is_control())?;
+    let mut buf = [0; 4];
+    let len = ch.to_ascii_uppercase().encode_utf8(&mut buf).len();
+    Some(KeyName(KeyNameInner::Char(buf, len as u8)))
+}
+
+/// Get a keycode from its name. Names are case insensitive, and letters return the lower
+/// case keycode.
+///
+/// A single character is the keycode of that character. Other names are looked up as
+/// scancode names and converted with [`default_keycode()`].
+///
+/// This is like `SDL_GetKeyFromName`.
+pub fn key_from_name(name: &str) -> Option<SDL_Keycode> {
+    let mut chars = name.chars();
+    if let (Some(ch), None) = (chars.next(), chars.next()) {
+        return Some(SDL_Keycode(ch.to_ascii_lowercase() as u32));
+    }
+    if let Some((key, _)) = EXTENDED_KEY_NAMES
+        .iter()
+        .find(|(_, n)| n.eq_ignore_ascii_case(name))
+    {
+        return Some(*key);
+    }
+    scancode_from_name(name).map(|scancode| default_keycode(scancode, SDL_KMOD_NONE))
+}
+
+fn normalize_keycode(key: SDL_Keycode) -> SDL_Keycode {
+    match char::from_u32(key.0) {
+        Some(ch) if ch.is_ascii_uppercase() => SDL_Keycode(ch.to_ascii_lowercase() as u32),
+        _ => key,
+    }
+}
+
+fn parse_hex(s: &str) -> Option<u32> {
+    let s = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X"))?;
+    u32::from_str_radix(s, 16).ok()
+}
+
+/// A key, identified either by the symbol it produces with the current keyboard layout
+/// (its keycode), or by its physical position on the keyboard (its scancode).
+///
+/// Keycodes are written as their [name](key_name). Scancodes are written as `Scancode `
+/// followed by their [name](scancode_name), like `Scancode W`. Keys without a name are
+/// written as hexadecimal numbers, like `0x40000190` or `Scancode 0x190`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    /// A key identified by its keycode
+    Keycode(SDL_Keycode),
+
+    /// A key identified by its scancode
+    Scancode(SDL_Scancode),
+}
+
+impl Key {
+    /// Returns true if this is the key that was pressed. Letters are compared case
+    /// insensitively.
+    pub fn matches(&self, key: SDL_Keycode, scancode: SDL_Scancode) -> bool {
+        match self {
+            Self::Keycode(k) => normalize_keycode(*k) == normalize_keycode(key),
+            Self::Scancode(s) => *s == scancode,
+        }
+    }
+}
+
+impl From<SDL_Keycode> for Key {
+    #[inline]
+    fn from(value: SDL_Keycode) -> Self {
+        Self::Keycode(value)
+    }
+}
+
+impl From<SDL_Scancode> for Key {
+    #[inline]
+    fn from(value: SDL_Scancode) -> Self {
+        Self::Scancode(value)
+    }
+}
+
+impl Debug for Key {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Key").field(&format_args!("{self}")).finish()
+    }
+}
+
+impl Display for Key {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Keycode(key) => match key_name(*key) {
+                Some(name) => f.write_str(name.as_str()),
+                None => write!(f, "{:#x}", key.0),
+            },
+            Self::Scancode(scancode) => match scancode_name(*scancode) {
+                Some(name) => write!(f, "Scancode {name}"),
+                None => write!(f, "Scancode {:#x}", scancode.0),
+            },
+        }
+    }
+}
+
+impl FromStr for Key {
+    type Err = ParseShortcutError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseShortcutError::Empty);
+        }
+        let key = if let Some(name) = s.strip_prefix("Scancode ") {
+            if let Some(scancode) = parse_hex(name) {
+                Some(Self::Scancode(SDL_Scancode(scancode as _)))
+            } else {
+                scancode_from_name(name).map(Self::Scancode)
+            }
+        } else if let Some(key) = parse_hex(s) {
+            Some(Self::Keycode(SDL_Keycode(key)))
+        } else {
+            key_from_name(s).map(Self::Keycode)
+        };
+        key.ok_or(ParseShortcutError::UnknownKey)
+    }
+}
+
+/// Error returned when parsing a [`Key`] or [`Shortcut`] fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseShortcutError {
+    /// The string was empty
+    Empty,
+
+    /// The key name isn't known. This is also returned for unknown modifier names
+    UnknownKey,
+}
+
+impl Display for ParseShortcutError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty shortcut"),
+            Self::UnknownKey => f.write_str("unknown key name"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseShortcutError {}
+
+/// A key pressed together with a combination of modifiers, like `Ctrl+Shift+F5`.
+///
+/// Shortcuts are written as the names of the modifiers followed by the [`Key`], separated
+/// by `+`. The modifiers are `Ctrl`, `Alt`, `Shift`, `GUI`, `AltGr` and `Level5`. Ctrl, Alt,
+/// Shift and GUI match either the left or the right key; prefix them with `L` or `R`, like
+/// `LCtrl`, to only match one of them. When parsing, modifier names are case insensitive,
+/// and `Control`, `Option`, `Cmd`, `Command`, `Super`, `Win` and `Mode` are accepted as
+/// alternative names.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    /// The key
+    pub key: Key,
+
+    /// The modifiers. Modifiers not in [`SHORTCUT_MODS`] are ignored.
+    pub mods: SDL_Keymod,
+}
+
+impl Shortcut {
+    /// Create a new shortcut.
+    #[inline]
+    pub fn new(key: impl Into<Key>, mods: SDL_Keymod) -> Self {
+        Self {
+            key: key.into(),
+            mods,
+        }
+    }
+
+    /// Create a shortcut from a key press, e.g. to let the user pick a shortcut in a
+    /// rebinding UI.
+    ///
+    /// Letters are converted to lower case, pressed left or right modifier keys are
+    /// converted to modifiers that match either key, and lock keys are ignored. If `key`
+    /// is itself a modifier key, only the other modifiers are included.
+    pub fn from_key_press(key: SDL_Keycode, mods: SDL_Keymod) -> Self {
+        let mut result = SDL_KMOD_NONE;
+        for (both, _, left, _, right, _) in MODIFIERS {
+            let own_key = [left, right]
+                .into_iter()
+                .any(|m| modifier_key(m) == Some(key));
+            if (mods & both) != SDL_KMOD_NONE && !own_key {
+                result |= both;
+            }
+        }
+        for (modifier, _) in SINGLE_MODIFIERS {
+            result |= mods & modifier;
+        }
+        Self::new(normalize_keycode(key), result)
+    }
+
+    /// Create a shortcut from a keyboard event. See [`Shortcut::from_key_press`].
+    #[inline]
+    pub fn from_event(event: &SDL_KeyboardEvent) -> Self {
+        Self::from_key_press(event.key, event.r#mod)
+    }
+
+    /// Returns true if this shortcut matches a key press.
+    ///
+    /// The key must match according to [`Key::matches`], and the pressed modifiers must
+    /// match exactly, except for lock keys. Modifiers that match either key are satisfied by
+    /// the left key, the right key or both.
+    pub fn matches(&self, key: SDL_Keycode, scancode: SDL_Scancode, mods: SDL_Keymod) -> bool {
+        if !self.key.matches(key, scancode) {
+            return false;
+        }
+        for (both, _, _, _, _, _) in MODIFIERS {
+            let want = self.mods & both;
+            let have = mods & both;
+            let ok = if want == both {
+                have != SDL_KMOD_NONE
+            } else {
+                have == want
+            };
+            if !ok {
+                return false;
+            }
+        }
+        SINGLE_MODIFIERS
+            .iter()
+            .all(|&(modifier, _)| (self.mods & modifier) == (mods & modifier))
+    }
+
+    /// Returns true if this shortcut matches a keyboard event. See [`Shortcut::matches`].
+    #[inline]
+    pub fn matches_event(&self, event: &SDL_KeyboardEvent) -> bool {
+        self.matches(event.key, event.scancode, event.r#mod)
+    }
+}
+
+// The keycode of the key that sets a single modifier bit
+fn modifier_key(modifier: SDL_Keymod) -> Option<SDL_Keycode> {
+    use sdl3_sys::keycode::{
+        SDLK_LALT, SDLK_LCTRL, SDLK_LGUI, SDLK_LSHIFT, SDLK_RALT, SDLK_RCTRL, SDLK_RGUI,
+        SDLK_RSHIFT,
+    };
+    [
+        (SDL_KMOD_LCTRL, SDLK_LCTRL),
+        (SDL_KMOD_RCTRL, SDLK_RCTRL),
+        (SDL_KMOD_LALT, SDLK_LALT),
+        (SDL_KMOD_RALT, SDLK_RALT),
+        (SDL_KMOD_LSHIFT, SDLK_LSHIFT),
+        (SDL_KMOD_RSHIFT, SDLK_RSHIFT),
+        (SDL_KMOD_LGUI, SDLK_LGUI),
+        (SDL_KMOD_RGUI, SDLK_RGUI),
+    ]
+    .into_iter()
+    .find_map(|(m, k)| (m == modifier).then_some(k))
+}
+
+fn modifier_from_name(name: &str) -> Option<SDL_Keymod> {
+    for (both, both_name, left, left_name, right, right_name) in MODIFIERS {
+        for (modifier, n) in [(both, both_name), (left, left_name), (right, right_name)] {
+            if n.eq_ignore_ascii_case(name) {
+                return Some(modifier);
+            }
+        }
+    }
+    SINGLE_MODIFIERS
+        .iter()
+        .chain(MODIFIER_ALIASES.iter())
+        .find(|(_, n)| n.eq_ignore_ascii_case(name))
+        .map(|&(modifier, _)| modifier)
+}
+
+impl From<Key> for Shortcut {
+    #[inline]
+    fn from(value: Key) -> Self {
+        Self::new(value, SDL_KMOD_NONE)
+    }
+}
+
+impl Debug for Shortcut {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Shortcut")
+            .field(&format_args!("{self}"))
+            .finish()
+    }
+}
+
+impl Display for Shortcut {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (both, both_name, left, left_name, right, right_name) in MODIFIERS {
+            let mods = self.mods & both;
+            if mods == both {
+                write!(f, "{both_name}+")?;
+            } else if mods == left {
+                write!(f, "{left_name}+")?;
+            } else if mods == right {
+                write!(f, "{right_name}+")?;
+            }
+        }
+        for (modifier, name) in SINGLE_MODIFIERS {
+            if (self.mods & modifier) != SDL_KMOD_NONE {
+                write!(f, "{name}+")?;
+            }
+        }
+        Display::fmt(&self.key, f)
+    }
+}
+
+impl FromStr for Shortcut {
+    type Err = ParseShortcutError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rest = s.trim();
+        if rest.is_empty() {
+            return Err(ParseShortcutError::Empty);
+        }
+        let mut mods = SDL_KMOD_NONE;
+        // a `+` at the start or end is part of the key name, e.g. `Ctrl++` or `Keypad +`
+        loop {
+            let first = rest.chars().next().map_or(0, char::len_utf8);
+            let Some(i) = rest[first..].find('+').map(|i| i + first) else {
+                break;
+            };
+            if i + 1 == rest.len() {
+                break;
+            }
+            let Some(modifier) = modifier_from_name(rest[..i].trim()) else {
+                break;
+            };
+            mods |= modifier;
+            rest = rest[i + 1..].trim_start();
+        }
+        Ok(Self::new(rest.parse::<Key>()?, mods))
+    }
+}
+
+/// A list of [`Shortcut`]s bound to named actions.
+///
+/// An action can have any number of shortcuts, and a shortcut can be bound to more than one
+/// action. The order of bindings is preserved.
+///
+/// The text format has one binding per line, written as the action name, `=` and the
+/// shortcut, like `save = Ctrl+S`. Empty lines and lines starting with `#` are ignored.
+/// Action names can't contain `=` or start or end with whitespace.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeyBindings {
+    bindings: Vec<(String, Shortcut)>,
+}
+
+#[cfg(feature = "alloc")]
+impl KeyBindings {
+    /// Create a new empty list of bindings.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+        }
+    }
+
+    /// Returns true if there are no bindings.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.bindings.is_empty()
+    }
+
+    /// Get the number of bindings.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.bindings.len()
+    }
+
+    /// Bind a shortcut to an action. Does nothing if the binding already exists.
+    pub fn bind(&mut self, action: &str, shortcut: Shortcut) {
+        if !self
+            .bindings
+            .iter()
+            .any(|(a, s)| a == action && *s == shortcut)
+        {
+            self.bindings.push((action.into(), shortcut));
+        }
+    }
+
+    /// Remove a binding. Returns true if it existed.
+    pub fn unbind(&mut self, action: &str, shortcut: &Shortcut) -> bool {
+        let len = self.bindings.len();
+        self.bindings
+            .retain(|(a, s)| !(a == action && s == shortcut));
+        self.bindings.len() != len
+    }
+
+    /// Remove all bindings for an action.
+    pub fn unbind_action(&mut self, action: &str) {
+        self.bindings.retain(|(a, _)| a != action);
+    }
+
+    /// Iterate over all bindings.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Shortcut)> {
+        self.bindings.iter().map(|(a, s)| (a.as_str(), s))
+    }
+
+    /// Iterate over the shortcuts bound to an action.
+    pub fn shortcuts<'a>(&'a self, action: &'a str) -> impl Iterator<Item = &'a Shortcut> {
+        self.bindings
+            .iter()
+            .filter(move |(a, _)| a == action)
+            .map(|(_, s)| s)
+    }
+
+    /// Iterate over the actions that a shortcut is bound to, e.g. to find conflicts.
+    pub fn actions<'a>(&'a self, shortcut: &'a Shortcut) -> impl Iterator<Item = &'a str> {
+        self.bindings
+            .iter()
+            .filter(move |(_, s)| s == shortcut)
+            .map(|(a, _)| a.as_str())
+    }
+
+    /// Get the first action with a shortcut that matches a key press. See
+    /// [`Shortcut::matches`].
+    pub fn action_for_key_press(
+        &self,
+        key: SDL_Keycode,
+        scancode: SDL_Scancode,
+        mods: SDL_Keymod,
+    ) -> Option<&str> {
+        self.bindings
+            .iter()
+            .find(|(_, s)| s.matches(key, scancode, mods))
+            .map(|(a, _)| a.as_str())
+    }
+
+    /// Get the first action with a shortcut that matches a keyboard event.
+    #[inline]
+    pub fn action_for_event(&self, event: &SDL_KeyboardEvent) -> Option<&str> {
+        self.action_for_key_press(event.key, event.scancode, event.r#mod)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Display for KeyBindings {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (action, shortcut) in self.iter() {
+            writeln!(f, "{action} = {shortcut}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromStr for KeyBindings {
+    type Err = ParseBindingsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bindings = Self::new();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |kind| ParseBindingsError { line: i + 1, kind };
+            let (action, shortcut) = line
+                .split_once('=')
+                .ok_or(error(ParseBindingsErrorKind::MissingSeparator))?;
+            let action = action.trim_end();
+            if action.is_empty() {
+                return Err(error(ParseBindingsErrorKind::EmptyAction));
+            }
+            let shortcut = shortcut
+                .parse()
+                .map_err(|e| error(ParseBindingsErrorKind::Shortcut(e)))?;
+            bindings.bind(action, shortcut);
+        }
+        Ok(bindings)
+    }
+}
+
+/// Error returned when parsing [`KeyBindings`] fails.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseBindingsError {
+    /// The line number of the error, starting at 1
+    pub line: usize,
+
+    /// What went wrong
+    pub kind: ParseBindingsErrorKind,
+}
+
+/// The kind of a [`ParseBindingsError`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseBindingsErrorKind {
+    /// The line doesn't have a `=` between the action and the shortcut
+    MissingSeparator,
+
+    /// The action name is empty
+    EmptyAction,
+
+    /// The shortcut is invalid
+    Shortcut(ParseShortcutError),
+}
+
+#[cfg(feature = "alloc")]
+impl Display for ParseBindingsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match self.kind {
+            ParseBindingsErrorKind::MissingSeparator => f.write_str("expected `action = shortcut`"),
+            ParseBindingsErrorKind::EmptyAction => f.write_str("empty action name"),
+            ParseBindingsErrorKind::Shortcut(e) => Display::fmt(&e, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseBindingsError {}
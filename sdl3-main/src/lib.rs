@@ -12,6 +12,7 @@ extern crate std;
 
 use core::{
     ffi::{c_int, c_void},
+    fmt::Debug,
     ptr,
 };
 use sdl3_sys::{
@@ -19,12 +20,15 @@ use sdl3_sys::{
     log::{SDL_LogCategory, SDL_LogCritical},
 };
 
-#[cfg(all(feature = "log-errors", feature = "nightly"))]
+#[cfg(feature = "log-errors")]
 use {
-    core::fmt::Display,
+    core::fmt,
     sdl3_sys::{error::SDL_SetError, log::SDL_LogError},
 };
 
+#[cfg(all(feature = "log-errors", feature = "nightly"))]
+use core::fmt::Display;
+
 #[cfg(feature = "nightly")]
 use core::{convert::Infallible, ops::FromResidual};
 
@@ -77,6 +81,11 @@ pub use sdl3_main_macros::main;
 /// pass its path via the `sdl3_main` argument, e.g.
 /// `#[::path::to::sdl3_main::app_impl(sdl3_main = ::path::to::sdl3_main)]`
 ///
+/// If you pass the `main_loop` argument, e.g. `#[app_impl(main_loop)]`, the callbacks aren't
+/// registered with SDL. Instead the type implements [`AppImpl`](app::AppImpl), and you can run
+/// it in a classic main loop with [`run()`] from your own [`main`]. In this mode the impl
+/// block doesn't have to be at the root of `main.rs`.
+///
 /// Example:
 /// ```rust
 /// use sdl3_main::{app_impl, AppResult};
@@ -130,9 +139,15 @@ pub use sdl3_main_macros::app_impl;
 /// Or you can use one of these signatures with an app state type `S` (safe or unsafe):
 /// ```custom,{.rust}
 /// fn() -> Option<S>
+/// fn() -> Result<S, E>
 /// fn() -> sdl3_main::AppResultWithState<S>
 /// ```
 ///
+/// If `Result<S, E>` is returned, `Err` is treated as failure. `E` must implement
+/// [`core::fmt::Debug`]. With the `log-errors` feature (enabled by default), the error is
+/// logged with `SDL_LogError` and set with `SDL_SetError`. This lets you use the `?`
+/// operator on stable Rust.
+///
 /// The app state type must implement `Send` and `Sync`. You can define your own app state type
 /// by implementing the `sdl3_main::AppState` trait, or use one of these predefined types:
 /// ```custom,{.rust}
@@ -174,6 +189,41 @@ pub use sdl3_main_macros::app_init;
 /// fn() -> impl sdl3_main::IntoAppResult
 /// fn(B) -> impl sdl3_main::IntoAppResult
 /// ```
+///
+/// `Result<impl IntoAppResult, E>` implements `IntoAppResult`, so you can use the `?` operator
+/// in this function. See [`IntoAppResult`] for details.
+///
+/// Example:
+/// ```rust
+/// use sdl3_main::{app_event, app_init, app_iterate, app_quit, AppResult, IntoAppResult};
+/// use sdl3_sys::events::SDL_Event;
+/// use std::{fmt, sync::Mutex};
+///
+/// struct MyAppState {
+///     // ...
+/// }
+///
+/// #[derive(Debug)]
+/// struct MyError;
+///
+/// #[app_init]
+/// fn app_init() -> Option<Box<Mutex<MyAppState>>> {
+///     todo!()
+/// }
+///
+/// #[app_iterate]
+/// fn app_iterate(state: &mut MyAppState) -> Result<impl IntoAppResult, MyError> {
+///     Ok(AppResult::Continue)
+/// }
+///
+/// #[app_event]
+/// fn app_event(state: &mut MyAppState, event: &SDL_Event) -> impl IntoAppResult {
+///     AppResult::Continue
+/// }
+///
+/// #[app_quit]
+/// fn app_quit() {}
+/// ```
 pub use sdl3_main_macros::app_iterate;
 
 /// The function tagged with `app_event` is called by SDL when an event is delivered. This may get called on the main thread
@@ -204,6 +254,9 @@ pub use sdl3_main_macros::app_iterate;
 /// *mut SDL_Event
 /// ```
 /// You can add support for your own event types by implementing the `PassEventVal`, `PassEventRef` and/or `PassEventMut` traits.
+///
+/// `Result<impl IntoAppResult, E>` implements `IntoAppResult`, so you can use the `?` operator
+/// in this function. See [`IntoAppResult`] for details.
 pub use sdl3_main_macros::app_event;
 
 /// The function tagged with `app_quit` is called by SDL on the main thread when the app quits.
@@ -237,6 +290,9 @@ pub use sdl3_main_macros::app_event;
 /// fn(S, SDL_AppResult)
 /// fn(S, sdl3_main::AppResult)
 /// ```
+///
+/// Any of these can also return `Result<(), E>`, in which case an `Err` is logged as described
+/// for [`IntoAppResult`]. See [`IntoAppQuitResult`].
 pub use sdl3_main_macros::app_quit;
 
 macro_rules! defer {
@@ -256,12 +312,31 @@ impl<F: FnOnce()> Drop for Defer<F> {
 }
 
 pub mod app;
+#[cfg(feature = "alloc")]
+pub mod asyncio;
+#[cfg(feature = "alloc")]
+pub mod events;
+#[cfg(feature = "alloc")]
+pub mod gamepad_mapping;
+#[cfg(any(feature = "std", feature = "use-embedded-io-v0-6"))]
+pub mod io;
+pub mod keymap;
 mod main_thread;
+#[cfg(feature = "std")]
+pub mod process;
+#[cfg(feature = "alloc")]
+pub mod properties;
+mod run;
 pub mod state;
+#[cfg(feature = "alloc")]
+pub mod storage;
 
+#[cfg(feature = "alloc")]
+pub use main_thread::MainThreadBox;
 pub use main_thread::{
     run_async_on_main_thread, run_sync_on_main_thread, MainThreadData, MainThreadToken,
 };
+pub use run::{run, run_with_args};
 use state::{AppState, BorrowMut, BorrowRef, BorrowVal, ConsumeMut, ConsumeRef, ConsumeVal};
 
 #[doc(hidden)]
@@ -403,6 +478,11 @@ pub mod __internal {
 /// This trait is used for converting a type into an [`SDL_AppResult`] or [`AppResult`].
 ///
 /// `()` implements this trait and turns into [`SDL_AppResult::CONTINUE`]
+///
+/// `Result<R, E>` implements this trait if `R` does. `Ok` is converted as `R`, and `Err` turns
+/// into [`SDL_AppResult::FAILURE`]. `E` must implement [`core::fmt::Debug`], like for errors
+/// returned from `main`. With the `log-errors` feature (enabled by default), the error is logged
+/// with `SDL_LogError` and set with `SDL_SetError`.
 pub trait IntoAppResult: Sized {
     fn into_sdl_app_result(self) -> SDL_AppResult;
 
@@ -496,6 +576,55 @@ impl IntoAppResult for Option<bool> {
     }
 }
 
+impl<R: IntoAppResult, E: Debug> IntoAppResult for Result<R, E> {
+    #[inline]
+    fn into_sdl_app_result(self) -> SDL_AppResult {
+        match self {
+            Ok(r) => r.into_sdl_app_result(),
+            Err(_err) => {
+                #[cfg(feature = "log-errors")]
+                log_error(format_args!("{_err:?}"));
+                SDL_AppResult::FAILURE
+            }
+        }
+    }
+}
+
+/// This trait is used for handling the value returned from the function tagged with [`app_quit`].
+///
+/// `()` implements this trait and does nothing. `Result<(), E>` logs the error on `Err`
+/// like [`IntoAppResult`] does.
+pub trait IntoAppQuitResult: Sized {
+    fn into_app_quit_result(self);
+}
+
+impl IntoAppQuitResult for () {
+    #[inline(always)]
+    fn into_app_quit_result(self) {}
+}
+
+impl<E: Debug> IntoAppQuitResult for Result<(), E> {
+    #[inline]
+    fn into_app_quit_result(self) {
+        #[cfg(feature = "log-errors")]
+        if let Err(err) = self {
+            log_error(format_args!("{err:?}"));
+        }
+    }
+}
+
+/// Log an error with `SDL_LogError` and set it as the SDL error with `SDL_SetError`
+#[cfg(feature = "log-errors")]
+#[cold]
+#[inline(never)]
+fn log_error(err: fmt::Arguments) {
+    let err = ::alloc::format!("{err}\0");
+    unsafe {
+        SDL_LogError(0, c"%s".as_ptr(), err.as_ptr());
+        SDL_SetError(c"%s".as_ptr(), err.as_ptr());
+    };
+}
+
 /// This is the Rust enum equivalent to [`SDL_AppResult`].
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum AppResult {
@@ -550,11 +679,7 @@ impl From<SDL_AppResult> for AppResult {
 impl<E: Display> FromResidual<Result<Infallible, E>> for AppResult {
     fn from_residual(residual: Result<Infallible, E>) -> Self {
         let Err(err) = residual;
-        let err = ::alloc::format!("{err}\0");
-        unsafe {
-            SDL_LogError(0, c"%s".as_ptr(), err.as_ptr());
-            SDL_SetError(c"%s".as_ptr(), err.as_ptr());
-        };
+        log_error(format_args!("{err}"));
         AppResult::Failure
     }
 }
@@ -592,11 +717,7 @@ pub enum AppResultWithState<S: AppState> {
 impl<S: AppState, E: Display> FromResidual<Result<Infallible, E>> for AppResultWithState<S> {
     fn from_residual(residual: Result<Infallible, E>) -> Self {
         let Err(err) = residual;
-        let err = ::alloc::format!("{err}\0");
-        unsafe {
-            SDL_LogError(0, c"%s".as_ptr(), err.as_ptr());
-            SDL_SetError(c"%s".as_ptr(), err.as_ptr());
-        };
+        log_error(format_args!("{err}"));
         AppResultWithState::Failure(None)
     }
 }
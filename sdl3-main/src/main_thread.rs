@@ -1,3 +1,5 @@
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
 use core::{
     cell::Cell,
     ffi::c_void,
@@ -225,6 +227,171 @@ impl<T> MainThreadData<T> {
     }
 }
 
+/// Owned, heap allocated data that can only be accessed from the main thread.
+/// Accessors take a [`MainThreadToken`].
+///
+/// This can be moved freely between threads. Unlike [`MainThreadData`], it can also be dropped
+/// on any thread: if it's dropped on a thread other than the main thread, the contained value
+/// is sent to the main thread and dropped there with [`run_async_on_main_thread()`]. This makes
+/// it suitable for wrapping handles that must be destroyed on the main thread, such as windows.
+///
+/// If the value can't be sent to the main thread, for example because SDL has already quit,
+/// it's leaked instead.
+///
+/// The contained value is never accessed or dropped on any thread but the main thread, so
+/// `MainThreadBox` is `Send` and `Sync` even if `T` isn't. Values that aren't `Send` can only
+/// be put in the box on the main thread.
+#[cfg(feature = "alloc")]
+pub struct MainThreadBox<T: ?Sized + 'static>(ManuallyDrop<Box<T>>);
+
+// SAFETY: `T` is only accessed through a `MainThreadToken` or in callbacks run on the main
+// thread, and `Drop` either drops it on the main thread or leaks it
+#[cfg(feature = "alloc")]
+unsafe impl<T: ?Sized + 'static> Send for MainThreadBox<T> {}
+// SAFETY: see `Send`
+#[cfg(feature = "alloc")]
+unsafe impl<T: ?Sized + 'static> Sync for MainThreadBox<T> {}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized + 'static> Drop for MainThreadBox<T> {
+    fn drop(&mut self) {
+        let value = unsafe { ManuallyDrop::take(&mut self.0) };
+        if MainThreadToken::get().is_none() {
+            // the value is only dropped if the closure runs, so if it can't be sent to the
+            // main thread, dropping the closure on this thread leaks the value instead
+            struct SendBox<T: ?Sized>(ManuallyDrop<Box<T>>);
+            unsafe impl<T: ?Sized> Send for SendBox<T> {}
+            impl<T: ?Sized> SendBox<T> {
+                fn into_inner(self) -> Box<T> {
+                    ManuallyDrop::into_inner(self.0)
+                }
+            }
+            let value = SendBox(ManuallyDrop::new(value));
+            let _ = run_async_on_main_thread(move || drop(value.into_inner()));
+        } else {
+            drop(value);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: 'static> MainThreadBox<T> {
+    /// Create a new `MainThreadBox`.
+    ///
+    /// See also [`Self::assert_new()`], [`Self::new_send()`]
+    #[inline(always)]
+    pub fn new(_: MainThreadToken, data: T) -> Self {
+        Self(ManuallyDrop::new(Box::new(data)))
+    }
+
+    /// Create a new `MainThreadBox` on any thread. This requires `T` to be `Send`,
+    /// because the data will be accessed and dropped on the main thread.
+    ///
+    /// See also [`Self::new()`]
+    #[inline(always)]
+    pub fn new_send(data: T) -> Self
+    where
+        T: Send,
+    {
+        Self(ManuallyDrop::new(Box::new(data)))
+    }
+
+    /// Create a new `MainThreadBox`. Panic if not called on the main thread.
+    ///
+    /// See also [`Self::new()`]
+    #[track_caller]
+    #[inline(always)]
+    pub fn assert_new(data: T) -> Self {
+        Self::new(MainThreadToken::assert(), data)
+    }
+
+    /// Take the contained value out of the box.
+    #[inline(always)]
+    pub fn into_inner(self, token: MainThreadToken) -> T {
+        *self.into_box(token)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized + 'static> MainThreadBox<T> {
+    /// Create a new `MainThreadBox` from a `Box`.
+    #[inline(always)]
+    pub fn from_box(_: MainThreadToken, data: Box<T>) -> Self {
+        Self(ManuallyDrop::new(data))
+    }
+
+    /// Convert this into a `Box`.
+    #[inline(always)]
+    pub fn into_box(self, _: MainThreadToken) -> Box<T> {
+        let mut this = ManuallyDrop::new(self);
+        unsafe { ManuallyDrop::take(&mut this.0) }
+    }
+
+    /// Get shared access to this data.
+    ///
+    /// See also [`Self::assert_get()`], [`Self::get_on_main_thread()`]
+    #[inline(always)]
+    pub fn get(&self, _: MainThreadToken) -> &T {
+        &self.0
+    }
+
+    /// Get exclusive access to this data.
+    ///
+    /// See also [`Self::assert_get_mut()`], [`Self::get_mut_on_main_thread()`]
+    #[inline(always)]
+    pub fn get_mut(&mut self, _: MainThreadToken) -> &mut T {
+        &mut self.0
+    }
+
+    /// Get shared access to this data. Panic if not called on the main thread.
+    ///
+    /// See also [`Self::get()`], [`Self::get_on_main_thread()`]
+    #[track_caller]
+    #[inline(always)]
+    pub fn assert_get(&self) -> &T {
+        self.get(MainThreadToken::assert())
+    }
+
+    /// Get exclusive access to this data. Panic if not called on the main thread.
+    ///
+    /// See also [`Self::get_mut()`], [`Self::get_mut_on_main_thread()`]
+    #[track_caller]
+    #[inline(always)]
+    pub fn assert_get_mut(&mut self) -> &mut T {
+        self.get_mut(MainThreadToken::assert())
+    }
+
+    /// Get shared access to this data in a callback that's run on the main thread.
+    /// This method waits for the callback to complete before returning.
+    ///
+    /// If this is called on a thread other than the main thread, it requires the SDL
+    /// event loop to run. See [`SDL_RunOnMainThread`] for details.
+    ///
+    /// Returns false if the callback failed to run.
+    ///
+    /// See also [`run_sync_on_main_thread()`], [`Self::get()`], [`Self::assert_get()`]
+    #[must_use]
+    #[inline(always)]
+    pub fn get_on_main_thread(&self, callback: impl FnOnce(&T) + Send) -> bool {
+        run_sync_on_main_thread(move || callback(&self.0))
+    }
+
+    /// Get exclusive access to this data in a callback that's run on the main thread.
+    /// This method waits for the callback to complete before returning.
+    ///
+    /// If this is called on a thread other than the main thread, it requires the SDL
+    /// event loop to run. See [`SDL_RunOnMainThread`] for details.
+    ///
+    /// Returns false if the callback failed to run.
+    ///
+    /// See also [`run_sync_on_main_thread()`], [`Self::get_mut()`], [`Self::assert_get_mut()`]
+    #[must_use]
+    #[inline(always)]
+    pub fn get_mut_on_main_thread(&mut self, callback: impl FnOnce(&mut T) + Send) -> bool {
+        run_sync_on_main_thread(move || callback(&mut self.0))
+    }
+}
+
 struct CallOnceContainer<F>(Option<F>);
 
 trait CallOnce {
@@ -0,0 +1,764 @@
+//! A `std::process`-like API for `SDL_Process`
+//!
+//! - [`Command`]\: Builder for spawning a process, with arguments, environment, working
+//!   directory and standard I/O configuration.
+//! - [`Child`]\: A running process, with pipes for its standard I/O that implement
+//!   `Read` and `Write`.
+//! - [`ExitStatus`]\: The decoded exit code of a finished process.
+//!
+//! Example:
+//! ```rust,ignore
+//! use sdl3_main::process::Command;
+//!
+//! let output = Command::new("/bin/sh")
+//!     .arg("-c")
+//!     .arg("echo $GREETING")
+//!     .env("GREETING", "hello")
+//!     .output()?;
+//! assert!(output.status.success());
+//! assert_eq!(output.stdout, b"hello\n");
+//! ```
+
+use crate::{
+    io::IoStream,
+    properties::{Properties, PropertiesRef},
+};
+use alloc::{ffi::CString, sync::Arc, vec::Vec};
+use core::{
+    ffi::{c_char, c_int, c_void, CStr},
+    fmt::{self, Debug, Display, Formatter},
+    mem::ManuallyDrop,
+    ptr,
+};
+use sdl3_sys::{
+    error::SDL_GetError,
+    iostream::SDL_IOStream,
+    process::{
+        SDL_CreateProcessWithProperties, SDL_DestroyProcess, SDL_GetProcessProperties,
+        SDL_KillProcess, SDL_Process, SDL_ProcessIO, SDL_WaitProcess, SDL_PROCESS_STDIO_APP,
+        SDL_PROCESS_STDIO_INHERITED, SDL_PROCESS_STDIO_NULL, SDL_PROCESS_STDIO_REDIRECT,
+        SDL_PROP_PROCESS_BACKGROUND_BOOLEAN, SDL_PROP_PROCESS_CREATE_ARGS_POINTER,
+        SDL_PROP_PROCESS_CREATE_BACKGROUND_BOOLEAN, SDL_PROP_PROCESS_CREATE_ENVIRONMENT_POINTER,
+        SDL_PROP_PROCESS_CREATE_STDERR_NUMBER, SDL_PROP_PROCESS_CREATE_STDERR_POINTER,
+        SDL_PROP_PROCESS_CREATE_STDERR_TO_STDOUT_BOOLEAN, SDL_PROP_PROCESS_CREATE_STDIN_NUMBER,
+        SDL_PROP_PROCESS_CREATE_STDIN_POINTER, SDL_PROP_PROCESS_CREATE_STDOUT_NUMBER,
+        SDL_PROP_PROCESS_CREATE_STDOUT_POINTER, SDL_PROP_PROCESS_CREATE_WORKING_DIRECTORY_STRING,
+        SDL_PROP_PROCESS_PID_NUMBER, SDL_PROP_PROCESS_STDERR_POINTER,
+        SDL_PROP_PROCESS_STDIN_POINTER, SDL_PROP_PROCESS_STDOUT_POINTER,
+    },
+    stdinc::{
+        SDL_CreateEnvironment, SDL_DestroyEnvironment, SDL_Environment, SDL_SetEnvironmentVariable,
+        SDL_UnsetEnvironmentVariable,
+    },
+    timer::SDL_Delay,
+};
+use std::{
+    ffi::OsStr,
+    io::{self, Read, Write},
+    path::Path,
+    thread,
+};
+
+// Only used with the SDL_PROP_* constants, which are static C strings
+fn prop(name: *const c_char) -> &'static CStr {
+    unsafe { CStr::from_ptr(name) }
+}
+
+fn sdl_error() -> io::Error {
+    let message = unsafe { CStr::from_ptr(SDL_GetError()) };
+    io::Error::other(message.to_string_lossy().into_owned())
+}
+
+fn invalid_input() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        "invalid string in process arguments, environment or working directory",
+    )
+}
+
+// SDL passes strings through unchanged on Unix, but expects UTF-8 elsewhere
+fn to_cstring(s: &OsStr) -> Option<CString> {
+    #[cfg(unix)]
+    let bytes = std::os::unix::ffi::OsStrExt::as_bytes(s);
+    #[cfg(not(unix))]
+    let bytes = s.to_str()?.as_bytes();
+    CString::new(bytes).ok()
+}
+
+enum StdioKind {
+    Inherit,
+    Null,
+    Piped,
+    Redirect(IoStream),
+}
+
+/// How to set up a standard I/O stream of a spawned process.
+///
+/// Used with [`Command::stdin()`], [`Command::stdout()`] and [`Command::stderr()`].
+pub struct Stdio(StdioKind);
+
+impl Stdio {
+    /// Use the standard I/O stream of the parent process.
+    #[inline]
+    pub fn inherit() -> Self {
+        Self(StdioKind::Inherit)
+    }
+
+    /// Connect the stream to nothing, like `/dev/null`.
+    #[inline]
+    pub fn null() -> Self {
+        Self(StdioKind::Null)
+    }
+
+    /// Create a pipe that can be accessed through the [`Child`].
+    #[inline]
+    pub fn piped() -> Self {
+        Self(StdioKind::Piped)
+    }
+
+    fn mode(&self) -> SDL_ProcessIO {
+        match self.0 {
+            StdioKind::Inherit => SDL_PROCESS_STDIO_INHERITED,
+            StdioKind::Null => SDL_PROCESS_STDIO_NULL,
+            StdioKind::Piped => SDL_PROCESS_STDIO_APP,
+            StdioKind::Redirect(_) => SDL_PROCESS_STDIO_REDIRECT,
+        }
+    }
+}
+
+impl Debug for Stdio {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            StdioKind::Inherit => f.write_str("Inherit"),
+            StdioKind::Null => f.write_str("Null"),
+            StdioKind::Piped => f.write_str("Piped"),
+            StdioKind::Redirect(stream) => {
+                f.debug_tuple("Redirect").field(&stream.as_raw()).finish()
+            }
+        }
+    }
+}
+
+/// Connect the stream to an existing `SDL_IOStream`. The stream must be backed by a file
+/// descriptor or Windows handle, like streams for files and process I/O are. It's kept
+/// open until the [`Command`] is dropped.
+impl From<IoStream> for Stdio {
+    #[inline]
+    fn from(value: IoStream) -> Self {
+        Self(StdioKind::Redirect(value))
+    }
+}
+
+/// Builder for spawning a process.
+///
+/// This works like [`std::process::Command`]. Unlike with `std`, the default for
+/// standard input is [`Stdio::null()`], to match SDL.
+#[derive(Debug)]
+pub struct Command {
+    args: Vec<Option<CString>>,
+    env: Vec<(Option<CString>, Option<Option<CString>>)>,
+    env_clear: bool,
+    current_dir: Option<Option<CString>>,
+    stdin: Option<Stdio>,
+    stdout: Option<Stdio>,
+    stderr: Option<Stdio>,
+    stderr_to_stdout: bool,
+    background: bool,
+}
+
+impl Command {
+    /// Create a new command for running `program`. If `program` isn't a path, it's searched
+    /// for in the `PATH` environment variable.
+    pub fn new(program: impl AsRef<OsStr>) -> Self {
+        Self {
+            args: Vec::from([to_cstring(program.as_ref())]),
+            env: Vec::new(),
+            env_clear: false,
+            current_dir: None,
+            stdin: None,
+            stdout: None,
+            stderr: None,
+            stderr_to_stdout: false,
+            background: false,
+        }
+    }
+
+    /// Add an argument.
+    #[inline]
+    pub fn arg(&mut self, arg: impl AsRef<OsStr>) -> &mut Self {
+        self.args.push(to_cstring(arg.as_ref()));
+        self
+    }
+
+    /// Add multiple arguments.
+    pub fn args<I: IntoIterator<Item = S>, S: AsRef<OsStr>>(&mut self, args: I) -> &mut Self {
+        for arg in args {
+            self.arg(arg);
+        }
+        self
+    }
+
+    /// Set an environment variable for the process.
+    #[inline]
+    pub fn env(&mut self, key: impl AsRef<OsStr>, value: impl AsRef<OsStr>) -> &mut Self {
+        self.env
+            .push((to_cstring(key.as_ref()), Some(to_cstring(value.as_ref()))));
+        self
+    }
+
+    /// Set multiple environment variables for the process.
+    pub fn envs<I, K, V>(&mut self, vars: I) -> &mut Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<OsStr>,
+        V: AsRef<OsStr>,
+    {
+        for (key, value) in vars {
+            self.env(key, value);
+        }
+        self
+    }
+
+    /// Remove an environment variable for the process.
+    #[inline]
+    pub fn env_remove(&mut self, key: impl AsRef<OsStr>) -> &mut Self {
+        self.env.push((to_cstring(key.as_ref()), None));
+        self
+    }
+
+    /// Don't inherit any environment variables from the parent process. This also discards
+    /// variables set with [`env()`](Command::env) before this call.
+    #[inline]
+    pub fn env_clear(&mut self) -> &mut Self {
+        self.env.clear();
+        self.env_clear = true;
+        self
+    }
+
+    /// Set the working directory for the process.
+    #[inline]
+    pub fn current_dir(&mut self, dir: impl AsRef<Path>) -> &mut Self {
+        self.current_dir = Some(to_cstring(dir.as_ref().as_os_str()));
+        self
+    }
+
+    /// Set up standard input. Defaults to [`Stdio::null()`].
+    #[inline]
+    pub fn stdin(&mut self, cfg: impl Into<Stdio>) -> &mut Self {
+        self.stdin = Some(cfg.into());
+        self
+    }
+
+    /// Set up standard output. Defaults to [`Stdio::inherit()`] for [`spawn()`](Command::spawn)
+    /// and [`status()`](Command::status), and [`Stdio::piped()`] for
+    /// [`output()`](Command::output).
+    #[inline]
+    pub fn stdout(&mut self, cfg: impl Into<Stdio>) -> &mut Self {
+        self.stdout = Some(cfg.into());
+        self
+    }
+
+    /// Set up standard error. Defaults are the same as for [`stdout()`](Command::stdout).
+    #[inline]
+    pub fn stderr(&mut self, cfg: impl Into<Stdio>) -> &mut Self {
+        self.stderr = Some(cfg.into());
+        self
+    }
+
+    /// Send standard error to the same place as standard output. If this is set, the
+    /// [`stderr()`](Command::stderr) setting is ignored, and the
+    /// [`stderr`](Output::stderr) of [`output()`](Command::output) is empty.
+    #[inline]
+    pub fn stderr_to_stdout(&mut self, enable: bool) -> &mut Self {
+        self.stderr_to_stdout = enable;
+        self
+    }
+
+    /// Run the process in the background. Background processes default to [`Stdio::null()`]
+    /// for all streams, and always report a successful exit status.
+    #[inline]
+    pub fn background(&mut self, enable: bool) -> &mut Self {
+        self.background = enable;
+        self
+    }
+
+    /// Spawn the process.
+    pub fn spawn(&mut self) -> io::Result<Child> {
+        self.spawn_with_default(None)
+    }
+
+    /// Spawn the process and wait for it to finish, collecting its output.
+    pub fn output(&mut self) -> io::Result<Output> {
+        self.spawn_with_default(Some(Stdio::piped()))?
+            .wait_with_output()
+    }
+
+    /// Spawn the process and wait for it to finish.
+    pub fn status(&mut self) -> io::Result<ExitStatus> {
+        self.spawn_with_default(None)?.wait()
+    }
+
+    fn spawn_with_default(&mut self, default_output: Option<Stdio>) -> io::Result<Child> {
+        let mut args = Vec::with_capacity(self.args.len() + 1);
+        for arg in &self.args {
+            args.push(arg.as_deref().ok_or_else(invalid_input)?.as_ptr());
+        }
+        args.push(ptr::null());
+
+        let env = Environment::new(self)?;
+        let mut builder = unsafe {
+            Properties::builder()
+                .with_pointer(
+                    prop(SDL_PROP_PROCESS_CREATE_ARGS_POINTER),
+                    args.as_ptr() as *mut c_void,
+                )
+                .with_pointer(
+                    prop(SDL_PROP_PROCESS_CREATE_ENVIRONMENT_POINTER),
+                    env.0 as *mut c_void,
+                )
+        };
+        if let Some(dir) = &self.current_dir {
+            builder = builder.with(
+                prop(SDL_PROP_PROCESS_CREATE_WORKING_DIRECTORY_STRING),
+                dir.as_deref().ok_or_else(invalid_input)?,
+            );
+        }
+        let default_output = default_output.as_ref();
+        for (stdio, number, pointer) in [
+            (
+                self.stdin.as_ref(),
+                SDL_PROP_PROCESS_CREATE_STDIN_NUMBER,
+                SDL_PROP_PROCESS_CREATE_STDIN_POINTER,
+            ),
+            (
+                self.stdout.as_ref().or(default_output),
+                SDL_PROP_PROCESS_CREATE_STDOUT_NUMBER,
+                SDL_PROP_PROCESS_CREATE_STDOUT_POINTER,
+            ),
+            (
+                // stderr shares stdout's stream, so its own settings must be left unset
+                (!self.stderr_to_stdout)
+                    .then(|| self.stderr.as_ref().or(default_output))
+                    .flatten(),
+                SDL_PROP_PROCESS_CREATE_STDERR_NUMBER,
+                SDL_PROP_PROCESS_CREATE_STDERR_POINTER,
+            ),
+        ] {
+            if let Some(stdio) = stdio {
+                builder = builder.with(prop(number), stdio.mode().0 as i64);
+                if let StdioKind::Redirect(stream) = &stdio.0 {
+                    builder = unsafe {
+                        builder.with_pointer(prop(pointer), stream.as_raw() as *mut c_void)
+                    };
+                }
+            }
+        }
+        let props = builder
+            .with(
+                prop(SDL_PROP_PROCESS_CREATE_STDERR_TO_STDOUT_BOOLEAN),
+                self.stderr_to_stdout,
+            )
+            .with(
+                prop(SDL_PROP_PROCESS_CREATE_BACKGROUND_BOOLEAN),
+                self.background,
+            )
+            .build()
+            .ok_or_else(sdl_error)?;
+
+        let process = unsafe { SDL_CreateProcessWithProperties(props.id()) };
+        if process.is_null() {
+            return Err(sdl_error());
+        }
+        Ok(Child::new(process))
+    }
+}
+
+// An SDL_Environment for a Command, or null to inherit the parent's environment
+struct Environment(*mut SDL_Environment);
+
+impl Environment {
+    fn new(command: &Command) -> io::Result<Self> {
+        if !command.env_clear && command.env.is_empty() {
+            return Ok(Self(ptr::null_mut()));
+        }
+        let env = Self(unsafe { SDL_CreateEnvironment(!command.env_clear) });
+        if env.0.is_null() {
+            return Err(sdl_error());
+        }
+        for (key, value) in &command.env {
+            let key = key.as_deref().ok_or_else(invalid_input)?;
+            let ok = match value {
+                Some(value) => {
+                    let value = value.as_deref().ok_or_else(invalid_input)?;
+                    unsafe { SDL_SetEnvironmentVariable(env.0, key.as_ptr(), value.as_ptr(), true) }
+                }
+                None => unsafe { SDL_UnsetEnvironmentVariable(env.0, key.as_ptr()) },
+            };
+            if !ok {
+                return Err(sdl_error());
+            }
+        }
+        Ok(env)
+    }
+}
+
+impl Drop for Environment {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            unsafe { SDL_DestroyEnvironment(self.0) };
+        }
+    }
+}
+
+/// The exit status of a finished process.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExitStatus(c_int);
+
+impl ExitStatus {
+    const UNKNOWN: c_int = -255;
+
+    /// Create an exit status from the exit code reported by [`SDL_WaitProcess`].
+    #[inline(always)]
+    pub const fn from_raw(exitcode: c_int) -> Self {
+        Self(exitcode)
+    }
+
+    /// Get the exit code as reported by [`SDL_WaitProcess`]: the exit code if the process
+    /// exited normally, the negated signal number if it was terminated by a signal, or
+    /// -255 otherwise.
+    #[inline(always)]
+    pub const fn into_raw(self) -> c_int {
+        self.0
+    }
+
+    /// Returns true if the process exited normally with exit code 0.
+    #[inline(always)]
+    pub const fn success(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Get the exit code if the process exited normally.
+    #[inline]
+    pub const fn code(&self) -> Option<i32> {
+        if self.0 >= 0 {
+            Some(self.0)
+        } else {
+            None
+        }
+    }
+
+    /// Get the number of the signal that terminated the process, if any.
+    #[inline]
+    pub const fn signal(&self) -> Option<i32> {
+        if self.0 < 0 && self.0 != Self::UNKNOWN {
+            Some(-self.0)
+        } else {
+            None
+        }
+    }
+}
+
+impl Debug for ExitStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "ExitStatus({self})")
+    }
+}
+
+impl Display for ExitStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(code) = self.code() {
+            write!(f, "exit status: {code}")
+        } else if let Some(signal) = self.signal() {
+            write!(f, "signal: {signal}")
+        } else {
+            f.write_str("unknown exit status")
+        }
+    }
+}
+
+/// The output of a finished process, returned by [`Command::output()`] and
+/// [`Child::wait_with_output()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Output {
+    /// The exit status of the process
+    pub status: ExitStatus,
+
+    /// Everything the process wrote to standard output, if it was piped
+    pub stdout: Vec<u8>,
+
+    /// Everything the process wrote to standard error, if it was piped
+    pub stderr: Vec<u8>,
+}
+
+struct ProcessHandle(*mut SDL_Process);
+
+// The pipes only use their own stream and the thread safe properties API, so the handle can
+// be shared between threads. Everything else goes through `&mut Child`
+unsafe impl Send for ProcessHandle {}
+unsafe impl Sync for ProcessHandle {}
+
+impl ProcessHandle {
+    fn properties(&self) -> PropertiesRef<'_> {
+        unsafe { PropertiesRef::from_id(SDL_GetProcessProperties(self.0)) }
+    }
+}
+
+impl Drop for ProcessHandle {
+    fn drop(&mut self) {
+        unsafe { SDL_DestroyProcess(self.0) };
+    }
+}
+
+/// A spawned process, created with [`Command::spawn()`].
+///
+/// Like with `std`, dropping a `Child` doesn't kill or wait for the process.
+pub struct Child {
+    process: Arc<ProcessHandle>,
+    status: Option<ExitStatus>,
+
+    /// The write end of the standard input pipe, if it was set to [`Stdio::piped()`].
+    /// Drop this to close the pipe.
+    pub stdin: Option<ChildStdin>,
+
+    /// The read end of the standard output pipe, if it was set to [`Stdio::piped()`]
+    pub stdout: Option<ChildStdout>,
+
+    /// The read end of the standard error pipe, if it was set to [`Stdio::piped()`]
+    pub stderr: Option<ChildStderr>,
+}
+
+impl Debug for Child {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Child")
+            .field("id", &self.id())
+            .field("status", &self.status)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Child {
+    fn new(process: *mut SDL_Process) -> Self {
+        let process = Arc::new(ProcessHandle(process));
+        Self {
+            stdin: Pipe::new(&process, prop(SDL_PROP_PROCESS_STDIN_POINTER)).map(ChildStdin),
+            stdout: Pipe::new(&process, prop(SDL_PROP_PROCESS_STDOUT_POINTER)).map(ChildStdout),
+            stderr: Pipe::new(&process, prop(SDL_PROP_PROCESS_STDERR_POINTER)).map(ChildStderr),
+            process,
+            status: None,
+        }
+    }
+
+    /// Get the raw `SDL_Process` handle.
+    #[inline(always)]
+    pub fn as_raw(&self) -> *mut SDL_Process {
+        self.process.0
+    }
+
+    /// Get the properties of the process.
+    #[inline]
+    pub fn properties(&self) -> PropertiesRef<'_> {
+        self.process.properties()
+    }
+
+    /// Get the OS assigned process ID.
+    #[inline]
+    pub fn id(&self) -> u32 {
+        self.properties()
+            .get::<i64>(prop(SDL_PROP_PROCESS_PID_NUMBER))
+            .unwrap_or(0) as u32
+    }
+
+    /// Returns true if the process was spawned in the background.
+    #[inline]
+    pub fn is_background(&self) -> bool {
+        self.properties()
+            .get::<bool>(prop(SDL_PROP_PROCESS_BACKGROUND_BOOLEAN))
+            .unwrap_or(false)
+    }
+
+    /// Forcibly kill the process. Does nothing if the process has already finished.
+    #[inline]
+    pub fn kill(&mut self) -> io::Result<()> {
+        self.stop(true)
+    }
+
+    /// Ask the process to stop, e.g. with `SIGTERM` on Unix. Does nothing if the process
+    /// has already finished.
+    #[inline]
+    pub fn terminate(&mut self) -> io::Result<()> {
+        self.stop(false)
+    }
+
+    fn stop(&mut self, force: bool) -> io::Result<()> {
+        if self.status.is_some() || unsafe { SDL_KillProcess(self.process.0, force) } {
+            Ok(())
+        } else {
+            Err(sdl_error())
+        }
+    }
+
+    /// Wait for the process to finish. Standard input is closed first, so that a process
+    /// waiting for input doesn't keep running.
+    pub fn wait(&mut self) -> io::Result<ExitStatus> {
+        drop(self.stdin.take());
+        match self.wait_raw(true)? {
+            Some(status) => Ok(status),
+            None => Err(sdl_error()),
+        }
+    }
+
+    /// Get the exit status if the process has finished, without blocking.
+    #[inline]
+    pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
+        self.wait_raw(false)
+    }
+
+    fn wait_raw(&mut self, block: bool) -> io::Result<Option<ExitStatus>> {
+        if self.status.is_none() {
+            let mut exitcode = 0;
+            if unsafe { SDL_WaitProcess(self.process.0, block, &mut exitcode) } {
+                self.status = Some(ExitStatus(exitcode));
+            } else if block {
+                return Err(sdl_error());
+            }
+        }
+        Ok(self.status)
+    }
+
+    /// Wait for the process to finish, and collect all remaining output from its piped
+    /// standard output and standard error.
+    pub fn wait_with_output(mut self) -> io::Result<Output> {
+        drop(self.stdin.take());
+        let (mut stdout, mut stderr) = (Vec::new(), Vec::new());
+        match (self.stdout.take(), self.stderr.take()) {
+            (None, None) => (),
+            (Some(mut out), None) => {
+                out.read_to_end(&mut stdout)?;
+            }
+            (None, Some(mut err)) => {
+                err.read_to_end(&mut stderr)?;
+            }
+            (Some(mut out), Some(mut err)) => {
+                // read both at once, so the process can't block on a full pipe
+                thread::scope(|scope| {
+                    let err = scope.spawn(|| err.read_to_end(&mut stderr));
+                    let out = out.read_to_end(&mut stdout);
+                    err.join().unwrap().and(out)
+                })?;
+            }
+        }
+        Ok(Output {
+            status: self.wait()?,
+            stdout,
+            stderr,
+        })
+    }
+}
+
+// A pipe to or from a process. SDL owns the stream, and closes it when the property that
+// holds it is cleared
+struct Pipe {
+    stream: ManuallyDrop<IoStream>,
+    property: &'static CStr,
+    process: Arc<ProcessHandle>,
+}
+
+impl Pipe {
+    fn new(process: &Arc<ProcessHandle>, property: &'static CStr) -> Option<Self> {
+        let stream = process.properties().get::<*mut c_void>(property)? as *mut SDL_IOStream;
+        Some(Self {
+            stream: ManuallyDrop::new(unsafe { IoStream::from_raw_borrowed(stream) }),
+            property,
+            process: Arc::clone(process),
+        })
+    }
+
+    // Pipes may not be ready even if they're still open, so wait and retry like
+    // SDL_ReadProcess does
+    fn retry<T>(&mut self, mut f: impl FnMut(&mut IoStream) -> io::Result<T>) -> io::Result<T> {
+        loop {
+            match f(&mut self.stream) {
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => unsafe { SDL_Delay(1) },
+                result => return result,
+            }
+        }
+    }
+}
+
+impl Drop for Pipe {
+    fn drop(&mut self) {
+        unsafe { ManuallyDrop::drop(&mut self.stream) };
+        self.process.properties().clear(self.property);
+    }
+}
+
+impl Debug for Pipe {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Pipe").field(&self.stream.as_raw()).finish()
+    }
+}
+
+/// The write end of a process's standard input pipe. The pipe is closed when this is
+/// dropped.
+///
+/// Writes block until all data has been accepted by the pipe.
+#[derive(Debug)]
+pub struct ChildStdin(Pipe);
+
+impl ChildStdin {
+    /// Get the underlying `SDL_IOStream`. It's owned by the process.
+    #[inline(always)]
+    pub fn as_raw(&self) -> *mut SDL_IOStream {
+        self.0.stream.as_raw()
+    }
+}
+
+impl Write for ChildStdin {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.retry(|stream| stream.write(buf))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.retry(|stream| stream.flush())
+    }
+}
+
+/// The read end of a process's standard output pipe.
+///
+/// Reads block until data is available or the pipe is closed.
+#[derive(Debug)]
+pub struct ChildStdout(Pipe);
+
+impl ChildStdout {
+    /// Get the underlying `SDL_IOStream`. It's owned by the process.
+    #[inline(always)]
+    pub fn as_raw(&self) -> *mut SDL_IOStream {
+        self.0.stream.as_raw()
+    }
+}
+
+impl Read for ChildStdout {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.retry(|stream| stream.read(buf))
+    }
+}
+
+/// The read end of a process's standard error pipe.
+///
+/// Reads block until data is available or the pipe is closed.
+#[derive(Debug)]
+pub struct ChildStderr(Pipe);
+
+impl ChildStderr {
+    /// Get the underlying `SDL_IOStream`. It's owned by the process.
+    #[inline(always)]
+    pub fn as_raw(&self) -> *mut SDL_IOStream {
+        self.0.stream.as_raw()
+    }
+}
+
+impl Read for ChildStderr {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.retry(|stream| stream.read(buf))
+    }
+}
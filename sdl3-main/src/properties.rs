@@ -0,0 +1,499 @@
+//! Safe wrappers for SDL property sets
+//!
+//! - [`Properties`]\: An owned property set that's destroyed on drop. Use
+//!   [`Properties::builder()`] to create temporary sets for `*WithProperties` functions.
+//! - [`PropertiesRef`]\: A borrowed view of a property set owned by someone else, such as
+//!   the global properties or the properties of an SDL object.
+//! - [`PropertiesLock`]\: Lock guard for a property set.
+//!
+//! Property names are passed as `&CStr`. The property name constants in `sdl3-sys` are raw
+//! pointers to C string literals, and can be converted with [`CStr::from_ptr()`].
+//!
+//! Example:
+//! ```rust,ignore
+//! use sdl3_main::properties::Properties;
+//! use sdl3_sys::video::*;
+//!
+//! let props = Properties::builder()
+//!     .with(c"SDL.window.create.title", c"Hello")
+//!     .with(c"SDL.window.create.width", 640_i64)
+//!     .with(c"SDL.window.create.height", 480_i64)
+//!     .build()
+//!     .ok_or("couldn't create properties")?;
+//! let window = unsafe { SDL_CreateWindowWithProperties(props.id()) };
+//! ```
+
+use alloc::{ffi::CString, vec::Vec};
+use core::{
+    ffi::{c_char, c_void, CStr},
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+    ops::Deref,
+    ptr,
+};
+use sdl3_sys::properties::{
+    SDL_CleanupPropertyCallback, SDL_ClearProperty, SDL_CopyProperties, SDL_CreateProperties,
+    SDL_DestroyProperties, SDL_EnumerateProperties, SDL_GetBooleanProperty, SDL_GetFloatProperty,
+    SDL_GetGlobalProperties, SDL_GetNumberProperty, SDL_GetPointerProperty, SDL_GetPropertyType,
+    SDL_GetStringProperty, SDL_HasProperty, SDL_LockProperties, SDL_PropertiesID, SDL_PropertyType,
+    SDL_SetBooleanProperty, SDL_SetFloatProperty, SDL_SetNumberProperty, SDL_SetPointerProperty,
+    SDL_SetPointerPropertyWithCleanup, SDL_SetStringProperty, SDL_UnlockProperties,
+};
+
+/// The value of a property, with its type.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PropertyValue {
+    /// A pointer property
+    Pointer(*mut c_void),
+
+    /// A string property
+    String(CString),
+
+    /// A number property
+    Number(i64),
+
+    /// A float property
+    Float(f32),
+
+    /// A boolean property
+    Boolean(bool),
+}
+
+impl PropertyValue {
+    /// Get the type of this value.
+    pub fn property_type(&self) -> SDL_PropertyType {
+        match self {
+            Self::Pointer(_) => SDL_PropertyType::POINTER,
+            Self::String(_) => SDL_PropertyType::STRING,
+            Self::Number(_) => SDL_PropertyType::NUMBER,
+            Self::Float(_) => SDL_PropertyType::FLOAT,
+            Self::Boolean(_) => SDL_PropertyType::BOOLEAN,
+        }
+    }
+}
+
+/// Types that can be read from a property with [`PropertiesRef::get()`].
+///
+/// This is implemented for `*mut c_void`, `CString`, `i64`, `f32` and `bool`, corresponding
+/// to the property types `POINTER`, `STRING`, `NUMBER`, `FLOAT` and `BOOLEAN`, and for
+/// [`PropertyValue`] which can hold any of them.
+pub trait GetProperty: sealed::GetProperty {
+    #[doc(hidden)]
+    fn get(props: SDL_PropertiesID, name: &CStr, ty: SDL_PropertyType) -> Option<Self>;
+}
+
+/// Types that can be stored in a property with [`PropertiesRef::set()`].
+///
+/// This is implemented for `&CStr`, `CString`, `i64`, `f32` and `bool`. Pointers can be set
+/// with the unsafe [`PropertiesRef::set_pointer()`].
+pub trait SetProperty: sealed::SetProperty {
+    #[doc(hidden)]
+    fn set(self, props: SDL_PropertiesID, name: &CStr) -> bool;
+}
+
+mod sealed {
+    pub trait GetProperty: Sized {}
+    pub trait SetProperty: Sized {}
+}
+
+macro_rules! impl_property {
+    ($($ty:ty: $variant:ident, $get:ident($default:expr), $set:ident;)*) => {$(
+        impl sealed::GetProperty for $ty {}
+        impl GetProperty for $ty {
+            #[inline]
+            fn get(props: SDL_PropertiesID, name: &CStr, ty: SDL_PropertyType) -> Option<Self> {
+                (ty == SDL_PropertyType::$variant)
+                    .then(|| unsafe { $get(props, name.as_ptr(), $default) })
+            }
+        }
+
+        impl sealed::SetProperty for $ty {}
+        impl SetProperty for $ty {
+            #[inline]
+            fn set(self, props: SDL_PropertiesID, name: &CStr) -> bool {
+                unsafe { $set(props, name.as_ptr(), self) }
+            }
+        }
+    )*};
+}
+
+impl_property! {
+    i64: NUMBER, SDL_GetNumberProperty(0), SDL_SetNumberProperty;
+    f32: FLOAT, SDL_GetFloatProperty(0.0), SDL_SetFloatProperty;
+    bool: BOOLEAN, SDL_GetBooleanProperty(false), SDL_SetBooleanProperty;
+}
+
+impl sealed::GetProperty for *mut c_void {}
+impl GetProperty for *mut c_void {
+    #[inline]
+    fn get(props: SDL_PropertiesID, name: &CStr, ty: SDL_PropertyType) -> Option<Self> {
+        (ty == SDL_PropertyType::POINTER)
+            .then(|| unsafe { SDL_GetPointerProperty(props, name.as_ptr(), ptr::null_mut()) })
+    }
+}
+
+impl sealed::GetProperty for CString {}
+impl GetProperty for CString {
+    fn get(props: SDL_PropertiesID, name: &CStr, ty: SDL_PropertyType) -> Option<Self> {
+        if ty != SDL_PropertyType::STRING {
+            return None;
+        }
+        // the string is only valid until the property changes, so copy it while locked
+        unsafe {
+            SDL_LockProperties(props);
+            let value = SDL_GetStringProperty(props, name.as_ptr(), ptr::null());
+            let value = (!value.is_null()).then(|| CStr::from_ptr(value).into());
+            SDL_UnlockProperties(props);
+            value
+        }
+    }
+}
+
+impl sealed::GetProperty for PropertyValue {}
+impl GetProperty for PropertyValue {
+    fn get(props: SDL_PropertiesID, name: &CStr, ty: SDL_PropertyType) -> Option<Self> {
+        Some(match ty {
+            SDL_PropertyType::POINTER => Self::Pointer(GetProperty::get(props, name, ty)?),
+            SDL_PropertyType::STRING => Self::String(GetProperty::get(props, name, ty)?),
+            SDL_PropertyType::NUMBER => Self::Number(GetProperty::get(props, name, ty)?),
+            SDL_PropertyType::FLOAT => Self::Float(GetProperty::get(props, name, ty)?),
+            SDL_PropertyType::BOOLEAN => Self::Boolean(GetProperty::get(props, name, ty)?),
+            _ => return None,
+        })
+    }
+}
+
+impl sealed::SetProperty for &CStr {}
+impl SetProperty for &CStr {
+    #[inline]
+    fn set(self, props: SDL_PropertiesID, name: &CStr) -> bool {
+        unsafe { SDL_SetStringProperty(props, name.as_ptr(), self.as_ptr()) }
+    }
+}
+
+impl sealed::SetProperty for CString {}
+impl SetProperty for CString {
+    #[inline]
+    fn set(self, props: SDL_PropertiesID, name: &CStr) -> bool {
+        self.as_c_str().set(props, name)
+    }
+}
+
+/// A borrowed property set. The set isn't destroyed when this is dropped.
+///
+/// The lifetime parameter is the lifetime of the owner of the set.
+/// [`Properties`] dereferences to this type, so all these methods can be used on owned
+/// property sets too.
+///
+/// Setting a property returns false on failure; call
+/// [`SDL_GetError`](sdl3_sys::error::SDL_GetError) for more information.
+pub struct PropertiesRef<'a> {
+    id: SDL_PropertiesID,
+    _owner: PhantomData<&'a ()>,
+}
+
+impl PropertiesRef<'static> {
+    /// Get the global property set. See [`SDL_GetGlobalProperties`].
+    ///
+    /// Returns `None` on failure; call [`SDL_GetError`](sdl3_sys::error::SDL_GetError)
+    /// for more information.
+    pub fn global() -> Option<Self> {
+        let id = unsafe { SDL_GetGlobalProperties() };
+        (id.0 != 0).then(|| unsafe { Self::from_id(id) })
+    }
+}
+
+impl PropertiesRef<'_> {
+    /// Borrow an existing property set, such as the set returned by `SDL_GetWindowProperties`.
+    ///
+    /// # Safety
+    /// `id` must be a valid property set that isn't destroyed while this exists.
+    #[inline(always)]
+    pub unsafe fn from_id(id: SDL_PropertiesID) -> Self {
+        Self {
+            id,
+            _owner: PhantomData,
+        }
+    }
+
+    /// Get the ID of this property set, for passing to SDL functions.
+    #[inline(always)]
+    pub fn id(&self) -> SDL_PropertiesID {
+        self.id
+    }
+
+    /// Check if a property exists.
+    #[inline]
+    pub fn has(&self, name: &CStr) -> bool {
+        unsafe { SDL_HasProperty(self.id, name.as_ptr()) }
+    }
+
+    /// Get the type of a property. This is `SDL_PROPERTY_TYPE_INVALID` if the property
+    /// doesn't exist.
+    #[inline]
+    pub fn property_type(&self, name: &CStr) -> SDL_PropertyType {
+        unsafe { SDL_GetPropertyType(self.id, name.as_ptr()) }
+    }
+
+    /// Get the value of a property, if it exists and has the requested type.
+    ///
+    /// Example:
+    /// ```rust,ignore
+    /// let width: Option<i64> = props.get(c"SDL.window.create.width");
+    /// let any: Option<PropertyValue> = props.get(c"SDL.window.create.title");
+    /// ```
+    #[inline]
+    pub fn get<T: GetProperty>(&self, name: &CStr) -> Option<T> {
+        T::get(self.id, name, self.property_type(name))
+    }
+
+    /// Set a property, replacing any existing value.
+    #[inline]
+    pub fn set(&self, name: &CStr, value: impl SetProperty) -> bool {
+        value.set(self.id, name)
+    }
+
+    /// Set a pointer property, replacing any existing value.
+    ///
+    /// # Safety
+    /// If SDL reads this property, for example when the set is passed to a function taking
+    /// properties, `value` must be valid for the use SDL makes of it.
+    #[inline]
+    pub unsafe fn set_pointer(&self, name: &CStr, value: *mut c_void) -> bool {
+        unsafe { SDL_SetPointerProperty(self.id, name.as_ptr(), value) }
+    }
+
+    /// Set a pointer property with a cleanup function that's called when the property is
+    /// deleted or replaced, or if setting it fails. See [`SDL_SetPointerPropertyWithCleanup`].
+    ///
+    /// # Safety
+    /// See [`set_pointer()`](Self::set_pointer). `cleanup` must be safe to call with
+    /// `userdata` and `value`.
+    #[inline]
+    pub unsafe fn set_pointer_with_cleanup(
+        &self,
+        name: &CStr,
+        value: *mut c_void,
+        cleanup: SDL_CleanupPropertyCallback,
+        userdata: *mut c_void,
+    ) -> bool {
+        unsafe {
+            SDL_SetPointerPropertyWithCleanup(self.id, name.as_ptr(), value, cleanup, userdata)
+        }
+    }
+
+    /// Remove a property.
+    #[inline]
+    pub fn clear(&self, name: &CStr) -> bool {
+        unsafe { SDL_ClearProperty(self.id, name.as_ptr()) }
+    }
+
+    /// Copy all properties to another set, except pointer properties with cleanup functions.
+    /// See [`SDL_CopyProperties`].
+    #[inline]
+    pub fn copy_to(&self, dst: &PropertiesRef) -> bool {
+        unsafe { SDL_CopyProperties(self.id, dst.id) }
+    }
+
+    /// Lock the property set, so other threads can't modify it until the returned guard is
+    /// dropped. The lock is recursive, so properties can still be read and modified on this
+    /// thread. See [`SDL_LockProperties`].
+    ///
+    /// Returns `None` on failure; call [`SDL_GetError`](sdl3_sys::error::SDL_GetError)
+    /// for more information.
+    #[inline]
+    pub fn lock(&self) -> Option<PropertiesLock<'_, '_>> {
+        unsafe { SDL_LockProperties(self.id) }.then_some(PropertiesLock { props: self })
+    }
+
+    /// Get the names and values of all properties in the set.
+    ///
+    /// Properties are read while the set is locked, so the result is a consistent snapshot.
+    pub fn iter(&self) -> impl Iterator<Item = (CString, PropertyValue)> {
+        unsafe extern "C" fn callback(
+            userdata: *mut c_void,
+            props: SDL_PropertiesID,
+            name: *const c_char,
+        ) {
+            let entries = unsafe { &mut *(userdata as *mut Vec<(CString, PropertyValue)>) };
+            let name = unsafe { CStr::from_ptr(name) };
+            let ty = unsafe { SDL_GetPropertyType(props, name.as_ptr()) };
+            if let Some(value) = PropertyValue::get(props, name, ty) {
+                entries.push((name.into(), value));
+            }
+        }
+
+        let mut entries = Vec::new();
+        unsafe {
+            SDL_EnumerateProperties(
+                self.id,
+                Some(callback),
+                &mut entries as *mut Vec<_> as *mut c_void,
+            )
+        };
+        entries.into_iter()
+    }
+}
+
+// SDL property sets are thread safe
+unsafe impl Send for PropertiesRef<'_> {}
+unsafe impl Sync for PropertiesRef<'_> {}
+
+impl Debug for PropertiesRef<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// An owned property set. The set is destroyed when this is dropped.
+///
+/// This dereferences to [`PropertiesRef`], which has the methods for accessing the
+/// properties. Cloning copies all properties except pointer properties with cleanup
+/// functions, like [`SDL_CopyProperties`].
+pub struct Properties {
+    props: PropertiesRef<'static>,
+}
+
+impl Properties {
+    /// Create a new empty property set. See [`SDL_CreateProperties`].
+    ///
+    /// Returns `None` on failure; call [`SDL_GetError`](sdl3_sys::error::SDL_GetError)
+    /// for more information.
+    pub fn new() -> Option<Self> {
+        let id = unsafe { SDL_CreateProperties() };
+        (id.0 != 0).then(|| unsafe { Self::from_id(id) })
+    }
+
+    /// Create a builder for a new property set.
+    #[inline]
+    pub fn builder() -> PropertiesBuilder {
+        PropertiesBuilder { props: Self::new() }
+    }
+
+    /// Take ownership of an existing property set.
+    ///
+    /// # Safety
+    /// `id` must be a valid property set that isn't destroyed by anything else.
+    #[inline(always)]
+    pub unsafe fn from_id(id: SDL_PropertiesID) -> Self {
+        Self {
+            props: unsafe { PropertiesRef::from_id(id) },
+        }
+    }
+
+    /// Release ownership of the property set and return its ID. The caller is responsible for
+    /// destroying it.
+    #[inline]
+    pub fn into_id(self) -> SDL_PropertiesID {
+        let id = self.props.id;
+        core::mem::forget(self);
+        id
+    }
+
+    /// Create a copy of this property set. See [`SDL_CopyProperties`].
+    ///
+    /// Returns `None` on failure; call [`SDL_GetError`](sdl3_sys::error::SDL_GetError)
+    /// for more information.
+    pub fn try_clone(&self) -> Option<Self> {
+        let props = Self::new()?;
+        self.copy_to(&props).then_some(props)
+    }
+}
+
+impl Deref for Properties {
+    type Target = PropertiesRef<'static>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.props
+    }
+}
+
+impl Clone for Properties {
+    /// Create a copy of this property set.
+    ///
+    /// # Panics
+    /// Panics if the copy couldn't be created. Use [`Properties::try_clone()`] to handle
+    /// this case.
+    fn clone(&self) -> Self {
+        self.try_clone().expect("failed to copy properties")
+    }
+}
+
+impl Debug for Properties {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.props, f)
+    }
+}
+
+impl Drop for Properties {
+    fn drop(&mut self) {
+        unsafe { SDL_DestroyProperties(self.props.id) };
+    }
+}
+
+/// Builder for a [`Properties`] set, created with [`Properties::builder()`].
+#[must_use]
+pub struct PropertiesBuilder {
+    props: Option<Properties>,
+}
+
+impl PropertiesBuilder {
+    /// Set a property.
+    #[inline]
+    pub fn with(mut self, name: &CStr, value: impl SetProperty) -> Self {
+        if let Some(props) = &self.props {
+            if !props.set(name, value) {
+                self.props = None;
+            }
+        }
+        self
+    }
+
+    /// Set a pointer property.
+    ///
+    /// # Safety
+    /// See [`PropertiesRef::set_pointer()`].
+    #[inline]
+    pub unsafe fn with_pointer(mut self, name: &CStr, value: *mut c_void) -> Self {
+        if let Some(props) = &self.props {
+            if !unsafe { props.set_pointer(name, value) } {
+                self.props = None;
+            }
+        }
+        self
+    }
+
+    /// Finish building the property set.
+    ///
+    /// Returns `None` if creating the set or setting any property failed; call
+    /// [`SDL_GetError`](sdl3_sys::error::SDL_GetError) for more information.
+    #[inline]
+    pub fn build(self) -> Option<Properties> {
+        self.props
+    }
+}
+
+/// Lock guard for a property set, returned by [`PropertiesRef::lock()`]. The set is unlocked
+/// when this is dropped.
+///
+/// This dereferences to the locked [`PropertiesRef`].
+pub struct PropertiesLock<'a, 'b> {
+    props: &'a PropertiesRef<'b>,
+}
+
+impl<'b> Deref for PropertiesLock<'_, 'b> {
+    type Target = PropertiesRef<'b>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.props
+    }
+}
+
+impl Drop for PropertiesLock<'_, '_> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { SDL_UnlockProperties(self.props.id) };
+    }
+}
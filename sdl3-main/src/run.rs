@@ -0,0 +1,145 @@
+use crate::{app::AppImpl, MainThreadToken};
+use core::{
+    ffi::{c_char, c_int, c_void},
+    mem::MaybeUninit,
+    ptr,
+};
+use sdl3_sys::{events::SDL_PollEvent, init::SDL_AppResult, init::SDL_Quit};
+
+/// Run an app in a classic main loop instead of through the SDL callback API.
+///
+/// This must be called on the main thread, e.g. from a function tagged with [`main`](crate::main).
+/// The app type must implement [`AppImpl`], which you get by using the
+/// [`app_impl`](crate::app_impl) attribute macro with the `main_loop` argument.
+///
+/// The app callbacks are called in the same order and with the same result semantics as with
+/// the SDL callback API:
+/// - `app_init` is called first. If it doesn't return continue status, the loop is skipped.
+/// - Pending events are passed to `app_event`, followed by a call to `app_iterate`, for as
+///   long as both return continue status.
+/// - `app_quit` is called with the final result, regardless of the result of `app_init`,
+///   and then `SDL_Quit` is called.
+///
+/// Returns 0 on success and 1 on failure, like [`SDL_EnterAppMainCallbacks`](sdl3_sys::main::SDL_EnterAppMainCallbacks).
+///
+/// On emscripten, the browser has to regain control between iterations, so if `app_init`
+/// returns continue status, the loop is handed to `emscripten_set_main_loop` and this returns 0
+/// immediately. The app then keeps running after `main` returns, and `app_quit` and `SDL_Quit`
+/// are called when the loop ends. This requires that the runtime isn't exited when `main`
+/// returns, which is the default unless you link with `-sEXIT_RUNTIME=1`.
+///
+/// Example:
+/// ```rust
+/// use core::ffi::c_int;
+/// use sdl3_main::{app_impl, AppResult};
+/// use sdl3_sys::events::SDL_Event;
+/// use std::sync::Mutex;
+///
+/// struct MyApp {
+///     // ...
+/// }
+///
+/// // `#[app_impl]` would use the callback API instead
+/// #[app_impl(main_loop)]
+/// impl MyApp {
+///     fn app_init() -> Option<Box<Mutex<MyApp>>> {
+///         todo!()
+///     }
+///
+///     fn app_iterate(&mut self) -> AppResult {
+///         todo!()
+///     }
+///
+///     fn app_event(&mut self, event: &SDL_Event) -> AppResult {
+///         todo!()
+///     }
+/// }
+///
+/// #[sdl3_main::main]
+/// fn main() -> c_int {
+///     sdl3_main::run::<MyApp>()
+/// }
+/// ```
+#[track_caller]
+pub fn run<A: AppImpl>() -> c_int {
+    unsafe { run_with_args::<A>(0, ptr::null_mut()) }
+}
+
+/// Run an app in a classic main loop, passing `argc` and `argv` to `app_init`.
+///
+/// See [`run()`] for details.
+///
+/// # Safety
+/// `argv` must be null or point to a valid array of length `argc` containing valid c strings
+#[track_caller]
+pub unsafe fn run_with_args<A: AppImpl>(argc: c_int, argv: *mut *mut c_char) -> c_int {
+    let main_thread = MainThreadToken::assert();
+    let mut appstate = ptr::null_mut();
+    let mut result = unsafe { A::init(main_thread, &mut appstate, argc, argv) };
+
+    #[cfg(target_os = "emscripten")]
+    if result == SDL_AppResult::CONTINUE {
+        unsafe { emscripten::set_main_loop::<A>(appstate) };
+        return 0;
+    }
+
+    while result == SDL_AppResult::CONTINUE {
+        result = unsafe { step::<A>(appstate) };
+    }
+
+    unsafe { finish::<A>(appstate, result) }
+}
+
+// Handle pending events and iterate once
+unsafe fn step<A: AppImpl>(appstate: *mut c_void) -> SDL_AppResult {
+    let mut event = MaybeUninit::uninit();
+    while unsafe { SDL_PollEvent(event.as_mut_ptr()) } {
+        let result = unsafe { A::event(appstate, event.as_mut_ptr()) };
+        if result != SDL_AppResult::CONTINUE {
+            return result;
+        }
+    }
+    unsafe { A::iterate(appstate) }
+}
+
+unsafe fn finish<A: AppImpl>(appstate: *mut c_void, result: SDL_AppResult) -> c_int {
+    unsafe {
+        A::quit(appstate, result);
+        SDL_Quit();
+    }
+    (result == SDL_AppResult::FAILURE) as c_int
+}
+
+#[cfg(target_os = "emscripten")]
+mod emscripten {
+    use super::{finish, step};
+    use crate::app::AppImpl;
+    use core::ffi::{c_int, c_void};
+    use sdl3_sys::init::SDL_AppResult;
+
+    extern "C" {
+        fn emscripten_set_main_loop_arg(
+            func: unsafe extern "C" fn(*mut c_void),
+            arg: *mut c_void,
+            fps: c_int,
+            simulate_infinite_loop: bool,
+        );
+        fn emscripten_cancel_main_loop();
+    }
+
+    unsafe extern "C" fn main_loop<A: AppImpl>(appstate: *mut c_void) {
+        let result = unsafe { step::<A>(appstate) };
+        if result != SDL_AppResult::CONTINUE {
+            unsafe {
+                emscripten_cancel_main_loop();
+                finish::<A>(appstate, result);
+            }
+        }
+    }
+
+    // Run the app loop from the browser's event loop. This returns immediately
+    pub unsafe fn set_main_loop<A: AppImpl>(appstate: *mut c_void) {
+        // fps 0 uses requestAnimationFrame
+        unsafe { emscripten_set_main_loop_arg(main_loop::<A>, appstate, 0, false) };
+    }
+}
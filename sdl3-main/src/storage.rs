@@ -0,0 +1,440 @@
+//! Custom [`SDL_Storage`] backends implemented in Rust
+//!
+//! - [`StorageBackend`]\: Trait mirroring [`SDL_StorageInterface`]. Implement this for your
+//!   own storage backend.
+//! - [`StorageBuilder`]\: Create an `SDL_Storage` from a `StorageBackend`.
+//! - [`MemoryStorage`]\: Writable storage that keeps everything in memory. Useful as a
+//!   test double for code that saves and loads through `SDL_Storage`.
+//! - [`ArchiveStorage`]\: Read-only storage for the files in a zip or pak archive.
+//!
+//! Paths passed to backends are relative to the root of the storage and use `/` as
+//! separator. The root directory is the empty string.
+
+use alloc::{borrow::Cow, boxed::Box, collections::BTreeMap, format, string::String};
+use core::{
+    ffi::{c_char, c_void, CStr},
+    fmt::{self, Display, Formatter},
+    slice,
+};
+use sdl3_sys::{
+    error::SDL_SetError,
+    filesystem::{
+        SDL_EnumerateDirectoryCallback, SDL_EnumerationResult, SDL_PathInfo, SDL_ENUM_CONTINUE,
+        SDL_ENUM_FAILURE,
+    },
+    storage::{SDL_OpenStorage, SDL_Storage, SDL_StorageInterface},
+};
+
+mod archive;
+mod memory;
+
+pub use archive::ArchiveStorage;
+pub use memory::MemoryStorage;
+
+/// An error from a [`StorageBackend`]. The error message is passed on to SDL with
+/// `SDL_SetError`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StorageError {
+    /// The path doesn't exist
+    NotFound,
+
+    /// The path already exists
+    AlreadyExists,
+
+    /// The path is a directory, but a file was expected
+    IsADirectory,
+
+    /// The path is a file, but a directory was expected
+    NotADirectory,
+
+    /// The directory to remove isn't empty
+    DirectoryNotEmpty,
+
+    /// The storage can't be written to
+    ReadOnly,
+
+    /// There isn't enough space left in the storage
+    NoSpace,
+
+    /// The length of the destination buffer doesn't match the size of the file
+    LengthMismatch,
+
+    /// The path isn't valid for this storage
+    InvalidPath,
+
+    /// Any other error
+    Other(Cow<'static, str>),
+}
+
+impl StorageError {
+    /// Create a [`StorageError::Other`] with a message.
+    pub fn other(message: impl Into<Cow<'static, str>>) -> Self {
+        Self::Other(message.into())
+    }
+}
+
+impl Display for StorageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::NotFound => "path not found",
+            Self::AlreadyExists => "path already exists",
+            Self::IsADirectory => "path is a directory",
+            Self::NotADirectory => "path is not a directory",
+            Self::DirectoryNotEmpty => "directory is not empty",
+            Self::ReadOnly => "storage is read-only",
+            Self::NoSpace => "not enough space in storage",
+            Self::LengthMismatch => "file length did not exactly match the destination length",
+            Self::InvalidPath => "invalid path",
+            Self::Other(message) => message,
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StorageError {}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for StorageError {
+    fn from(value: std::io::Error) -> Self {
+        use std::io::ErrorKind;
+        match value.kind() {
+            ErrorKind::NotFound => Self::NotFound,
+            ErrorKind::AlreadyExists => Self::AlreadyExists,
+            _ => Self::Other(std::string::ToString::to_string(&value).into()),
+        }
+    }
+}
+
+/// A storage backend that can be used as an [`SDL_Storage`] with [`StorageBuilder`].
+///
+/// This mirrors [`SDL_StorageInterface`]. Closing the storage drops the backend. Methods that
+/// modify the storage return [`StorageError::ReadOnly`] by default, and aren't made available
+/// to SDL at all if [`READ_ONLY`](StorageBackend::READ_ONLY) is true.
+pub trait StorageBackend: Send + 'static {
+    /// If true, the storage is created without write support, and SDL reports an error for
+    /// any attempt to modify it without calling the backend.
+    const READ_ONLY: bool = false;
+
+    /// Check if the storage is ready to use. See `SDL_StorageReady`.
+    fn ready(&mut self) -> bool {
+        true
+    }
+
+    /// Call `callback` with the name of each entry in the directory at `path`, until all
+    /// entries have been visited or `callback` returns something other than
+    /// [`SDL_ENUM_CONTINUE`]. See `SDL_EnumerateStorageDirectory`.
+    fn enumerate(
+        &mut self,
+        path: &str,
+        callback: &mut dyn FnMut(&str) -> SDL_EnumerationResult,
+    ) -> Result<(), StorageError>;
+
+    /// Get information about `path`. See `SDL_GetStoragePathInfo`.
+    fn info(&mut self, path: &str) -> Result<SDL_PathInfo, StorageError>;
+
+    /// Read the file at `path` into `buf`. The length of `buf` must match the size of the
+    /// file. See `SDL_ReadStorageFile`.
+    fn read_file(&mut self, path: &str, buf: &mut [u8]) -> Result<(), StorageError>;
+
+    /// Create or replace the file at `path` with `data`. See `SDL_WriteStorageFile`.
+    fn write_file(&mut self, path: &str, data: &[u8]) -> Result<(), StorageError> {
+        let _ = (path, data);
+        Err(StorageError::ReadOnly)
+    }
+
+    /// Create a directory. See `SDL_CreateStorageDirectory`.
+    fn mkdir(&mut self, path: &str) -> Result<(), StorageError> {
+        let _ = path;
+        Err(StorageError::ReadOnly)
+    }
+
+    /// Remove a file or an empty directory. See `SDL_RemoveStoragePath`.
+    fn remove(&mut self, path: &str) -> Result<(), StorageError> {
+        let _ = path;
+        Err(StorageError::ReadOnly)
+    }
+
+    /// Rename a file or directory. See `SDL_RenameStoragePath`.
+    fn rename(&mut self, old_path: &str, new_path: &str) -> Result<(), StorageError> {
+        let _ = (old_path, new_path);
+        Err(StorageError::ReadOnly)
+    }
+
+    /// Copy a file. See `SDL_CopyStorageFile`.
+    fn copy(&mut self, old_path: &str, new_path: &str) -> Result<(), StorageError> {
+        let _ = (old_path, new_path);
+        Err(StorageError::ReadOnly)
+    }
+
+    /// Get the number of bytes that can still be written. See `SDL_GetStorageSpaceRemaining`.
+    fn space_remaining(&mut self) -> u64 {
+        0
+    }
+}
+
+/// Builder for an [`SDL_Storage`] backed by a [`StorageBackend`].
+///
+/// Example:
+/// ```rust,ignore
+/// use sdl3_main::storage::{ArchiveStorage, StorageBuilder};
+///
+/// let assets = ArchiveStorage::from_zip(include_bytes!("assets.zip").as_slice())?;
+/// let storage = StorageBuilder::new(assets).build();
+/// ```
+pub struct StorageBuilder<B> {
+    backend: B,
+    read_only: bool,
+}
+
+impl<B: StorageBackend> StorageBuilder<B> {
+    /// Create a new builder for `backend`.
+    #[inline]
+    pub fn new(backend: B) -> Self {
+        Self {
+            backend,
+            read_only: B::READ_ONLY,
+        }
+    }
+
+    /// Create the storage without write support, even if the backend supports writing.
+    #[inline]
+    pub fn read_only(mut self) -> Self {
+        self.read_only = true;
+        self
+    }
+
+    /// Create the storage. The backend is dropped when the storage is closed with
+    /// `SDL_CloseStorage`.
+    ///
+    /// Returns null on failure; call [`SDL_GetError`](sdl3_sys::error::SDL_GetError) for more
+    /// information.
+    pub fn build(self) -> *mut SDL_Storage {
+        let mut iface = SDL_StorageInterface::new();
+        iface.close = Some(close::<B>);
+        iface.ready = Some(ready::<B>);
+        iface.enumerate = Some(enumerate::<B>);
+        iface.info = Some(info::<B>);
+        iface.read_file = Some(read_file::<B>);
+        if !self.read_only {
+            iface.write_file = Some(write_file::<B>);
+            iface.mkdir = Some(mkdir::<B>);
+            iface.remove = Some(remove::<B>);
+            iface.rename = Some(rename::<B>);
+            iface.copy = Some(copy::<B>);
+            iface.space_remaining = Some(space_remaining::<B>);
+        }
+
+        let userdata = Box::into_raw(Box::new(self.backend));
+        let storage = unsafe { SDL_OpenStorage(&iface, userdata as *mut c_void) };
+        if storage.is_null() {
+            drop(unsafe { Box::from_raw(userdata) });
+        }
+        storage
+    }
+}
+
+fn set_error(error: &StorageError) {
+    let message = format!("{error}\0");
+    unsafe { SDL_SetError(c"%s".as_ptr(), message.as_ptr()) };
+}
+
+fn report(result: Result<(), StorageError>) -> bool {
+    match result {
+        Ok(()) => true,
+        Err(e) => {
+            set_error(&e);
+            false
+        }
+    }
+}
+
+unsafe fn path<'a>(path: *const c_char) -> Result<&'a str, StorageError> {
+    if path.is_null() {
+        return Ok("");
+    }
+    unsafe { CStr::from_ptr(path) }
+        .to_str()
+        .map_err(|_| StorageError::InvalidPath)
+}
+
+#[inline(always)]
+unsafe fn backend<'a, B>(userdata: *mut c_void) -> &'a mut B {
+    unsafe { &mut *(userdata as *mut B) }
+}
+
+unsafe extern "C" fn close<B: StorageBackend>(userdata: *mut c_void) -> bool {
+    drop(unsafe { Box::from_raw(userdata as *mut B) });
+    true
+}
+
+unsafe extern "C" fn ready<B: StorageBackend>(userdata: *mut c_void) -> bool {
+    unsafe { backend::<B>(userdata) }.ready()
+}
+
+unsafe extern "C" fn enumerate<B: StorageBackend>(
+    userdata: *mut c_void,
+    path: *const c_char,
+    callback: SDL_EnumerateDirectoryCallback,
+    callback_userdata: *mut c_void,
+) -> bool {
+    let Some(callback) = callback else {
+        return true;
+    };
+    report((|| {
+        let path = unsafe { self::path(path) }?;
+        // SDL expects the directory name passed to the callback to end with a separator,
+        // except for the root directory
+        let trimmed = path.trim_end_matches('/');
+        let dirname = if trimmed.is_empty() {
+            String::from("\0")
+        } else {
+            format!("{trimmed}/\0")
+        };
+        let mut result = SDL_ENUM_CONTINUE;
+        unsafe { backend::<B>(userdata) }.enumerate(path, &mut |name| {
+            let name = format!("{name}\0");
+            result = unsafe {
+                callback(
+                    callback_userdata,
+                    dirname.as_ptr() as *const c_char,
+                    name.as_ptr() as *const c_char,
+                )
+            };
+            result
+        })?;
+        if result == SDL_ENUM_FAILURE {
+            return Err(StorageError::other("enumeration callback failed"));
+        }
+        Ok(())
+    })())
+}
+
+unsafe extern "C" fn info<B: StorageBackend>(
+    userdata: *mut c_void,
+    path: *const c_char,
+    info: *mut SDL_PathInfo,
+) -> bool {
+    report((|| {
+        let path = unsafe { self::path(path) }?;
+        let result = unsafe { backend::<B>(userdata) }.info(path)?;
+        if !info.is_null() {
+            unsafe { info.write(result) };
+        }
+        Ok(())
+    })())
+}
+
+unsafe extern "C" fn read_file<B: StorageBackend>(
+    userdata: *mut c_void,
+    path: *const c_char,
+    destination: *mut c_void,
+    length: u64,
+) -> bool {
+    report((|| {
+        let path = unsafe { self::path(path) }?;
+        let length = usize::try_from(length).map_err(|_| StorageError::LengthMismatch)?;
+        let buf = if length == 0 {
+            &mut []
+        } else {
+            unsafe { slice::from_raw_parts_mut(destination as *mut u8, length) }
+        };
+        unsafe { backend::<B>(userdata) }.read_file(path, buf)
+    })())
+}
+
+unsafe extern "C" fn write_file<B: StorageBackend>(
+    userdata: *mut c_void,
+    path: *const c_char,
+    source: *const c_void,
+    length: u64,
+) -> bool {
+    report((|| {
+        let path = unsafe { self::path(path) }?;
+        let length = usize::try_from(length).map_err(|_| StorageError::NoSpace)?;
+        let data = if length == 0 {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(source as *const u8, length) }
+        };
+        unsafe { backend::<B>(userdata) }.write_file(path, data)
+    })())
+}
+
+unsafe extern "C" fn mkdir<B: StorageBackend>(userdata: *mut c_void, path: *const c_char) -> bool {
+    report(
+        unsafe { self::path(path) }.and_then(|path| unsafe { backend::<B>(userdata) }.mkdir(path)),
+    )
+}
+
+unsafe extern "C" fn remove<B: StorageBackend>(userdata: *mut c_void, path: *const c_char) -> bool {
+    report(
+        unsafe { self::path(path) }.and_then(|path| unsafe { backend::<B>(userdata) }.remove(path)),
+    )
+}
+
+unsafe extern "C" fn rename<B: StorageBackend>(
+    userdata: *mut c_void,
+    old_path: *const c_char,
+    new_path: *const c_char,
+) -> bool {
+    report((|| {
+        let (old_path, new_path) = unsafe { (path(old_path)?, path(new_path)?) };
+        unsafe { backend::<B>(userdata) }.rename(old_path, new_path)
+    })())
+}
+
+unsafe extern "C" fn copy<B: StorageBackend>(
+    userdata: *mut c_void,
+    old_path: *const c_char,
+    new_path: *const c_char,
+) -> bool {
+    report((|| {
+        let (old_path, new_path) = unsafe { (path(old_path)?, path(new_path)?) };
+        unsafe { backend::<B>(userdata) }.copy(old_path, new_path)
+    })())
+}
+
+unsafe extern "C" fn space_remaining<B: StorageBackend>(userdata: *mut c_void) -> u64 {
+    unsafe { backend::<B>(userdata) }.space_remaining()
+}
+
+// Normalize a path for lookup in the builtin backends
+fn normalize(path: &str) -> Result<&str, StorageError> {
+    let path = path.trim_matches('/');
+    if path
+        .split('/')
+        .any(|c| c.is_empty() && !path.is_empty() || c == "." || c == "..")
+        || path.contains('\\')
+    {
+        Err(StorageError::InvalidPath)
+    } else {
+        Ok(path)
+    }
+}
+
+// The parent directory of a normalized path
+fn parent(path: &str) -> &str {
+    path.rsplit_once('/').map_or("", |(parent, _)| parent)
+}
+
+// Call `callback` for each direct child of `dir` in a map of normalized paths
+fn enumerate_children<V>(
+    entries: &BTreeMap<String, V>,
+    dir: &str,
+    callback: &mut dyn FnMut(&str) -> SDL_EnumerationResult,
+) {
+    let prefix = if dir.is_empty() {
+        String::new()
+    } else {
+        format!("{dir}/")
+    };
+    for path in entries.range(prefix.clone()..).map(|(path, _)| path) {
+        let Some(name) = path.strip_prefix(prefix.as_str()) else {
+            break;
+        };
+        if !name.is_empty() && !name.contains('/') && callback(name) != SDL_ENUM_CONTINUE {
+            break;
+        }
+    }
+}
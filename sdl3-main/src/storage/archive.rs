@@ -0,0 +1,347 @@
+use super::{enumerate_children, normalize, parent, StorageBackend, StorageError};
+use alloc::{borrow::Cow, collections::BTreeMap, string::String};
+use sdl3_sys::{
+    filesystem::{SDL_EnumerationResult, SDL_PathInfo, SDL_PATHTYPE_DIRECTORY, SDL_PATHTYPE_FILE},
+    stdinc::SDL_Time,
+    time::{SDL_DateTime, SDL_DateTimeToTime},
+};
+
+#[cfg(feature = "use-miniz-oxide-v0-8")]
+use miniz_oxide_v0_8 as miniz_oxide;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Method {
+    Stored,
+    Deflated,
+    Unsupported(u16),
+}
+
+#[derive(Clone)]
+enum Entry {
+    File {
+        offset: usize,
+        compressed_size: usize,
+        size: usize,
+        method: Method,
+        modify_time: SDL_Time,
+    },
+    Directory,
+}
+
+/// A read-only [`StorageBackend`] for the files in a zip or pak archive.
+///
+/// The whole archive is kept in memory, so this works well with `include_bytes!` for
+/// assets that are packed into the executable.
+///
+/// Zip archives can contain stored (uncompressed) files, and deflated files if the
+/// `use-miniz-oxide-v0-8` feature is enabled. Zip64 and encrypted archives aren't supported.
+/// Pak archives are the uncompressed `PACK` format used by Quake and many other games.
+///
+/// Example:
+/// ```rust,ignore
+/// use sdl3_main::storage::{ArchiveStorage, StorageBuilder};
+///
+/// static ASSETS: &[u8] = include_bytes!("../assets.zip");
+///
+/// let storage = StorageBuilder::new(ArchiveStorage::from_zip(ASSETS)?).build();
+/// ```
+#[derive(Clone)]
+pub struct ArchiveStorage {
+    data: Cow<'static, [u8]>,
+    entries: BTreeMap<String, Entry>,
+}
+
+fn invalid(what: &'static str) -> StorageError {
+    StorageError::Other(what.into())
+}
+
+// Offsets are read from the archive, so they must be checked for overflow
+fn add(a: usize, b: usize) -> Result<usize, StorageError> {
+    a.checked_add(b).ok_or(invalid("truncated archive"))
+}
+
+fn u16_at(data: &[u8], offset: usize) -> Result<u16, StorageError> {
+    data.get(offset..add(offset, 2)?)
+        .map(|b| u16::from_le_bytes([b[0], b[1]]))
+        .ok_or(invalid("truncated archive"))
+}
+
+fn u32_at(data: &[u8], offset: usize) -> Result<u32, StorageError> {
+    data.get(offset..add(offset, 4)?)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .ok_or(invalid("truncated archive"))
+}
+
+// Convert an MS-DOS date and time, as used in zip archives, to SDL_Time. The time zone
+// isn't stored, so it's treated as UTC
+fn dos_time(date: u16, time: u16) -> SDL_Time {
+    let dt = SDL_DateTime {
+        year: 1980 + (date >> 9) as i32,
+        month: ((date >> 5) & 0xf).max(1) as i32,
+        day: (date & 0x1f).max(1) as i32,
+        hour: (time >> 11) as i32,
+        minute: ((time >> 5) & 0x3f) as i32,
+        second: ((time & 0x1f) * 2) as i32,
+        ..Default::default()
+    };
+    let mut ticks = 0;
+    if unsafe { SDL_DateTimeToTime(&dt, &mut ticks) } {
+        ticks
+    } else {
+        0
+    }
+}
+
+// Add an entry and its parent directories, which don't need to be listed in the archive
+fn insert(
+    entries: &mut BTreeMap<String, Entry>,
+    name: &str,
+    entry: Entry,
+) -> Result<(), StorageError> {
+    let name = normalize(name).map_err(|_| invalid("invalid file name"))?;
+    if name.is_empty() {
+        return Ok(());
+    }
+    let mut dir = parent(name);
+    while !dir.is_empty() && !entries.contains_key(dir) {
+        entries.insert(dir.into(), Entry::Directory);
+        dir = parent(dir);
+    }
+    entries.insert(name.into(), entry);
+    Ok(())
+}
+
+impl ArchiveStorage {
+    /// Open a zip archive.
+    pub fn from_zip(data: impl Into<Cow<'static, [u8]>>) -> Result<Self, StorageError> {
+        const EOCD_SIGNATURE: u32 = 0x0605_4b50;
+        const CENTRAL_SIGNATURE: u32 = 0x0201_4b50;
+        const LOCAL_SIGNATURE: u32 = 0x0403_4b50;
+        const EOCD_SIZE: usize = 22;
+
+        let archive: Cow<'static, [u8]> = data.into();
+        let data = &*archive;
+        let mut entries = BTreeMap::new();
+
+        // the end of central directory record is at the end, followed by a comment of up
+        // to 64 KiB
+        let eocd = (data.len().saturating_sub(EOCD_SIZE + 0xffff)
+            ..=data.len().saturating_sub(EOCD_SIZE))
+            .rev()
+            .find(|&i| u32_at(data, i) == Ok(EOCD_SIGNATURE))
+            .ok_or(invalid("not a zip archive"))?;
+        let count = u16_at(&data[eocd..], 10)?;
+        let pos = u32_at(&data[eocd..], 16)?;
+        if count == 0xffff || pos == 0xffff_ffff {
+            return Err(invalid("zip64 archives aren't supported"));
+        }
+        let mut pos = pos as usize;
+
+        for _ in 0..count {
+            // read the header fields relative to the header, so the offsets can't overflow
+            let header = data.get(pos..).ok_or(invalid("truncated archive"))?;
+            if u32_at(header, 0)? != CENTRAL_SIGNATURE {
+                return Err(invalid("corrupt zip central directory"));
+            }
+            let flags = u16_at(header, 8)?;
+            let method = match u16_at(header, 10)? {
+                0 => Method::Stored,
+                8 => Method::Deflated,
+                method => Method::Unsupported(method),
+            };
+            let modify_time = dos_time(u16_at(header, 14)?, u16_at(header, 12)?);
+            let compressed_size = u32_at(header, 20)? as usize;
+            let size = u32_at(header, 24)? as usize;
+            let name_len = u16_at(header, 28)? as usize;
+            let extra_len = u16_at(header, 30)? as usize;
+            let comment_len = u16_at(header, 32)? as usize;
+            let local = u32_at(header, 42)? as usize;
+            let name = header
+                .get(46..46 + name_len)
+                .ok_or(invalid("truncated archive"))?;
+            let name = core::str::from_utf8(name).map_err(|_| invalid("invalid file name"))?;
+            pos = add(pos, 46 + name_len + extra_len + comment_len)?;
+
+            if flags & 1 != 0 {
+                return Err(invalid("encrypted zip archives aren't supported"));
+            }
+            if name.ends_with('/') {
+                insert(&mut entries, name, Entry::Directory)?;
+                continue;
+            }
+            let local_header = data.get(local..).ok_or(invalid("truncated archive"))?;
+            if u32_at(local_header, 0)? != LOCAL_SIGNATURE {
+                return Err(invalid("corrupt zip local header"));
+            }
+            let offset = add(
+                local,
+                30 + u16_at(local_header, 26)? as usize + u16_at(local_header, 28)? as usize,
+            )?;
+            if add(offset, compressed_size)? > data.len() {
+                return Err(invalid("truncated archive"));
+            }
+            insert(
+                &mut entries,
+                name,
+                Entry::File {
+                    offset,
+                    compressed_size,
+                    size,
+                    method,
+                    modify_time,
+                },
+            )?;
+        }
+
+        Ok(Self {
+            data: archive,
+            entries,
+        })
+    }
+
+    /// Open a pak archive.
+    pub fn from_pak(data: impl Into<Cow<'static, [u8]>>) -> Result<Self, StorageError> {
+        const ENTRY_SIZE: usize = 64;
+        const NAME_SIZE: usize = 56;
+
+        let archive: Cow<'static, [u8]> = data.into();
+        let data = &*archive;
+        let mut entries = BTreeMap::new();
+
+        if data.get(..4) != Some(b"PACK") {
+            return Err(invalid("not a pak archive"));
+        }
+        let dir_offset = u32_at(data, 4)? as usize;
+        let dir_len = u32_at(data, 8)? as usize;
+        let dir = data
+            .get(dir_offset..add(dir_offset, dir_len)?)
+            .ok_or(invalid("truncated archive"))?;
+
+        for entry in dir.chunks_exact(ENTRY_SIZE) {
+            let name = &entry[..NAME_SIZE];
+            let name = &name[..name.iter().position(|&b| b == 0).unwrap_or(NAME_SIZE)];
+            let name = core::str::from_utf8(name).map_err(|_| invalid("invalid file name"))?;
+            let offset = u32_at(entry, NAME_SIZE)? as usize;
+            let size = u32_at(entry, NAME_SIZE + 4)? as usize;
+            if add(offset, size)? > data.len() {
+                return Err(invalid("truncated archive"));
+            }
+            insert(
+                &mut entries,
+                name,
+                Entry::File {
+                    offset,
+                    compressed_size: size,
+                    size,
+                    method: Method::Stored,
+                    modify_time: 0,
+                },
+            )?;
+        }
+
+        Ok(Self {
+            data: archive,
+            entries,
+        })
+    }
+
+    /// Iterate over the paths of all files in the archive, in sorted order.
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().filter_map(|(path, entry)| match entry {
+            Entry::File { .. } => Some(path.as_str()),
+            Entry::Directory => None,
+        })
+    }
+}
+
+impl StorageBackend for ArchiveStorage {
+    const READ_ONLY: bool = true;
+
+    fn enumerate(
+        &mut self,
+        path: &str,
+        callback: &mut dyn FnMut(&str) -> SDL_EnumerationResult,
+    ) -> Result<(), StorageError> {
+        let path = normalize(path)?;
+        match self.entries.get(path) {
+            Some(Entry::Directory) => (),
+            None if path.is_empty() => (),
+            Some(Entry::File { .. }) => return Err(StorageError::NotADirectory),
+            None => return Err(StorageError::NotFound),
+        }
+        enumerate_children(&self.entries, path, callback);
+        Ok(())
+    }
+
+    fn info(&mut self, path: &str) -> Result<SDL_PathInfo, StorageError> {
+        let path = normalize(path)?;
+        match self.entries.get(path) {
+            Some(Entry::File {
+                size, modify_time, ..
+            }) => Ok(SDL_PathInfo {
+                r#type: SDL_PATHTYPE_FILE,
+                size: *size as u64,
+                create_time: *modify_time,
+                modify_time: *modify_time,
+                access_time: *modify_time,
+            }),
+            Some(Entry::Directory) => Ok(SDL_PathInfo {
+                r#type: SDL_PATHTYPE_DIRECTORY,
+                ..Default::default()
+            }),
+            None if path.is_empty() => Ok(SDL_PathInfo {
+                r#type: SDL_PATHTYPE_DIRECTORY,
+                ..Default::default()
+            }),
+            None => Err(StorageError::NotFound),
+        }
+    }
+
+    fn read_file(&mut self, path: &str, buf: &mut [u8]) -> Result<(), StorageError> {
+        let path = normalize(path)?;
+        let Some(&Entry::File {
+            offset,
+            compressed_size,
+            size,
+            method,
+            ..
+        }) = self.entries.get(path)
+        else {
+            return Err(if self.entries.contains_key(path) || path.is_empty() {
+                StorageError::IsADirectory
+            } else {
+                StorageError::NotFound
+            });
+        };
+        if buf.len() != size {
+            return Err(StorageError::LengthMismatch);
+        }
+        let data = &self.data[offset..offset + compressed_size];
+        match method {
+            Method::Stored if compressed_size == size => {
+                buf.copy_from_slice(data);
+                Ok(())
+            }
+            Method::Stored => Err(invalid("corrupt zip entry")),
+            #[cfg(feature = "use-miniz-oxide-v0-8")]
+            Method::Deflated => {
+                match miniz_oxide::inflate::decompress_slice_iter_to_slice(
+                    buf,
+                    core::iter::once(data),
+                    false,
+                    true,
+                ) {
+                    Ok(n) if n == size => Ok(()),
+                    _ => Err(invalid("corrupt deflate stream")),
+                }
+            }
+            #[cfg(not(feature = "use-miniz-oxide-v0-8"))]
+            Method::Deflated => Err(invalid(
+                "deflated zip entries require the `use-miniz-oxide-v0-8` feature",
+            )),
+            Method::Unsupported(method) => Err(StorageError::Other(
+                alloc::format!("unsupported zip compression method {method}").into(),
+            )),
+        }
+    }
+}
@@ -0,0 +1,315 @@
+use super::{enumerate_children, normalize, parent, StorageBackend, StorageError};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use sdl3_sys::{
+    filesystem::{SDL_EnumerationResult, SDL_PathInfo, SDL_PATHTYPE_DIRECTORY, SDL_PATHTYPE_FILE},
+    stdinc::SDL_Time,
+    time::SDL_GetCurrentTime,
+};
+
+#[derive(Clone)]
+enum Entry {
+    File {
+        data: Vec<u8>,
+        create_time: SDL_Time,
+        modify_time: SDL_Time,
+    },
+    Directory {
+        create_time: SDL_Time,
+    },
+}
+
+fn now() -> SDL_Time {
+    let mut time = 0;
+    unsafe { SDL_GetCurrentTime(&mut time) };
+    time
+}
+
+/// A writable [`StorageBackend`] that keeps all files in memory.
+///
+/// This can be used as a test double for code that saves and loads data with `SDL_Storage`.
+/// Populate it with [`insert_file()`](MemoryStorage::insert_file) before creating the
+/// storage, and read the results back through SDL.
+///
+/// Example:
+/// ```rust,ignore
+/// use sdl3_main::storage::{MemoryStorage, StorageBuilder};
+///
+/// let mut saves = MemoryStorage::new();
+/// saves.insert_file("slot1/save.dat", b"...")?;
+/// let storage = StorageBuilder::new(saves).build();
+/// ```
+#[derive(Clone, Default)]
+pub struct MemoryStorage {
+    entries: BTreeMap<String, Entry>,
+    capacity: Option<u64>,
+    used: u64,
+}
+
+impl MemoryStorage {
+    /// Create a new empty storage without a size limit.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new empty storage that can hold at most `capacity` bytes of file data.
+    /// Writes that would exceed this fail with [`StorageError::NoSpace`].
+    #[inline]
+    pub fn with_capacity(capacity: u64) -> Self {
+        Self {
+            capacity: Some(capacity),
+            ..Self::default()
+        }
+    }
+
+    /// Add a file, creating any missing parent directories. An existing file at `path`
+    /// is replaced.
+    pub fn insert_file(
+        &mut self,
+        path: &str,
+        data: impl Into<Vec<u8>>,
+    ) -> Result<(), StorageError> {
+        let path = normalize(path)?;
+        self.create_dirs(parent(path))?;
+        self.put_file(path, data.into())
+    }
+
+    /// Get the contents of the file at `path`, if it exists.
+    pub fn file(&self, path: &str) -> Option<&[u8]> {
+        match self.entries.get(normalize(path).ok()?)? {
+            Entry::File { data, .. } => Some(data),
+            Entry::Directory { .. } => None,
+        }
+    }
+
+    /// Iterate over the paths and contents of all files in the storage, in sorted order.
+    pub fn files(&self) -> impl Iterator<Item = (&str, &[u8])> {
+        self.entries.iter().filter_map(|(path, entry)| match entry {
+            Entry::File { data, .. } => Some((path.as_str(), data.as_slice())),
+            Entry::Directory { .. } => None,
+        })
+    }
+
+    fn is_dir(&self, path: &str) -> bool {
+        path.is_empty() || matches!(self.entries.get(path), Some(Entry::Directory { .. }))
+    }
+
+    fn create_dirs(&mut self, path: &str) -> Result<(), StorageError> {
+        if self.is_dir(path) {
+            return Ok(());
+        }
+        if self.entries.contains_key(path) {
+            return Err(StorageError::NotADirectory);
+        }
+        self.create_dirs(parent(path))?;
+        self.entries
+            .insert(path.to_string(), Entry::Directory { create_time: now() });
+        Ok(())
+    }
+
+    fn put_file(&mut self, path: &str, data: Vec<u8>) -> Result<(), StorageError> {
+        let (old_size, create_time) = match self.entries.get(path) {
+            None => (0, None),
+            Some(Entry::File {
+                data, create_time, ..
+            }) => (data.len() as u64, Some(*create_time)),
+            Some(Entry::Directory { .. }) => return Err(StorageError::IsADirectory),
+        };
+        let used = self.used - old_size + data.len() as u64;
+        if self.capacity.is_some_and(|capacity| used > capacity) {
+            return Err(StorageError::NoSpace);
+        }
+        self.used = used;
+        let time = now();
+        self.entries.insert(
+            path.to_string(),
+            Entry::File {
+                data,
+                create_time: create_time.unwrap_or(time),
+                modify_time: time,
+            },
+        );
+        Ok(())
+    }
+
+    fn check_parent(&self, path: &str) -> Result<(), StorageError> {
+        if path.is_empty() {
+            Err(StorageError::AlreadyExists)
+        } else if self.is_dir(parent(path)) {
+            Ok(())
+        } else if self.entries.contains_key(parent(path)) {
+            Err(StorageError::NotADirectory)
+        } else {
+            Err(StorageError::NotFound)
+        }
+    }
+}
+
+impl StorageBackend for MemoryStorage {
+    fn enumerate(
+        &mut self,
+        path: &str,
+        callback: &mut dyn FnMut(&str) -> SDL_EnumerationResult,
+    ) -> Result<(), StorageError> {
+        let path = normalize(path)?;
+        if !self.is_dir(path) {
+            return Err(if self.entries.contains_key(path) {
+                StorageError::NotADirectory
+            } else {
+                StorageError::NotFound
+            });
+        }
+        enumerate_children(&self.entries, path, callback);
+        Ok(())
+    }
+
+    fn info(&mut self, path: &str) -> Result<SDL_PathInfo, StorageError> {
+        let path = normalize(path)?;
+        if path.is_empty() {
+            return Ok(SDL_PathInfo {
+                r#type: SDL_PATHTYPE_DIRECTORY,
+                ..Default::default()
+            });
+        }
+        Ok(
+            match self.entries.get(path).ok_or(StorageError::NotFound)? {
+                Entry::File {
+                    data,
+                    create_time,
+                    modify_time,
+                } => SDL_PathInfo {
+                    r#type: SDL_PATHTYPE_FILE,
+                    size: data.len() as u64,
+                    create_time: *create_time,
+                    modify_time: *modify_time,
+                    access_time: *modify_time,
+                },
+                Entry::Directory { create_time } => SDL_PathInfo {
+                    r#type: SDL_PATHTYPE_DIRECTORY,
+                    size: 0,
+                    create_time: *create_time,
+                    modify_time: *create_time,
+                    access_time: *create_time,
+                },
+            },
+        )
+    }
+
+    fn read_file(&mut self, path: &str, buf: &mut [u8]) -> Result<(), StorageError> {
+        let path = normalize(path)?;
+        match self.entries.get(path) {
+            Some(Entry::File { data, .. }) if data.len() == buf.len() => {
+                buf.copy_from_slice(data);
+                Ok(())
+            }
+            Some(Entry::File { .. }) => Err(StorageError::LengthMismatch),
+            Some(Entry::Directory { .. }) => Err(StorageError::IsADirectory),
+            None if path.is_empty() => Err(StorageError::IsADirectory),
+            None => Err(StorageError::NotFound),
+        }
+    }
+
+    fn write_file(&mut self, path: &str, data: &[u8]) -> Result<(), StorageError> {
+        let path = normalize(path)?;
+        self.check_parent(path).map_err(|e| match e {
+            StorageError::AlreadyExists => StorageError::IsADirectory,
+            e => e,
+        })?;
+        self.put_file(path, data.to_vec())
+    }
+
+    fn mkdir(&mut self, path: &str) -> Result<(), StorageError> {
+        self.create_dirs(normalize(path)?)
+    }
+
+    fn remove(&mut self, path: &str) -> Result<(), StorageError> {
+        let path = normalize(path)?;
+        if path.is_empty() {
+            return Err(StorageError::InvalidPath);
+        }
+        match self.entries.get(path).ok_or(StorageError::NotFound)? {
+            Entry::File { data, .. } => self.used -= data.len() as u64,
+            Entry::Directory { .. } => {
+                let mut empty = true;
+                enumerate_children(&self.entries, path, &mut |_| {
+                    empty = false;
+                    SDL_EnumerationResult::SUCCESS
+                });
+                if !empty {
+                    return Err(StorageError::DirectoryNotEmpty);
+                }
+            }
+        }
+        self.entries.remove(path);
+        Ok(())
+    }
+
+    fn rename(&mut self, old_path: &str, new_path: &str) -> Result<(), StorageError> {
+        let (old_path, new_path) = (normalize(old_path)?, normalize(new_path)?);
+        if old_path.is_empty() {
+            return Err(StorageError::InvalidPath);
+        }
+        let entry = self.entries.get(old_path).ok_or(StorageError::NotFound)?;
+        if old_path == new_path {
+            return Ok(());
+        }
+        self.check_parent(new_path)?;
+        if new_path.starts_with(old_path) && new_path.as_bytes()[old_path.len()] == b'/' {
+            // can't move a directory into itself
+            return Err(StorageError::InvalidPath);
+        }
+        match (entry, self.entries.get(new_path)) {
+            (_, None) => (),
+            (Entry::File { .. }, Some(Entry::File { data, .. })) => {
+                self.used -= data.len() as u64;
+            }
+            (Entry::File { .. }, Some(Entry::Directory { .. })) => {
+                return Err(StorageError::IsADirectory)
+            }
+            (Entry::Directory { .. }, Some(_)) => return Err(StorageError::AlreadyExists),
+        }
+
+        let entry = self.entries.remove(old_path).unwrap();
+        let old_prefix = alloc::format!("{old_path}/");
+        let children: Vec<String> = self
+            .entries
+            .range(old_prefix.clone()..)
+            .map(|(path, _)| path)
+            .take_while(|path| path.starts_with(&old_prefix))
+            .cloned()
+            .collect();
+        for child in children {
+            let entry = self.entries.remove(&child).unwrap();
+            self.entries.insert(
+                alloc::format!("{new_path}/{}", &child[old_prefix.len()..]),
+                entry,
+            );
+        }
+        self.entries.insert(new_path.to_string(), entry);
+        Ok(())
+    }
+
+    fn copy(&mut self, old_path: &str, new_path: &str) -> Result<(), StorageError> {
+        let (old_path, new_path) = (normalize(old_path)?, normalize(new_path)?);
+        let data = match self.entries.get(old_path) {
+            Some(Entry::File { data, .. }) => data.clone(),
+            Some(Entry::Directory { .. }) => return Err(StorageError::IsADirectory),
+            None if old_path.is_empty() => return Err(StorageError::IsADirectory),
+            None => return Err(StorageError::NotFound),
+        };
+        if old_path == new_path {
+            return Ok(());
+        }
+        self.check_parent(new_path)?;
+        self.put_file(new_path, data)
+    }
+
+    fn space_remaining(&mut self) -> u64 {
+        self.capacity
+            .map_or(u64::MAX, |capacity| capacity.saturating_sub(self.used))
+    }
+}
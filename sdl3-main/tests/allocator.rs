@@ -0,0 +1,180 @@
+use core::{
+    alloc::{GlobalAlloc, Layout},
+    ffi::c_void,
+};
+use libtest_mimic_collect::{libtest_mimic, test};
+use sdl3_sys::{
+    allocator::{set_memory_functions, SdlAllocator},
+    stdinc::{SDL_calloc, SDL_free, SDL_malloc, SDL_realloc},
+};
+use std::{alloc::System, collections::BTreeMap, sync::Mutex};
+
+// Size of the header `set_memory_functions` puts before each block
+const HEADER_SIZE: usize = 16;
+
+// Allocates with `System`, and checks that every block is freed with the layout it was
+// allocated with
+struct Tracker(Mutex<BTreeMap<usize, Layout>>);
+
+impl Tracker {
+    fn insert(&self, ptr: *mut u8, layout: Layout) {
+        if !ptr.is_null() {
+            let old = self.0.lock().unwrap().insert(ptr as usize, layout);
+            assert!(old.is_none());
+        }
+    }
+
+    fn remove(&self, ptr: *mut u8, layout: Layout) {
+        assert_eq!(self.0.lock().unwrap().remove(&(ptr as usize)), Some(layout));
+    }
+
+    // Get the layout of the block SDL's memory functions allocated for `mem`
+    fn block_layout(&self, mem: *mut c_void) -> Option<Layout> {
+        let block = (mem as usize).checked_sub(HEADER_SIZE)?;
+        self.0.lock().unwrap().get(&block).copied()
+    }
+}
+
+unsafe impl GlobalAlloc for Tracker {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        self.insert(ptr, layout);
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        self.insert(ptr, layout);
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.remove(ptr, layout);
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        self.remove(ptr, layout);
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if new_ptr.is_null() {
+            self.insert(ptr, layout);
+        } else {
+            self.insert(
+                new_ptr,
+                Layout::from_size_align(new_size, layout.align()).unwrap(),
+            );
+        }
+        new_ptr
+    }
+}
+
+static TRACKER: Tracker = Tracker(Mutex::new(BTreeMap::new()));
+
+// Check that `mem` is in a block of `size` bytes plus the header, with the size in the header
+fn assert_block(mem: *mut c_void, size: usize) {
+    assert!(!mem.is_null());
+    assert_eq!(mem as usize % HEADER_SIZE, 0);
+    assert_eq!(
+        TRACKER.block_layout(mem),
+        Some(Layout::from_size_align(size + HEADER_SIZE, HEADER_SIZE).unwrap())
+    );
+    assert_eq!(
+        unsafe { (mem as *const u8).sub(HEADER_SIZE).cast::<usize>().read() },
+        size
+    );
+}
+
+#[test]
+fn memory_functions() {
+    unsafe {
+        let mem = SDL_malloc(10);
+        assert_block(mem, 10);
+        mem.cast::<u8>().copy_from(b"0123456789".as_ptr(), 10);
+
+        let mem = SDL_realloc(mem, 1000);
+        assert_block(mem, 1000);
+        assert_eq!(
+            core::slice::from_raw_parts(mem.cast::<u8>(), 10),
+            b"0123456789"
+        );
+
+        let mem = SDL_realloc(mem, 4);
+        assert_block(mem, 4);
+        assert_eq!(core::slice::from_raw_parts(mem.cast::<u8>(), 4), b"0123");
+
+        let block = (mem as usize) - HEADER_SIZE;
+        SDL_free(mem);
+        assert!(!TRACKER.0.lock().unwrap().contains_key(&block));
+
+        let mem = SDL_calloc(7, 9);
+        assert_block(mem, 63);
+        assert!(core::slice::from_raw_parts(mem.cast::<u8>(), 63)
+            .iter()
+            .all(|&b| b == 0));
+        SDL_free(mem);
+
+        let mem = SDL_realloc(core::ptr::null_mut(), 5);
+        assert_block(mem, 5);
+        SDL_free(mem);
+
+        assert!(SDL_calloc(usize::MAX, 2).is_null());
+        assert!(SDL_malloc(usize::MAX - 4).is_null());
+        SDL_free(core::ptr::null_mut());
+    }
+}
+
+#[test]
+fn set_memory_functions_twice() {
+    static OTHER: System = System;
+    assert!(!set_memory_functions(&OTHER));
+    assert!(!set_memory_functions(&TRACKER));
+}
+
+#[test]
+fn sdl_allocator() {
+    for align in [1, 2, align_of::<usize>(), 16, 64, 256, 4096] {
+        let layout = Layout::from_size_align(100, align).unwrap();
+        unsafe {
+            let ptr = SdlAllocator.alloc(layout);
+            assert!(!ptr.is_null());
+            assert_eq!(ptr as usize % align, 0, "align {align}");
+            for i in 0..100 {
+                ptr.add(i).write(i as u8);
+            }
+
+            let ptr = SdlAllocator.realloc(ptr, layout, 300);
+            assert!(!ptr.is_null());
+            assert_eq!(ptr as usize % align, 0, "align {align}");
+            assert!(
+                (0..100).all(|i| ptr.add(i).read() == i as u8),
+                "align {align}"
+            );
+            ptr.add(299).write(0xff);
+
+            let layout = Layout::from_size_align(300, align).unwrap();
+            let ptr = SdlAllocator.realloc(ptr, layout, 50);
+            assert!(!ptr.is_null());
+            assert_eq!(ptr as usize % align, 0, "align {align}");
+            assert!(
+                (0..50).all(|i| ptr.add(i).read() == i as u8),
+                "align {align}"
+            );
+            SdlAllocator.dealloc(ptr, Layout::from_size_align(50, align).unwrap());
+
+            let ptr = SdlAllocator.alloc_zeroed(layout);
+            assert!(!ptr.is_null());
+            assert_eq!(ptr as usize % align, 0, "align {align}");
+            assert!((0..300).all(|i| ptr.add(i).read() == 0), "align {align}");
+            SdlAllocator.dealloc(ptr, layout);
+        }
+    }
+}
+
+fn main() {
+    // this must happen before any other SDL call
+    assert!(set_memory_functions(&TRACKER));
+
+    let tests = libtest_mimic_collect::TestCollection::collect_tests();
+    let args = libtest_mimic::Arguments::from_args();
+    libtest_mimic::run(&args, tests).exit();
+}
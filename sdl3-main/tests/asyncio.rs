@@ -0,0 +1,62 @@
+use core::{
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+use libtest_mimic_collect::{libtest_mimic, test};
+use sdl3_main::asyncio::{AsyncIOError, AsyncIOReactor};
+use std::{ffi::CString, fs};
+
+// Minimal executor that drives the reactor while the future is pending
+fn block_on<F: Future>(reactor: &AsyncIOReactor, future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        reactor.wait(100);
+    }
+}
+
+#[test]
+fn write_read_load() {
+    let path = std::env::temp_dir().join(format!("sdl3-main-asyncio-{}", std::process::id()));
+    let c_path = CString::new(path.to_str().unwrap()).unwrap();
+    let reactor = AsyncIOReactor::new().unwrap();
+
+    let file = reactor.open(&c_path, c"w+").unwrap();
+    let written = file.write(b"hello async".to_vec(), 0);
+    assert_eq!(block_on(&reactor, written), Ok(11));
+    assert_eq!(file.size(), Some(11));
+    let read = file.read(vec![0; 5], 6);
+    assert_eq!(block_on(&reactor, read).unwrap(), b"async");
+    assert_eq!(block_on(&reactor, file.close(true)), Ok(()));
+
+    let loaded = block_on(&reactor, reactor.load_file(&c_path)).unwrap();
+    assert_eq!(&*loaded, b"hello async");
+    assert_eq!(reactor.pending(), 0);
+
+    // dropping a future before it completes is fine; the reactor still processes the task
+    let file = reactor.open(&c_path, c"r").unwrap();
+    drop(file.read(vec![0; 11], 0));
+    drop(file);
+    while reactor.pending() != 0 {
+        reactor.wait(-1);
+    }
+
+    fs::remove_file(&path).unwrap();
+    let missing = reactor.load_file(&c_path);
+    assert_eq!(
+        block_on(&reactor, missing).err(),
+        Some(AsyncIOError::Failed)
+    );
+    assert!(reactor.open(&c_path, c"r").is_none());
+    assert_eq!(reactor.pending(), 0);
+}
+
+fn main() {
+    let tests = libtest_mimic_collect::TestCollection::collect_tests();
+    let args = libtest_mimic::Arguments::from_args();
+    libtest_mimic::run(&args, tests).exit();
+}
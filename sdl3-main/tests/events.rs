@@ -0,0 +1,302 @@
+use core::{
+    ffi::CStr,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use libtest_mimic_collect::{libtest_mimic, test};
+use sdl3_main::events::{
+    release_user_event,
+    replay::{EventPlayer, EventRecorder, Recording},
+    EventFilter, EventRouter, EventWatch, Propagation, UserEvent,
+};
+use sdl3_sys::{
+    error::SDL_GetError,
+    events::{
+        SDL_DisplayEvent, SDL_Event, SDL_EventType, SDL_FlushEvents, SDL_HasEvent,
+        SDL_KeyboardEvent, SDL_PollEvent, SDL_PushEvent, SDL_QuitEvent, SDL_TextInputEvent,
+        SDL_UserEvent, SDL_WindowEvent, SDL_EVENT_KEY_DOWN, SDL_EVENT_KEY_UP, SDL_EVENT_LAST,
+        SDL_EVENT_QUIT, SDL_EVENT_TEXT_INPUT, SDL_EVENT_USER,
+    },
+    init::{SDL_Init, SDL_Quit, SDL_INIT_EVENTS},
+};
+use std::sync::{Arc, Mutex};
+
+macro_rules! defer {
+    ($($tt:tt)*) => {
+        let _defer = $crate::Defer(Some(move || {{ $($tt)* };}));
+    };
+}
+
+struct Defer<F: FnOnce()>(Option<F>);
+
+impl<F: FnOnce()> Drop for Defer<F> {
+    fn drop(&mut self) {
+        if let Some(f) = self.0.take() {
+            f();
+        }
+    }
+}
+
+fn init() {
+    if !unsafe { SDL_Init(SDL_INIT_EVENTS) } {
+        dbg!(unsafe { CStr::from_ptr(SDL_GetError()) });
+        panic!();
+    }
+}
+
+fn event(event_type: SDL_EventType) -> SDL_Event {
+    let mut event = SDL_Event::default();
+    event.r#type = event_type.0;
+    event
+}
+
+fn push(event_type: SDL_EventType) {
+    assert!(unsafe { SDL_PushEvent(&mut event(event_type)) });
+}
+
+#[test]
+fn router_priority_and_propagation() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let mut router = EventRouter::new();
+    let l = log.clone();
+    router.add_handler(.., 0, move |_| {
+        l.lock().unwrap().push("all");
+        Propagation::Continue
+    });
+    let l = log.clone();
+    router.add_handler(SDL_EVENT_KEY_DOWN..=SDL_EVENT_KEY_UP, 10, move |_| {
+        l.lock().unwrap().push("keys");
+        Propagation::Stop
+    });
+    let l = log.clone();
+    let quit = router.add_handler(SDL_EVENT_QUIT, 0, move |_| {
+        l.lock().unwrap().push("quit");
+        Propagation::Continue
+    });
+
+    assert_eq!(
+        router.dispatch(&mut event(SDL_EVENT_KEY_DOWN)),
+        Propagation::Stop
+    );
+    assert_eq!(
+        router.dispatch(&mut event(SDL_EVENT_QUIT)),
+        Propagation::Continue
+    );
+    assert_eq!(*log.lock().unwrap(), ["keys", "all", "quit"]);
+
+    log.lock().unwrap().clear();
+    assert!(router.remove_handler(quit));
+    assert!(!router.remove_handler(quit));
+    router.dispatch(&mut event(SDL_EVENT_QUIT));
+    assert_eq!(*log.lock().unwrap(), ["all"]);
+}
+
+#[test]
+fn event_watch() {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    init();
+    defer!(unsafe { SDL_Quit() });
+    let watch = EventWatch::new(|event| {
+        if unsafe { event.r#type } == SDL_EVENT_USER.0 {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+    })
+    .unwrap();
+    push(SDL_EVENT_USER);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 1);
+    drop(watch);
+    push(SDL_EVENT_USER);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 1);
+    unsafe { SDL_FlushEvents(SDL_EVENT_QUIT.0, SDL_EVENT_LAST.0) };
+}
+
+#[test]
+fn event_filter() {
+    init();
+    defer!(unsafe { SDL_Quit() });
+    let filter = EventFilter::set(|event| unsafe { event.r#type } != SDL_EVENT_USER.0);
+    assert!(filter.is_current());
+    unsafe { SDL_FlushEvents(SDL_EVENT_QUIT.0, SDL_EVENT_LAST.0) };
+    push(SDL_EVENT_USER);
+    assert!(!unsafe { SDL_HasEvent(SDL_EVENT_USER.0) });
+
+    let filter2 = EventFilter::set(|_| true);
+    assert!(!filter.is_current());
+    drop(filter);
+    assert!(filter2.is_current());
+    drop(filter2);
+
+    push(SDL_EVENT_USER);
+    assert!(unsafe { SDL_HasEvent(SDL_EVENT_USER.0) });
+    unsafe { SDL_FlushEvents(SDL_EVENT_QUIT.0, SDL_EVENT_LAST.0) };
+}
+
+#[test]
+fn user_event() {
+    static DROPPED: AtomicUsize = AtomicUsize::new(0);
+    struct Payload(u32);
+    impl Drop for Payload {
+        fn drop(&mut self) {
+            DROPPED.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+    init();
+    defer!(unsafe { SDL_Quit() });
+    let user_event = UserEvent::<Payload>::register().unwrap();
+    assert!(user_event.event_type() >= SDL_EVENT_USER.0);
+
+    assert!(std::thread::spawn(move || user_event.push(Payload(1)))
+        .join()
+        .unwrap());
+    let mut event = SDL_Event::default();
+    assert!(unsafe { SDL_PollEvent(&mut event) });
+    let copy = event;
+    assert!(user_event.is(&event));
+    assert_eq!(user_event.take(&event).unwrap().0, 1);
+    assert!(user_event.take(&copy).is_none());
+    assert_eq!(DROPPED.load(Ordering::Relaxed), 1);
+
+    // unhandled
+    assert!(user_event.push(Payload(2)));
+    assert!(unsafe { SDL_PollEvent(&mut event) });
+    release_user_event(&event);
+    assert_eq!(DROPPED.load(Ordering::Relaxed), 2);
+    assert!(user_event.take(&event).is_none());
+
+    // flushed
+    assert!(user_event.push(Payload(3)));
+    assert!(user_event.push(Payload(4)));
+    user_event.flush();
+    assert_eq!(DROPPED.load(Ordering::Relaxed), 4);
+    assert!(!unsafe { SDL_HasEvent(user_event.event_type()) });
+}
+
+#[test]
+fn record_and_replay() {
+    init();
+    defer!(unsafe { SDL_Quit() });
+    unsafe { SDL_FlushEvents(SDL_EVENT_QUIT.0, SDL_EVENT_LAST.0) };
+
+    let recorder = EventRecorder::start().unwrap();
+    push(SDL_EVENT_KEY_DOWN);
+    push(SDL_EVENT_USER);
+    let mut text = event(SDL_EVENT_TEXT_INPUT);
+    text.text.text = c"hello".as_ptr();
+    assert!(unsafe { SDL_PushEvent(&mut text) });
+    push(SDL_EVENT_KEY_UP);
+    let recording = recorder.stop();
+    push(SDL_EVENT_KEY_DOWN);
+    unsafe { SDL_FlushEvents(SDL_EVENT_QUIT.0, SDL_EVENT_LAST.0) };
+
+    let recording = Recording::from_bytes(&recording.to_bytes()).unwrap();
+    let types: Vec<u32> = recording.iter().map(|e| unsafe { e.r#type }).collect();
+    assert_eq!(
+        types,
+        [
+            SDL_EVENT_KEY_DOWN.0,
+            SDL_EVENT_TEXT_INPUT.0,
+            SDL_EVENT_KEY_UP.0
+        ]
+    );
+
+    let mut player = EventPlayer::new(recording);
+    while !player.is_finished() {
+        player.pump();
+    }
+    let mut replayed = Vec::new();
+    let mut event = SDL_Event::default();
+    while unsafe { SDL_PollEvent(&mut event) } {
+        if unsafe { event.r#type } == SDL_EVENT_TEXT_INPUT.0 {
+            assert_eq!(unsafe { CStr::from_ptr(event.text.text) }, c"hello");
+        }
+        replayed.push(unsafe { event.r#type });
+    }
+    assert_eq!(replayed, types);
+}
+
+#[test]
+fn drop_player_keeps_other_events() {
+    init();
+    defer!(unsafe { SDL_Quit() });
+    unsafe { SDL_FlushEvents(SDL_EVENT_QUIT.0, SDL_EVENT_LAST.0) };
+
+    let mut text = event(SDL_EVENT_TEXT_INPUT);
+    text.text.text = c"replayed".as_ptr();
+    let mut recording = Recording::new();
+    assert!(unsafe { recording.push(&text) });
+    let mut player = EventPlayer::new(recording);
+    assert_eq!(player.pump(), 1);
+
+    // text that didn't come from the player stays in the queue
+    text.text.text = c"typed".as_ptr();
+    assert!(unsafe { SDL_PushEvent(&mut text) });
+    push(SDL_EVENT_KEY_DOWN);
+    drop(player);
+
+    let mut event = SDL_Event::default();
+    assert!(unsafe { SDL_PollEvent(&mut event) });
+    assert_eq!(unsafe { event.r#type }, SDL_EVENT_TEXT_INPUT.0);
+    assert_eq!(unsafe { CStr::from_ptr(event.text.text) }, c"typed");
+    assert!(unsafe { SDL_PollEvent(&mut event) });
+    assert_eq!(unsafe { event.r#type }, SDL_EVENT_KEY_DOWN.0);
+    assert!(!unsafe { SDL_PollEvent(&mut event) });
+}
+
+#[test]
+fn from_event_struct() {
+    // an unset type is filled in for structs that only have one event type
+    let event = SDL_Event::from(SDL_QuitEvent::default());
+    assert!(event.event_type() == SDL_EventType::QUIT);
+    assert!(event.as_quit().is_some());
+    let event = SDL_Event::from(SDL_TextInputEvent::default());
+    assert!(event.event_type() == SDL_EventType::TEXT_INPUT);
+    assert!(event.as_text_input().is_some());
+
+    // other structs keep the type set by the caller
+    let event = SDL_Event::from(SDL_DisplayEvent {
+        r#type: SDL_EventType::DISPLAY_ADDED,
+        ..Default::default()
+    });
+    assert!(event.event_type() == SDL_EventType::DISPLAY_ADDED);
+    assert!(event.as_display().is_some());
+    let event = SDL_Event::from(SDL_WindowEvent {
+        r#type: SDL_EventType::WINDOW_RESIZED,
+        data1: 640,
+        ..Default::default()
+    });
+    assert!(event.event_type() == SDL_EventType::WINDOW_RESIZED);
+    assert_eq!(event.as_window().map(|window| window.data1), Some(640));
+    let event = SDL_Event::from(SDL_KeyboardEvent {
+        r#type: SDL_EventType::KEY_UP,
+        repeat: true,
+        ..Default::default()
+    });
+    assert!(event.as_keyboard().is_some_and(|key| key.repeat));
+    assert!(event.as_quit().is_none());
+    let event = SDL_Event::from(SDL_UserEvent {
+        r#type: SDL_EventType::USER.0 + 1,
+        code: 7,
+        ..Default::default()
+    });
+    assert_eq!(event.event_type().0, SDL_EventType::USER.0 + 1);
+    assert_eq!(event.as_user().map(|user| user.code), Some(7));
+
+    // and a missing or wrong type is caught in debug builds
+    if cfg!(debug_assertions) {
+        assert!(
+            std::panic::catch_unwind(|| SDL_Event::from(SDL_KeyboardEvent::default())).is_err()
+        );
+        assert!(std::panic::catch_unwind(|| SDL_Event::from(SDL_WindowEvent::default())).is_err());
+        assert!(std::panic::catch_unwind(|| SDL_Event::from(SDL_UserEvent {
+            r#type: SDL_EventType::QUIT.0,
+            ..Default::default()
+        }))
+        .is_err());
+    }
+}
+
+fn main() {
+    let tests = libtest_mimic_collect::TestCollection::collect_tests();
+    let mut args = libtest_mimic::Arguments::from_args();
+    args.test_threads = Some(1);
+    libtest_mimic::run(&args, tests).exit();
+}
@@ -0,0 +1,137 @@
+use libtest_mimic_collect::{libtest_mimic, test};
+use sdl3_main::gamepad_mapping::{
+    AxisRange, Input, Mapping, MappingDatabase, MappingGuid, MappingIssue, Output,
+    ParseMappingError,
+};
+use sdl3_sys::gamepad::SDL_GAMEPAD_AXIS_LEFTX;
+
+const STEAM_WINDOWS: &str = "03000000de280000ff11000001000000,Steam Virtual Gamepad,a:b0,b:b1,back:b6,dpdown:h0.4,dpleft:h0.8,dpright:h0.2,dpup:h0.1,leftshoulder:b4,leftstick:b8,lefttrigger:+a2,leftx:a0,lefty:a1,rightshoulder:b5,rightstick:b9,righttrigger:-a2,rightx:a3,righty:a4,start:b7,x:b2,y:b3,platform:Windows,";
+const XINPUT: &str =
+    "xinput,XInput Controller,a:b0,b:b1,-leftx:a0~,+lefty:+a1,crc:1a2b,platform:Windows,";
+const STEAM_LINUX: &str =
+    "03000000de280000ff11000001000000,Steam Virtual Gamepad,a:b0,b:b1,platform:Linux,";
+
+fn database() -> MappingDatabase {
+    format!("# Windows\n{STEAM_WINDOWS}\n{XINPUT}\n\n# Linux\n{STEAM_LINUX}\n")
+        .parse()
+        .unwrap()
+}
+
+#[test]
+fn parse_and_format() {
+    let db = database();
+    assert_eq!(db.len(), 3);
+    assert_eq!(
+        db.to_string(),
+        format!("{STEAM_WINDOWS}\n{XINPUT}\n{STEAM_LINUX}\n")
+    );
+    assert!(db.lint().is_empty());
+    assert_eq!(db.for_platform("Linux").count(), 1);
+
+    let xinput = db.get(&MappingGuid::XInput, Some("Windows")).unwrap();
+    let output = Output::Axis {
+        axis: SDL_GAMEPAD_AXIS_LEFTX,
+        range: AxisRange::Negative,
+    };
+    let input = Input::Axis {
+        index: 0,
+        range: AxisRange::Full,
+        inverted: true,
+    };
+    assert_eq!(xinput.input(output), Some(&input));
+    assert_eq!(xinput.field("crc"), Some("1a2b"));
+
+    for (text, error) in [
+        ("zz,Name", ParseMappingError::InvalidGuid),
+        ("xinput", ParseMappingError::MissingName),
+        ("xinput,Name,a", ParseMappingError::MissingSeparator),
+        ("xinput,Name,a:x1", ParseMappingError::InvalidInput),
+        ("xinput,Name,a:+b1", ParseMappingError::InvalidInput),
+        ("xinput,Name,a:h1", ParseMappingError::InvalidInput),
+    ] {
+        assert_eq!(text.parse::<Mapping>(), Err(error), "{text}");
+    }
+    let error = "\n\nxinput,Name,a:q"
+        .parse::<MappingDatabase>()
+        .unwrap_err();
+    assert_eq!(error.to_string(), "line 3: invalid input in binding");
+}
+
+#[test]
+fn lint() {
+    let mapping: Mapping =
+        "0300000000000000000000000000000f,,a:b0,a:b1,b:b1,dpup:h0.3,leftshoulde:b4,"
+            .parse()
+            .unwrap();
+    let issues: Vec<String> = mapping.lint().iter().map(|i| i.to_string()).collect();
+    assert_eq!(
+        issues,
+        [
+            "empty name",
+            "`a` is bound more than once",
+            "`b1` is bound more than once",
+            "invalid hat direction in `h0.3`",
+            "unknown field `leftshoulde`",
+            "missing platform",
+        ]
+    );
+
+    let db: MappingDatabase = "xinput,A,a:b0,platform:Linux,\nxinput,B,a:b0,platform:Linux,"
+        .parse()
+        .unwrap();
+    assert_eq!(db.lint(), [(1, MappingIssue::DuplicateMapping(0))]);
+}
+
+#[test]
+fn merge_and_diff() {
+    let db = database();
+
+    let mut merged = db.clone();
+    merged.merge_missing(
+        "xinput,Other,a:b1,platform:Windows,\nxinput,Other,a:b1,platform:Linux,"
+            .parse()
+            .unwrap(),
+    );
+    assert_eq!(merged.len(), 4);
+    let xinput = merged.get(&MappingGuid::XInput, Some("Windows")).unwrap();
+    assert_eq!(xinput.name, "XInput Controller");
+
+    let mut new = db.clone();
+    new.merge(
+        "xinput,XInput Controller,b:b1,a:b0,-leftx:a0~,+lefty:+a1,crc:1a2b,platform:Windows,\n\
+         03000000de280000ff11000001000000,Steam Virtual Gamepad,a:b1,b:b0,platform:Linux,\n\
+         default,Default,a:b0,platform:Linux,"
+            .parse()
+            .unwrap(),
+    );
+    assert_eq!(new.len(), 4);
+    let steam = "03000000de280000ff11000001000000".parse().unwrap();
+    assert_eq!(
+        new.remove(&steam, Some("Windows")).unwrap().to_string(),
+        STEAM_WINDOWS
+    );
+    let diff: String = db.diff(&new).iter().map(|c| c.to_string()).collect();
+    assert_eq!(
+        diff,
+        format!(
+            "-{STEAM_WINDOWS}\n-{STEAM_LINUX}\n\
+             +03000000de280000ff11000001000000,Steam Virtual Gamepad,a:b1,b:b0,platform:Linux,\n\
+             +default,Default,a:b0,platform:Linux,\n"
+        )
+    );
+
+    let mut db: MappingDatabase =
+        "xinput,A,a:b0,platform:Linux,\nxinput,B,a:b0,platform:Linux,\ndefault,C,a:b0,"
+            .parse()
+            .unwrap();
+    let old = db.insert("xinput,D,a:b0,platform:Linux,".parse().unwrap());
+    assert_eq!(old.unwrap().name, "B");
+    let names: Vec<_> = db.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(names, ["D", "C"]);
+}
+
+fn main() {
+    let tests = libtest_mimic_collect::TestCollection::collect_tests();
+    let args = libtest_mimic::Arguments::from_args();
+    libtest_mimic::run(&args, tests).exit();
+}
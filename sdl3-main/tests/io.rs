@@ -0,0 +1,135 @@
+use libtest_mimic_collect::{libtest_mimic, test};
+use sdl3_main::io::{io_from_rust, io_from_rust_rw, IoStream};
+use sdl3_sys::iostream::{SDL_GetIOSize, SDL_IOFromConstMem};
+use std::io::{self, BufRead, Cursor, Read, Seek, SeekFrom, Write};
+
+const DATA: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+fn const_mem(data: &'static [u8]) -> IoStream {
+    let stream = unsafe { SDL_IOFromConstMem(data.as_ptr().cast(), data.len()) };
+    assert!(!stream.is_null());
+    unsafe { IoStream::from_raw(stream) }
+}
+
+// Reader that returns at most `self.1` bytes per read
+struct Chunked<T>(T, usize);
+
+impl<T: Read> Read for Chunked<T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = buf.len().min(self.1);
+        self.0.read(&mut buf[..len])
+    }
+}
+
+impl<T: Seek> Seek for Chunked<T> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.0.seek(pos)
+    }
+}
+
+#[test]
+fn rust_stream_round_trip() {
+    let stream = io_from_rust(Cursor::new(DATA.to_vec()));
+    assert!(!stream.is_null());
+    assert_eq!(unsafe { SDL_GetIOSize(stream) }, DATA.len() as i64);
+    let mut stream = unsafe { IoStream::from_raw(stream) };
+    let mut buf = Vec::new();
+    stream.read_to_end(&mut buf).unwrap();
+    assert_eq!(buf, DATA);
+    assert!(stream.write(b"x").is_err());
+
+    let mut stream = unsafe { IoStream::from_raw(io_from_rust_rw(Cursor::new(Vec::new()))) };
+    stream.write_all(DATA).unwrap();
+    stream.flush().unwrap();
+    assert_eq!(stream.seek(SeekFrom::Current(-26)).unwrap(), 10);
+    stream.write_all(b"ABC").unwrap();
+    stream.rewind().unwrap();
+    let mut text = String::new();
+    stream.read_to_string(&mut text).unwrap();
+    assert_eq!(text, "0123456789ABCdefghijklmnopqrstuvwxyz");
+}
+
+#[test]
+fn const_mem_stream() {
+    let mut stream = const_mem(DATA);
+    let mut buf = [0; 10];
+    stream.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"0123456789");
+    let mut rest = Vec::new();
+    stream.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, &DATA[10..]);
+    assert!(stream.write(b"x").is_err());
+    assert_eq!(stream.seek(SeekFrom::End(-3)).unwrap(), 33);
+    let mut rest = String::new();
+    stream.read_to_string(&mut rest).unwrap();
+    assert_eq!(rest, "xyz");
+}
+
+#[test]
+fn seek_after_fill_buf() {
+    let mut stream = const_mem(DATA);
+    assert_eq!(stream.fill_buf().unwrap(), DATA);
+    stream.consume(3);
+    // the underlying stream is at the end, but the position must account for buffered bytes
+    assert_eq!(stream.stream_position().unwrap(), 3);
+    assert_eq!(stream.seek(SeekFrom::Current(2)).unwrap(), 5);
+    let mut buf = [0; 5];
+    stream.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"56789");
+    assert_eq!(stream.seek(SeekFrom::Current(-10)).unwrap(), 0);
+    assert_eq!(stream.fill_buf().unwrap(), DATA);
+
+    // borrowed streams are left at the consumed position when dropped
+    let raw = const_mem(DATA).into_raw();
+    let mut borrowed = unsafe { IoStream::from_raw_borrowed(raw) };
+    borrowed.fill_buf().unwrap();
+    borrowed.consume(7);
+    drop(borrowed);
+    let mut stream = unsafe { IoStream::from_raw(raw) };
+    assert_eq!(stream.stream_position().unwrap(), 7);
+}
+
+#[test]
+fn short_reads() {
+    let mut stream = unsafe { IoStream::from_raw(io_from_rust(Chunked(Cursor::new(DATA), 4))) };
+    let mut buf = [0; 10];
+    let n = stream.read(&mut buf).unwrap();
+    assert!(n > 0 && n <= 4);
+    assert_eq!(&buf[..n], &DATA[..n]);
+    stream.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, &DATA[n..n + 10]);
+    let mut rest = Vec::new();
+    stream.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, &DATA[n + 10..]);
+
+    let mut stream = const_mem(DATA);
+    stream.seek(SeekFrom::Start(30)).unwrap();
+    let mut buf = [0; 10];
+    assert_eq!(stream.read(&mut buf).unwrap(), 6);
+    assert_eq!(&buf[..6], b"uvwxyz");
+}
+
+#[test]
+fn eof() {
+    let mut stream = const_mem(DATA);
+    stream.seek(SeekFrom::End(0)).unwrap();
+    let mut buf = [0; 4];
+    assert_eq!(stream.read(&mut buf).unwrap(), 0);
+    assert_eq!(stream.read(&mut buf).unwrap(), 0);
+    assert!(stream.fill_buf().unwrap().is_empty());
+    assert_eq!(
+        stream.read_exact(&mut buf).unwrap_err().kind(),
+        io::ErrorKind::UnexpectedEof
+    );
+
+    let mut stream = unsafe { IoStream::from_raw(io_from_rust(Cursor::new(Vec::new()))) };
+    let mut buf = Vec::new();
+    assert_eq!(stream.read_to_end(&mut buf).unwrap(), 0);
+    assert!(stream.fill_buf().unwrap().is_empty());
+}
+
+fn main() {
+    let tests = libtest_mimic_collect::TestCollection::collect_tests();
+    let args = libtest_mimic::Arguments::from_args();
+    libtest_mimic::run(&args, tests).exit();
+}
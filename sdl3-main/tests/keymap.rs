@@ -0,0 +1,216 @@
+use libtest_mimic_collect::{libtest_mimic, test};
+use sdl3_main::keymap::{
+    default_keycode, default_scancode, key_from_name, key_name, scancode_from_name, scancode_name,
+    Key, KeyBindings, ParseBindingsErrorKind, ParseShortcutError, Shortcut,
+};
+use sdl3_sys::{
+    keycode::{
+        SDL_Keycode, SDLK_A, SDLK_EXCLAIM, SDLK_LMETA, SDLK_LSHIFT, SDLK_Q, SDLK_S, SDLK_Z,
+        SDL_KMOD_CAPS, SDL_KMOD_CTRL, SDL_KMOD_LCTRL, SDL_KMOD_LSHIFT, SDL_KMOD_NONE, SDL_KMOD_NUM,
+        SDL_KMOD_RCTRL, SDL_KMOD_RSHIFT, SDL_KMOD_SHIFT,
+    },
+    scancode::{
+        SDL_Scancode, SDL_SCANCODE_1, SDL_SCANCODE_A, SDL_SCANCODE_COUNT, SDL_SCANCODE_NONUSHASH,
+        SDL_SCANCODE_Q, SDL_SCANCODE_S, SDL_SCANCODE_W,
+    },
+};
+
+#[test]
+fn names_round_trip() {
+    for i in 0..SDL_SCANCODE_COUNT.0 {
+        let scancode = SDL_Scancode(i);
+        let Some(name) = scancode_name(scancode) else {
+            continue;
+        };
+        assert!(scancode_from_name(name) == Some(scancode), "{name}");
+        assert!(
+            scancode_from_name(&name.to_lowercase()) == Some(scancode),
+            "{name}"
+        );
+
+        let key = default_keycode(scancode, SDL_KMOD_NONE);
+        let key_name = key_name(key).unwrap();
+        assert!(key_from_name(key_name.as_str()) == Some(key), "{key_name}");
+        if scancode != SDL_SCANCODE_NONUSHASH {
+            assert!(
+                default_scancode(key) == Some((scancode, SDL_KMOD_NONE)),
+                "{name}"
+            );
+        }
+
+        for key in [Key::Keycode(key), Key::Scancode(scancode)] {
+            assert_eq!(key.to_string().parse::<Key>(), Ok(key));
+        }
+    }
+
+    assert_eq!(key_name(SDLK_A).unwrap().as_str(), "A");
+    assert_eq!(key_name(SDL_Keycode('é' as u32)).unwrap().as_str(), "é");
+    assert_eq!(key_name(SDLK_LMETA).unwrap().as_str(), "Left Meta");
+    assert!(key_from_name("left meta") == Some(SDLK_LMETA));
+    assert!(key_name(SDL_Keycode(1)).is_none());
+    assert_eq!(Key::Keycode(SDL_Keycode(1)).to_string(), "0x1");
+    assert_eq!("0x1".parse::<Key>(), Ok(Key::Keycode(SDL_Keycode(1))));
+    assert_eq!(
+        Key::Scancode(SDL_Scancode(400)).to_string(),
+        "Scancode 0x190"
+    );
+}
+
+#[test]
+fn default_keymap() {
+    assert!(default_keycode(SDL_SCANCODE_1, SDL_KMOD_RSHIFT) == SDLK_EXCLAIM);
+    assert!(default_keycode(SDL_SCANCODE_A, SDL_KMOD_CAPS) == SDL_Keycode('A' as u32));
+    assert!(default_keycode(SDL_SCANCODE_A, SDL_KMOD_CAPS | SDL_KMOD_SHIFT) == SDLK_A);
+    assert!(default_scancode(SDLK_EXCLAIM) == Some((SDL_SCANCODE_1, SDL_KMOD_SHIFT)));
+    assert!(default_scancode(SDL_Keycode('é' as u32)).is_none());
+}
+
+#[test]
+fn parse_and_format_shortcuts() {
+    for (text, expected) in [
+        ("Ctrl+Shift+F5", "Ctrl+Shift+F5"),
+        ("shift + control + f5", "Ctrl+Shift+F5"),
+        ("Ctrl++", "Ctrl++"),
+        ("Keypad +", "Keypad +"),
+        ("Alt+Keypad +", "Alt+Keypad +"),
+        ("LCtrl+RShift+Scancode W", "LCtrl+RShift+Scancode W"),
+        ("Cmd+Option+a", "Alt+GUI+A"),
+        ("AltGr+Level5+Space", "AltGr+Level5+Space"),
+        ("+", "+"),
+    ] {
+        let shortcut: Shortcut = text.parse().unwrap();
+        assert_eq!(shortcut.to_string(), expected, "{text}");
+        assert_eq!(expected.parse::<Shortcut>(), Ok(shortcut));
+    }
+    assert_eq!("".parse::<Shortcut>(), Err(ParseShortcutError::Empty));
+    assert_eq!(
+        "Foo+A".parse::<Shortcut>(),
+        Err(ParseShortcutError::UnknownKey)
+    );
+    assert_eq!(
+        "Ctrl+".parse::<Shortcut>(),
+        Err(ParseShortcutError::UnknownKey)
+    );
+}
+
+#[test]
+fn non_ascii_shortcuts() {
+    let e_acute = Key::Keycode(SDL_Keycode('é' as u32));
+    assert_eq!("é".parse::<Key>(), Ok(e_acute));
+    for (text, expected) in [
+        ("é", "é"),
+        ("Ctrl+é", "Ctrl+é"),
+        ("shift+ctrl+é", "Ctrl+Shift+é"),
+        ("Ctrl+ß", "Ctrl+ß"),
+        ("Alt+€", "Alt+€"),
+    ] {
+        let shortcut: Shortcut = text.parse().unwrap();
+        assert_eq!(shortcut.to_string(), expected, "{text}");
+        assert_eq!(expected.parse::<Shortcut>(), Ok(shortcut));
+    }
+    assert_eq!(
+        "Ctrl+é".parse::<Shortcut>(),
+        Ok(Shortcut::new(e_acute, SDL_KMOD_CTRL))
+    );
+    assert_eq!(
+        "é+A".parse::<Shortcut>(),
+        Err(ParseShortcutError::UnknownKey)
+    );
+    assert_eq!(
+        "Ctrl+é+".parse::<Shortcut>(),
+        Err(ParseShortcutError::UnknownKey)
+    );
+
+    let bindings: KeyBindings = "accent = Ctrl+é\n".parse().unwrap();
+    assert_eq!(bindings.to_string(), "accent = Ctrl+é\n");
+}
+
+#[test]
+fn shortcuts_round_trip() {
+    let mods = [
+        SDL_KMOD_NONE,
+        SDL_KMOD_CTRL,
+        SDL_KMOD_LCTRL | SDL_KMOD_RSHIFT,
+    ];
+    let keycodes = (0..SDL_SCANCODE_COUNT.0)
+        .map(|i| default_keycode(SDL_Scancode(i), SDL_KMOD_NONE))
+        .chain(['+', 'é', 'ß', 'Ω', '€', 'ж', '中'].map(|c| SDL_Keycode(c as u32)))
+        .chain([SDL_Keycode(1), SDL_Keycode(0x4000_1234)]);
+    let scancodes = (0..SDL_SCANCODE_COUNT.0 + 2).map(SDL_Scancode);
+    for key in keycodes
+        .map(Key::Keycode)
+        .chain(scancodes.map(Key::Scancode))
+    {
+        assert_eq!(key.to_string().parse::<Key>(), Ok(key));
+        for mods in mods {
+            let shortcut = Shortcut::new(key, mods);
+            assert_eq!(
+                shortcut.to_string().parse::<Shortcut>(),
+                Ok(shortcut),
+                "{shortcut}"
+            );
+        }
+    }
+}
+
+#[test]
+fn match_shortcuts() {
+    let shortcut: Shortcut = "Ctrl+S".parse().unwrap();
+    assert!(shortcut.matches(SDLK_S, SDL_SCANCODE_S, SDL_KMOD_LCTRL | SDL_KMOD_NUM));
+    assert!(shortcut.matches(SDL_Keycode('S' as u32), SDL_SCANCODE_S, SDL_KMOD_CTRL));
+    assert!(!shortcut.matches(SDLK_S, SDL_SCANCODE_S, SDL_KMOD_LCTRL | SDL_KMOD_LSHIFT));
+    assert!(!shortcut.matches(SDLK_S, SDL_SCANCODE_S, SDL_KMOD_NONE));
+
+    let shortcut: Shortcut = "LCtrl+S".parse().unwrap();
+    assert!(shortcut.matches(SDLK_S, SDL_SCANCODE_S, SDL_KMOD_LCTRL));
+    assert!(!shortcut.matches(SDLK_S, SDL_SCANCODE_S, SDL_KMOD_RCTRL));
+
+    let shortcut: Shortcut = "Scancode W".parse().unwrap();
+    assert!(shortcut.matches(SDLK_Z, SDL_SCANCODE_W, SDL_KMOD_CAPS));
+
+    let shortcut = Shortcut::from_key_press(
+        SDL_Keycode('S' as u32),
+        SDL_KMOD_LCTRL | SDL_KMOD_RSHIFT | SDL_KMOD_CAPS,
+    );
+    assert_eq!(shortcut.to_string(), "Ctrl+Shift+S");
+    let shortcut = Shortcut::from_key_press(SDLK_LSHIFT, SDL_KMOD_LSHIFT | SDL_KMOD_LCTRL);
+    assert_eq!(shortcut.to_string(), "Ctrl+Left Shift");
+}
+
+#[test]
+fn key_bindings() {
+    let text = "# comment\nsave = Ctrl+S\n\nsave = Cmd+S\nquit = Ctrl+Q\n";
+    let mut bindings: KeyBindings = text.parse().unwrap();
+    assert_eq!(bindings.len(), 3);
+    assert_eq!(
+        bindings.to_string(),
+        "save = Ctrl+S\nsave = GUI+S\nquit = Ctrl+Q\n"
+    );
+    assert_eq!(bindings.to_string().parse(), Ok(bindings.clone()));
+    assert_eq!(
+        bindings.action_for_key_press(SDLK_Q, SDL_SCANCODE_Q, SDL_KMOD_RCTRL),
+        Some("quit")
+    );
+    assert_eq!(bindings.shortcuts("save").count(), 2);
+    let quit = "Ctrl+Q".parse().unwrap();
+    assert_eq!(bindings.actions(&quit).collect::<Vec<_>>(), ["quit"]);
+
+    assert!(bindings.unbind("save", &"GUI+S".parse().unwrap()));
+    bindings.unbind_action("quit");
+    assert_eq!(bindings.to_string(), "save = Ctrl+S\n");
+
+    let error = "save = Ctrl+S\nbroken\n"
+        .parse::<KeyBindings>()
+        .unwrap_err();
+    assert_eq!(error.to_string(), "line 2: expected `action = shortcut`");
+    let error = "save = Ctrl+Foo".parse::<KeyBindings>().unwrap_err();
+    assert_eq!(error.to_string(), "line 1: unknown key name");
+    let error = " = A".parse::<KeyBindings>().unwrap_err();
+    assert_eq!(error.kind, ParseBindingsErrorKind::EmptyAction);
+}
+
+fn main() {
+    let tests = libtest_mimic_collect::TestCollection::collect_tests();
+    let args = libtest_mimic::Arguments::from_args();
+    libtest_mimic::run(&args, tests).exit();
+}
@@ -1,15 +1,22 @@
 use core::{
+    cell::Cell,
     ffi::CStr,
     sync::atomic::{AtomicBool, AtomicUsize, Ordering},
 };
 use libtest_mimic_collect::{libtest_mimic, test};
-use sdl3_main::{run_async_on_main_thread, run_sync_on_main_thread, MainThreadToken};
+use sdl3_main::{
+    run_async_on_main_thread, run_sync_on_main_thread, MainThreadBox, MainThreadToken,
+};
 use sdl3_sys::{
     error::SDL_GetError,
     events::SDL_PumpEvents,
     init::{SDL_Init, SDL_Quit, SDL_INIT_VIDEO},
+    stdinc::{
+        SDL_GetOriginalMemoryFunctions, SDL_SetMemoryFunctions, SDL_calloc_func, SDL_free_func,
+        SDL_malloc_func, SDL_realloc_func,
+    },
 };
-use std::{ffi::c_void, thread};
+use std::{ffi::c_void, sync::OnceLock, thread};
 
 macro_rules! defer {
     ($($tt:tt)*) => {
@@ -27,6 +34,64 @@ impl<F: FnOnce()> Drop for Defer<F> {
     }
 }
 
+thread_local! {
+    static FAIL_ALLOC: Cell<bool> = const { Cell::new(false) };
+}
+
+static ORIGINAL_MEMORY_FUNCTIONS: OnceLock<(
+    SDL_malloc_func,
+    SDL_calloc_func,
+    SDL_realloc_func,
+    SDL_free_func,
+)> = OnceLock::new();
+
+// Wrap SDL's memory functions so they can be made to fail with `with_failing_alloc`.
+// SDL doesn't allow changing them after it's been initialized, so this is called before
+// running any tests
+fn install_memory_functions() {
+    unsafe extern "C" fn malloc(size: usize) -> *mut c_void {
+        if FAIL_ALLOC.get() {
+            return core::ptr::null_mut();
+        }
+        unsafe { ORIGINAL_MEMORY_FUNCTIONS.get().unwrap().0.unwrap()(size) }
+    }
+
+    unsafe extern "C" fn calloc(nmemb: usize, size: usize) -> *mut c_void {
+        if FAIL_ALLOC.get() {
+            return core::ptr::null_mut();
+        }
+        unsafe { ORIGINAL_MEMORY_FUNCTIONS.get().unwrap().1.unwrap()(nmemb, size) }
+    }
+
+    unsafe extern "C" fn realloc(mem: *mut c_void, size: usize) -> *mut c_void {
+        if FAIL_ALLOC.get() {
+            return core::ptr::null_mut();
+        }
+        unsafe { ORIGINAL_MEMORY_FUNCTIONS.get().unwrap().2.unwrap()(mem, size) }
+    }
+
+    unsafe extern "C" fn free(mem: *mut c_void) {
+        unsafe { ORIGINAL_MEMORY_FUNCTIONS.get().unwrap().3.unwrap()(mem) }
+    }
+
+    ORIGINAL_MEMORY_FUNCTIONS.get_or_init(|| {
+        let (mut m, mut c, mut r, mut f) = (None, None, None, None);
+        unsafe { SDL_GetOriginalMemoryFunctions(&mut m, &mut c, &mut r, &mut f) };
+        (m, c, r, f)
+    });
+    assert!(unsafe {
+        SDL_SetMemoryFunctions(Some(malloc), Some(calloc), Some(realloc), Some(free))
+    });
+}
+
+// Make SDL_malloc fail on the current thread while `f` runs
+fn with_failing_alloc<R>(f: impl FnOnce() -> R) -> R {
+    FAIL_ALLOC.set(true);
+    let result = f();
+    FAIL_ALLOC.set(false);
+    result
+}
+
 #[must_use]
 fn async_zst<F: FnOnce() + Send + 'static>(callback: F) -> bool {
     assert!(size_of::<F>() == 0);
@@ -317,7 +382,85 @@ fn run_async_on_main_thread_drop_large_on_thread() {
     assert_eq!(VALUE.load(Ordering::Acquire), 0x1234);
 }
 
+#[test]
+fn main_thread_box_drop_on_thread() {
+    struct Dropper(u32);
+    impl Drop for Dropper {
+        fn drop(&mut self) {
+            MainThreadToken::assert();
+            DROPPED.store(true, Ordering::Release);
+        }
+    }
+    static DROPPED: AtomicBool = AtomicBool::new(false);
+    if !unsafe { SDL_Init(SDL_INIT_VIDEO) } {
+        dbg!(unsafe { CStr::from_ptr(SDL_GetError()) });
+        panic!();
+    }
+    defer!(unsafe { SDL_Quit() });
+    let mut data = MainThreadBox::assert_new(Dropper(0x1234));
+    data.assert_get_mut().0 += 1;
+    thread::spawn(move || {
+        assert!(MainThreadToken::get().is_none());
+        assert!(data.get_on_main_thread(|d| assert_eq!(d.0, 0x1235)));
+        drop(data);
+    });
+    while !DROPPED.load(Ordering::Acquire) {
+        unsafe { SDL_PumpEvents() };
+    }
+}
+
+#[test]
+fn main_thread_box_leak_on_failed_dispatch() {
+    struct Dropper;
+    impl Drop for Dropper {
+        fn drop(&mut self) {
+            MainThreadToken::assert();
+            DROPPED.store(true, Ordering::Release);
+        }
+    }
+    static DROPPED: AtomicBool = AtomicBool::new(false);
+    if !unsafe { SDL_Init(SDL_INIT_VIDEO) } {
+        dbg!(unsafe { CStr::from_ptr(SDL_GetError()) });
+        panic!();
+    }
+    defer!(unsafe { SDL_Quit() });
+    let data = MainThreadBox::assert_new(Dropper);
+    thread::spawn(move || {
+        assert!(MainThreadToken::get().is_none());
+        // the value can't be sent to the main thread, so it must be leaked instead of
+        // dropped on this thread
+        with_failing_alloc(|| drop(data));
+    })
+    .join()
+    .unwrap();
+    unsafe { SDL_PumpEvents() };
+    assert!(!DROPPED.load(Ordering::Acquire));
+}
+
+#[test]
+fn main_thread_box_drop_on_main() {
+    static DROPPED: AtomicBool = AtomicBool::new(false);
+    struct Dropper;
+    impl Drop for Dropper {
+        fn drop(&mut self) {
+            DROPPED.store(true, Ordering::Release);
+        }
+    }
+    if !unsafe { SDL_Init(SDL_INIT_VIDEO) } {
+        dbg!(unsafe { CStr::from_ptr(SDL_GetError()) });
+        panic!();
+    }
+    defer!(unsafe { SDL_Quit() });
+    let data = thread::spawn(|| MainThreadBox::new_send(Dropper))
+        .join()
+        .unwrap();
+    drop(data);
+    assert!(DROPPED.load(Ordering::Acquire));
+}
+
 fn main() {
+    install_memory_functions();
+
     let tests = libtest_mimic_collect::TestCollection::collect_tests();
     let mut args = libtest_mimic::Arguments::from_args();
     args.test_threads = Some(1);
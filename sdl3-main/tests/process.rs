@@ -0,0 +1,103 @@
+#![cfg_attr(not(unix), allow(unused))]
+
+use libtest_mimic_collect::{libtest_mimic, test};
+use sdl3_main::process::{Command, Stdio};
+use std::io::{Read, Write};
+
+#[cfg(unix)]
+fn sh(script: &str) -> Command {
+    let mut command = Command::new("/bin/sh");
+    command.arg("-c").arg(script);
+    command
+}
+
+#[cfg(unix)]
+#[test]
+fn output_and_exit_code() {
+    let output = sh("echo out; echo err >&2; exit 3").output().unwrap();
+    assert_eq!(output.status.code(), Some(3));
+    assert!(!output.status.success());
+    assert_eq!(output.stdout, b"out\n");
+    assert_eq!(output.stderr, b"err\n");
+
+    let output = sh("echo out; echo err >&2")
+        .stderr_to_stdout(true)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(output.stdout, b"out\nerr\n");
+    assert!(output.stderr.is_empty());
+}
+
+#[cfg(unix)]
+#[test]
+fn env_and_current_dir() {
+    let output = sh("echo \"$A,$B,$HOME\"; pwd")
+        .env("A", "1")
+        .env("B", "2")
+        .env_remove("B")
+        .current_dir("/")
+        .output()
+        .unwrap();
+    let home = std::env::var("HOME").unwrap_or_default();
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        format!("1,,{home}\n/\n")
+    );
+
+    let output = sh("echo \"$HOME,$A\"")
+        .env_clear()
+        .env("A", "1")
+        .output()
+        .unwrap();
+    assert_eq!(output.stdout, b",1\n");
+}
+
+#[cfg(unix)]
+#[test]
+fn piped_stdin() {
+    let mut child = sh("tr a-z A-Z")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    assert_ne!(child.id(), 0);
+    child.stdin.as_mut().unwrap().write_all(b"hello").unwrap();
+    drop(child.stdin.take());
+    let mut out = String::new();
+    child
+        .stdout
+        .as_mut()
+        .unwrap()
+        .read_to_string(&mut out)
+        .unwrap();
+    assert_eq!(out, "HELLO");
+    assert!(child.wait().unwrap().success());
+}
+
+#[cfg(unix)]
+#[test]
+fn kill_and_try_wait() {
+    let mut child = sh("sleep 10").spawn().unwrap();
+    assert_eq!(child.try_wait().unwrap(), None);
+    child.kill().unwrap();
+    let status = child.wait().unwrap();
+    assert_eq!(status.code(), None);
+    assert_eq!(status.signal(), Some(9));
+    assert_eq!(child.try_wait().unwrap(), Some(status));
+    child.kill().unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn spawn_failure() {
+    let status = Command::new("/nonexistent/program").status();
+    assert!(!status.is_ok_and(|status| status.success()));
+    assert!(Command::new("nul\0byte").spawn().is_err());
+}
+
+fn main() {
+    let tests = libtest_mimic_collect::TestCollection::collect_tests();
+    let args = libtest_mimic::Arguments::from_args();
+    libtest_mimic::run(&args, tests).exit();
+}
@@ -0,0 +1,116 @@
+use core::{
+    ffi::c_void,
+    ptr,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use libtest_mimic_collect::{libtest_mimic, test};
+use sdl3_main::properties::{Properties, PropertyValue};
+use sdl3_sys::properties::SDL_PropertyType;
+use std::ffi::CString;
+
+#[test]
+fn set_and_get() {
+    let props = Properties::builder()
+        .with(c"string", c"value")
+        .with(c"number", 42_i64)
+        .with(c"float", 0.5_f32)
+        .with(c"bool", true)
+        .build()
+        .unwrap();
+    assert_eq!(props.get::<CString>(c"string").as_deref(), Some(c"value"));
+    assert_eq!(props.get::<i64>(c"number"), Some(42));
+    assert_eq!(props.get::<f32>(c"float"), Some(0.5));
+    assert_eq!(props.get::<bool>(c"bool"), Some(true));
+    assert_eq!(
+        props.get::<PropertyValue>(c"number"),
+        Some(PropertyValue::Number(42))
+    );
+    assert!(props.property_type(c"float") == SDL_PropertyType::FLOAT);
+
+    // values aren't converted between types
+    assert_eq!(props.get::<i64>(c"float"), None);
+    assert_eq!(props.get::<CString>(c"number"), None);
+    assert_eq!(props.get::<bool>(c"missing"), None);
+
+    assert!(props.set(c"number", CString::from(c"replaced")));
+    assert_eq!(
+        props.get::<PropertyValue>(c"number"),
+        Some(PropertyValue::String(c"replaced".into()))
+    );
+    assert!(props.has(c"bool"));
+    assert!(props.clear(c"bool"));
+    assert!(!props.has(c"bool"));
+    assert!(props.property_type(c"bool") == SDL_PropertyType::INVALID);
+
+    let lock = props.lock().unwrap();
+    assert!(lock.set(c"locked", 1_i64));
+    drop(lock);
+    assert_eq!(props.get::<i64>(c"locked"), Some(1));
+}
+
+#[test]
+fn iterate() {
+    let props = Properties::builder()
+        .with(c"b", 2_i64)
+        .with(c"a", c"1")
+        .with(c"c", false)
+        .build()
+        .unwrap();
+    let mut entries: Vec<_> = props.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(
+        entries,
+        [
+            (c"a".into(), PropertyValue::String(c"1".into())),
+            (c"b".into(), PropertyValue::Number(2)),
+            (c"c".into(), PropertyValue::Boolean(false)),
+        ]
+    );
+    assert_eq!(Properties::new().unwrap().iter().count(), 0);
+}
+
+#[test]
+fn try_clone() {
+    static CLEANUPS: AtomicUsize = AtomicUsize::new(0);
+    unsafe extern "C" fn cleanup(_userdata: *mut c_void, _value: *mut c_void) {
+        CLEANUPS.fetch_add(1, Ordering::Relaxed);
+    }
+
+    let props = Properties::builder()
+        .with(c"number", 1_i64)
+        .with(c"string", c"original")
+        .build()
+        .unwrap();
+    let mut value = 0_u8;
+    let value = &mut value as *mut u8 as *mut c_void;
+    unsafe {
+        assert!(props.set_pointer(c"pointer", value));
+        assert!(props.set_pointer_with_cleanup(c"cleanup", value, Some(cleanup), ptr::null_mut()));
+    }
+
+    let copy = props.try_clone().unwrap();
+    assert!(copy.id() != props.id());
+    assert_eq!(copy.get::<i64>(c"number"), Some(1));
+    assert_eq!(copy.get::<*mut c_void>(c"pointer"), Some(value));
+    // pointer properties with cleanup functions aren't copied
+    assert!(!copy.has(c"cleanup"));
+
+    assert!(copy.set(c"string", c"copy"));
+    assert_eq!(
+        props.get::<CString>(c"string").as_deref(),
+        Some(c"original")
+    );
+    assert_eq!(copy.get::<CString>(c"string").as_deref(), Some(c"copy"));
+
+    assert_eq!(CLEANUPS.load(Ordering::Relaxed), 0);
+    drop(copy);
+    assert_eq!(CLEANUPS.load(Ordering::Relaxed), 0);
+    drop(props);
+    assert_eq!(CLEANUPS.load(Ordering::Relaxed), 1);
+}
+
+fn main() {
+    let tests = libtest_mimic_collect::TestCollection::collect_tests();
+    let args = libtest_mimic::Arguments::from_args();
+    libtest_mimic::run(&args, tests).exit();
+}
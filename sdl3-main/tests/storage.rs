@@ -0,0 +1,307 @@
+use libtest_mimic_collect::{libtest_mimic, test};
+use sdl3_main::storage::{ArchiveStorage, MemoryStorage, StorageBackend, StorageError};
+use sdl3_sys::filesystem::{SDL_ENUM_CONTINUE, SDL_PATHTYPE_DIRECTORY, SDL_PATHTYPE_FILE};
+
+const STORED: u16 = 0;
+const DEFLATED: u16 = 8;
+
+// "hello" compressed with raw deflate
+const HELLO_DEFLATED: &[u8] = &[0xcb, 0x48, 0xcd, 0xc9, 0xc9, 0x07, 0x00];
+
+// Build a zip archive from (name, method, compressed data, uncompressed size)
+fn zip(files: &[(&str, u16, &[u8], usize)]) -> Vec<u8> {
+    let mut data = Vec::new();
+    let mut central = Vec::new();
+    for &(name, method, contents, size) in files {
+        let local = data.len() as u32;
+        data.extend(0x0403_4b50_u32.to_le_bytes());
+        data.extend([20, 0, 0, 0]);
+        data.extend(method.to_le_bytes());
+        data.extend([0, 0, 0x21, 0, 0, 0, 0, 0]);
+        data.extend((contents.len() as u32).to_le_bytes());
+        data.extend((size as u32).to_le_bytes());
+        data.extend((name.len() as u16).to_le_bytes());
+        data.extend([0, 0]);
+        data.extend(name.as_bytes());
+        data.extend(contents);
+
+        central.extend(0x0201_4b50_u32.to_le_bytes());
+        central.extend([20, 0, 20, 0, 0, 0]);
+        central.extend(method.to_le_bytes());
+        central.extend([0, 0, 0x21, 0, 0, 0, 0, 0]);
+        central.extend((contents.len() as u32).to_le_bytes());
+        central.extend((size as u32).to_le_bytes());
+        central.extend((name.len() as u16).to_le_bytes());
+        central.extend([0; 12]);
+        central.extend(local.to_le_bytes());
+        central.extend(name.as_bytes());
+    }
+    let central_offset = data.len() as u32;
+    data.extend(&central);
+    data.extend(0x0605_4b50_u32.to_le_bytes());
+    data.extend([0; 4]);
+    data.extend((files.len() as u16).to_le_bytes());
+    data.extend((files.len() as u16).to_le_bytes());
+    data.extend((central.len() as u32).to_le_bytes());
+    data.extend(central_offset.to_le_bytes());
+    data.extend([0, 0]);
+    data
+}
+
+// Build a pak archive from (name, contents)
+fn pak(files: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut data = b"PACK\0\0\0\0\0\0\0\0".to_vec();
+    let mut dir = Vec::new();
+    for &(name, contents) in files {
+        let mut entry = [0; 64];
+        entry[..name.len()].copy_from_slice(name.as_bytes());
+        entry[56..60].copy_from_slice(&(data.len() as u32).to_le_bytes());
+        entry[60..].copy_from_slice(&(contents.len() as u32).to_le_bytes());
+        dir.extend(entry);
+        data.extend(contents);
+    }
+    let dir_offset = data.len() as u32;
+    data[4..8].copy_from_slice(&dir_offset.to_le_bytes());
+    data[8..12].copy_from_slice(&(dir.len() as u32).to_le_bytes());
+    data.extend(dir);
+    data
+}
+
+fn list(storage: &mut impl StorageBackend, path: &str) -> Result<Vec<String>, StorageError> {
+    let mut names = Vec::new();
+    storage.enumerate(path, &mut |name| {
+        names.push(name.to_string());
+        SDL_ENUM_CONTINUE
+    })?;
+    Ok(names)
+}
+
+fn read(storage: &mut impl StorageBackend, path: &str) -> Result<Vec<u8>, StorageError> {
+    let mut buf = vec![0; storage.info(path)?.size as usize];
+    storage.read_file(path, &mut buf)?;
+    Ok(buf)
+}
+
+#[test]
+fn zip_archive() {
+    let mut archive = ArchiveStorage::from_zip(zip(&[
+        ("a.txt", STORED, b"stored", 6),
+        ("dir/", STORED, b"", 0),
+        ("dir/sub/b.txt", DEFLATED, HELLO_DEFLATED, 5),
+    ]))
+    .unwrap();
+    assert_eq!(
+        archive.paths().collect::<Vec<_>>(),
+        ["a.txt", "dir/sub/b.txt"]
+    );
+    assert_eq!(list(&mut archive, "").unwrap(), ["a.txt", "dir"]);
+    assert_eq!(list(&mut archive, "dir").unwrap(), ["sub"]);
+    assert_eq!(
+        list(&mut archive, "a.txt"),
+        Err(StorageError::NotADirectory)
+    );
+    assert!(archive.info("dir/sub").unwrap().r#type == SDL_PATHTYPE_DIRECTORY);
+    assert!(archive.info("a.txt").unwrap().r#type == SDL_PATHTYPE_FILE);
+    assert_eq!(read(&mut archive, "/a.txt/").unwrap(), b"stored");
+    #[cfg(feature = "use-miniz-oxide-v0-8")]
+    assert_eq!(read(&mut archive, "dir/sub/b.txt").unwrap(), b"hello");
+    #[cfg(not(feature = "use-miniz-oxide-v0-8"))]
+    assert!(read(&mut archive, "dir/sub/b.txt").is_err());
+
+    let mut buf = [0; 5];
+    assert_eq!(
+        archive.read_file("a.txt", &mut buf),
+        Err(StorageError::LengthMismatch)
+    );
+    assert_eq!(
+        archive.read_file("dir", &mut buf),
+        Err(StorageError::IsADirectory)
+    );
+    assert_eq!(
+        archive.read_file("c.txt", &mut buf),
+        Err(StorageError::NotFound)
+    );
+    assert_eq!(
+        archive.write_file("c.txt", b""),
+        Err(StorageError::ReadOnly)
+    );
+}
+
+#[test]
+fn corrupt_zip_archive() {
+    assert!(ArchiveStorage::from_zip(&b"not a zip"[..]).is_err());
+
+    let data = zip(&[("a.txt", STORED, b"stored", 6)]);
+    for len in 0..data.len() {
+        assert!(ArchiveStorage::from_zip(data[..len].to_vec()).is_err());
+    }
+
+    // offsets near the end of the address space must be rejected without overflowing
+    let central = data.len() - 22 - 51;
+    for (offset, value) in [
+        (data.len() - 6, 0xffff_fffe_u32),
+        (central + 42, 0xffff_fffe),
+        (central + 20, 0xffff_ffff),
+    ] {
+        let mut data = data.clone();
+        data[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+        assert!(ArchiveStorage::from_zip(data).is_err());
+    }
+}
+
+#[test]
+fn pak_archive() {
+    let mut archive =
+        ArchiveStorage::from_pak(pak(&[("maps/e1m1.bsp", b"map"), ("progs.dat", b"")])).unwrap();
+    assert_eq!(
+        archive.paths().collect::<Vec<_>>(),
+        ["maps/e1m1.bsp", "progs.dat"]
+    );
+    assert_eq!(list(&mut archive, "").unwrap(), ["maps", "progs.dat"]);
+    assert_eq!(read(&mut archive, "maps/e1m1.bsp").unwrap(), b"map");
+    assert_eq!(read(&mut archive, "progs.dat").unwrap(), b"");
+
+    assert!(ArchiveStorage::from_pak(&b"PACK"[..]).is_err());
+    let mut data = pak(&[("a", b"a")]);
+    data[4..8].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert!(ArchiveStorage::from_pak(data).is_err());
+}
+
+#[test]
+fn path_traversal() {
+    for name in [
+        "../evil.txt",
+        "dir/../../evil.txt",
+        "./a.txt",
+        "dir\\..\\evil.txt",
+    ] {
+        assert!(
+            ArchiveStorage::from_zip(zip(&[(name, STORED, b"evil", 4)])).is_err(),
+            "{name}"
+        );
+        assert!(
+            ArchiveStorage::from_pak(pak(&[(name, b"evil")])).is_err(),
+            "{name}"
+        );
+    }
+
+    let mut archive = ArchiveStorage::from_zip(zip(&[("dir/a.txt", STORED, b"a", 1)])).unwrap();
+    let mut buf = [0; 1];
+    for path in ["../dir/a.txt", "dir/../dir/a.txt", "dir//a.txt"] {
+        assert_eq!(
+            archive.read_file(path, &mut buf),
+            Err(StorageError::InvalidPath)
+        );
+        assert_eq!(archive.info(path).err(), Some(StorageError::InvalidPath));
+    }
+
+    let mut storage = MemoryStorage::new();
+    assert_eq!(
+        storage.insert_file("../a.txt", b"a"),
+        Err(StorageError::InvalidPath)
+    );
+    assert_eq!(
+        storage.write_file("dir/../a.txt", b"a"),
+        Err(StorageError::InvalidPath)
+    );
+    assert_eq!(storage.mkdir(".."), Err(StorageError::InvalidPath));
+}
+
+#[test]
+fn memory_storage() {
+    let mut storage = MemoryStorage::with_capacity(10);
+    storage.insert_file("dir/a.txt", b"abc").unwrap();
+    storage.write_file("b.txt", b"de").unwrap();
+    assert_eq!(storage.space_remaining(), 5);
+    assert_eq!(list(&mut storage, "").unwrap(), ["b.txt", "dir"]);
+
+    storage.copy("b.txt", "dir/c.txt").unwrap();
+    storage.rename("dir", "moved").unwrap();
+    assert_eq!(
+        storage.files().collect::<Vec<_>>(),
+        [
+            ("b.txt", &b"de"[..]),
+            ("moved/a.txt", b"abc"),
+            ("moved/c.txt", b"de")
+        ]
+    );
+    storage.rename("b.txt", "moved/c.txt").unwrap();
+    assert_eq!(storage.space_remaining(), 5);
+    storage.remove("moved/a.txt").unwrap();
+    storage.remove("moved/c.txt").unwrap();
+    storage.remove("moved").unwrap();
+    assert!(storage.files().next().is_none());
+    assert_eq!(storage.space_remaining(), 10);
+}
+
+#[test]
+fn memory_storage_errors() {
+    let mut storage = MemoryStorage::with_capacity(7);
+    storage.insert_file("dir/sub/a.txt", b"abc").unwrap();
+    storage.insert_file("b.txt", b"de").unwrap();
+
+    assert_eq!(storage.mkdir("b.txt"), Err(StorageError::NotADirectory));
+    assert_eq!(storage.mkdir("b.txt/dir"), Err(StorageError::NotADirectory));
+    storage.mkdir("dir/sub").unwrap();
+
+    assert_eq!(
+        storage.rename("missing", "c.txt"),
+        Err(StorageError::NotFound)
+    );
+    assert_eq!(storage.rename("", "c.txt"), Err(StorageError::InvalidPath));
+    assert_eq!(
+        storage.rename("dir", "dir/sub/dir"),
+        Err(StorageError::InvalidPath)
+    );
+    assert_eq!(
+        storage.rename("dir/sub", "b.txt"),
+        Err(StorageError::AlreadyExists)
+    );
+    assert_eq!(
+        storage.rename("b.txt", "dir/sub"),
+        Err(StorageError::IsADirectory)
+    );
+    assert_eq!(
+        storage.rename("b.txt", "missing/b.txt"),
+        Err(StorageError::NotFound)
+    );
+    assert_eq!(
+        storage.rename("dir", "b.txt/dir"),
+        Err(StorageError::NotADirectory)
+    );
+
+    assert_eq!(storage.copy("dir", "dir2"), Err(StorageError::IsADirectory));
+    assert_eq!(
+        storage.copy("missing", "c.txt"),
+        Err(StorageError::NotFound)
+    );
+    assert_eq!(
+        storage.copy("b.txt", "missing/c.txt"),
+        Err(StorageError::NotFound)
+    );
+    assert_eq!(
+        storage.copy("dir/sub/a.txt", "c.txt"),
+        Err(StorageError::NoSpace)
+    );
+
+    assert_eq!(storage.remove(""), Err(StorageError::InvalidPath));
+    assert_eq!(storage.remove("missing"), Err(StorageError::NotFound));
+    assert_eq!(storage.remove("dir"), Err(StorageError::DirectoryNotEmpty));
+
+    assert_eq!(storage.write_file("", b""), Err(StorageError::IsADirectory));
+    assert_eq!(
+        storage.write_file("dir", b""),
+        Err(StorageError::IsADirectory)
+    );
+    assert_eq!(
+        storage.write_file("b.txt", b"0123456789"),
+        Err(StorageError::NoSpace)
+    );
+    assert_eq!(storage.file("b.txt"), Some(&b"de"[..]));
+}
+
+fn main() {
+    let tests = libtest_mimic_collect::TestCollection::collect_tests();
+    let args = libtest_mimic::Arguments::from_args();
+    libtest_mimic::run(&args, tests).exit();
+}
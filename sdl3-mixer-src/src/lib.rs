@@ -24,3 +24,123 @@ pub const REVISION_OFFSET: &str = "0";
 
 /// Hash part of the revision
 pub const REVISION_HASH: &str = "g72a81869";
+
+/// License of a library in the source tree
+#[derive(Clone, Copy, Debug)]
+pub struct License {
+    /// Name of the library
+    pub name: &'static str,
+    /// SPDX license expression
+    pub spdx: &'static str,
+    /// License files, relative to [`SOURCE_DIR`]
+    pub files: &'static [&'static str],
+}
+
+/// A library vendored in the source tree
+#[derive(Clone, Copy, Debug)]
+pub struct Vendored {
+    /// The library is built if [`VENDORED_OPTION`] and any of these CMake options are
+    /// enabled. It's always built with [`VENDORED_OPTION`] if this is empty.
+    pub cmake_options: &'static [&'static str],
+    /// License of the library
+    pub license: License,
+}
+
+/// License of SDL_mixer
+pub const LICENSE: License = License {
+    name: "SDL_mixer",
+    spdx: "Zlib",
+    files: &["LICENSE.txt"],
+};
+
+/// CMake option that enables building the vendored libraries
+pub const VENDORED_OPTION: Option<&str> = Some("SDLMIXER_VENDORED");
+
+/// Libraries vendored in the source tree
+pub const VENDORED: &[Vendored] = &[
+    Vendored {
+        cmake_options: &["SDLMIXER_FLAC_LIBFLAC"],
+        license: License {
+            name: "libFLAC",
+            spdx: "BSD-3-Clause",
+            files: &["external/flac/COPYING.Xiph"],
+        },
+    },
+    Vendored {
+        cmake_options: &["SDLMIXER_GME"],
+        license: License {
+            name: "Game_Music_Emu",
+            spdx: "LGPL-2.1-or-later",
+            files: &["external/libgme/license.txt"],
+        },
+    },
+    Vendored {
+        cmake_options: &["SDLMIXER_MOD_XMP"],
+        license: License {
+            name: "libxmp",
+            spdx: "MIT",
+            files: &["external/libxmp/README"],
+        },
+    },
+    Vendored {
+        cmake_options: &["SDLMIXER_MP3_MPG123"],
+        license: License {
+            name: "mpg123",
+            spdx: "LGPL-2.1-only",
+            files: &["external/mpg123/COPYING"],
+        },
+    },
+    Vendored {
+        cmake_options: &[
+            "SDLMIXER_FLAC_LIBFLAC",
+            "SDLMIXER_OPUS",
+            "SDLMIXER_VORBIS_TREMOR",
+            "SDLMIXER_VORBIS_VORBISFILE",
+        ],
+        license: License {
+            name: "libogg",
+            spdx: "BSD-3-Clause",
+            files: &["external/ogg/COPYING"],
+        },
+    },
+    Vendored {
+        cmake_options: &["SDLMIXER_OPUS"],
+        license: License {
+            name: "Opus",
+            spdx: "BSD-3-Clause",
+            files: &["external/opus/COPYING"],
+        },
+    },
+    Vendored {
+        cmake_options: &["SDLMIXER_OPUS"],
+        license: License {
+            name: "opusfile",
+            spdx: "BSD-3-Clause",
+            files: &["external/opusfile/COPYING"],
+        },
+    },
+    Vendored {
+        cmake_options: &["SDLMIXER_VORBIS_TREMOR"],
+        license: License {
+            name: "Tremor",
+            spdx: "BSD-3-Clause",
+            files: &["external/tremor/COPYING"],
+        },
+    },
+    Vendored {
+        cmake_options: &["SDLMIXER_VORBIS_VORBISFILE"],
+        license: License {
+            name: "libvorbis",
+            spdx: "BSD-3-Clause",
+            files: &["external/vorbis/COPYING"],
+        },
+    },
+    Vendored {
+        cmake_options: &["SDLMIXER_WAVPACK"],
+        license: License {
+            name: "WavPack",
+            spdx: "BSD-3-Clause",
+            files: &["external/wavpack/COPYING"],
+        },
+    },
+];
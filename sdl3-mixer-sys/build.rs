@@ -1,8 +1,42 @@
 #[cfg(feature = "build-from-source")]
 const SOURCE_DIR: &str = sdl3_mixer_src::SOURCE_DIR;
+#[cfg(feature = "build-from-source")]
+const SOURCE_REVISION_HASH: &str = sdl3_mixer_src::REVISION_HASH;
+#[cfg(feature = "build-from-source")]
+use sdl3_mixer_src as source_crate;
 
 const LINK_FRAMEWORK: bool = cfg!(feature = "link-framework");
 
+// cmake options that are exported as lowercase cfgs if they were enabled when building from source
+const CMAKE_CFGS: &[&str] = &[
+    "SDLMIXER_VENDORED",
+    "SDLMIXER_AIFF",
+    "SDLMIXER_WAVE",
+    "SDLMIXER_VOC",
+    "SDLMIXER_AU",
+    "SDLMIXER_FLAC_LIBFLAC",
+    "SDLMIXER_FLAC_DRFLAC",
+    "SDLMIXER_GME",
+    "SDLMIXER_MOD_XMP",
+    "SDLMIXER_MOD_XMP_LITE",
+    "SDLMIXER_MP3_DRMP3",
+    "SDLMIXER_MP3_MPG123",
+    "SDLMIXER_MIDI_FLUIDSYNTH",
+    "SDLMIXER_MIDI_TIMIDITY",
+    "SDLMIXER_OPUS",
+    "SDLMIXER_VORBIS_STB",
+    "SDLMIXER_VORBIS_VORBISFILE",
+    "SDLMIXER_VORBIS_TREMOR",
+    "SDLMIXER_WAVPACK",
+    "SDLMIXER_WAVPACK_DSD",
+];
+
+// features of the linked library that are exported as cfgs if they can be detected
+const FEATURE_PROBES: FeatureProbes = FeatureProbes::NONE;
+
+// emscripten port and the static libraries it builds, linked for emscripten when not building from source
+const EMSCRIPTEN_PORT: Option<(&str, &[&str])> = None;
+
 include!("build-common.rs");
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -20,7 +54,7 @@ fn main() -> Result<(), Box<dyn Error>> {
                     "SDL3_mixer is currently missing a configuration option to build as a framework. You can download the official framework build from <https://github.com/libsdl-org/SDL_mixer/releases>."
                 );
                 //config.define("SDL_FRAMEWORK", "ON");
-            } else if cfg!(feature = "link-static") {
+            } else if link_static() {
                 config.define("BUILD_SHARED_LIBS", "OFF");
             }
 
@@ -38,8 +72,8 @@ fn main() -> Result<(), Box<dyn Error>> {
                 SDLMIXER_FLAC_LIBFLAC,
                 SDLMIXER_FLAC_LIBFLAC_SHARED,
                 SDLMIXER_FLAC_DRFLAC,
-                SDLMIXER_FLAC_GME,
-                SDLMIXER_FLAC_GME_SHARED,
+                SDLMIXER_GME,
+                SDLMIXER_GME_SHARED,
                 SDLMIXER_MOD_XMP,
                 SDLMIXER_MOD_XMP_LITE,
                 SDLMIXER_MOD_XMP_SHARED,
@@ -0,0 +1,14 @@
+//! Check that the linked SDL_mixer library is compatible with the bindings at runtime
+//!
+//! See [`sdl3_sys::version_check`] for details.
+
+use crate::mixer::{MIX_Version, SDL_MIXER_VERSION};
+pub use sdl3_sys::version_check::{ApiKind, UnavailableApi, VersionReport};
+
+/// Compare the version of the linked SDL_mixer library with the version of the bindings.
+///
+/// This calls [`MIX_Version()`], so SDL_mixer must be linked. It doesn't check SDL itself;
+/// use [`sdl3_sys::version_check::check_compatible()`] for that.
+pub fn check_compatible() -> VersionReport {
+    sdl3_sys::__version_report!("SDL_mixer", SDL_MIXER_VERSION, MIX_Version(), None)
+}
@@ -1,8 +1,21 @@
 #[cfg(feature = "build-from-source")]
 const SOURCE_DIR: &str = sdl3_net_src::SOURCE_DIR;
+#[cfg(feature = "build-from-source")]
+const SOURCE_REVISION_HASH: &str = sdl3_net_src::REVISION_HASH;
+#[cfg(feature = "build-from-source")]
+use sdl3_net_src as source_crate;
 
 const LINK_FRAMEWORK: bool = cfg!(feature = "link-framework");
 
+// cmake options that are exported as lowercase cfgs if they were enabled when building from source
+const CMAKE_CFGS: &[&str] = &[];
+
+// features of the linked library that are exported as cfgs if they can be detected
+const FEATURE_PROBES: FeatureProbes = FeatureProbes::NONE;
+
+// emscripten port and the static libraries it builds, linked for emscripten when not building from source
+const EMSCRIPTEN_PORT: Option<(&str, &[&str])> = None;
+
 include!("build-common.rs");
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -22,7 +35,7 @@ fn main() -> Result<(), Box<dyn Error>> {
                     "SDL3_net is currently missing a configuration option to build as a framework. You can download the official framework build from <https://github.com/libsdl-org/SDL_image/releases>."
                 );
                 //config.define("SDL_FRAMEWORK", "ON");
-            } else if cfg!(feature = "link-static") {
+            } else if link_static() {
                 config.define("BUILD_SHARED_LIBS", "OFF");
             }
         }
@@ -0,0 +1,14 @@
+//! Check that the linked SDL_net library is compatible with the bindings at runtime
+//!
+//! See [`sdl3_sys::version_check`] for details.
+
+use crate::net::{NET_Version, SDL_NET_VERSION};
+pub use sdl3_sys::version_check::{ApiKind, UnavailableApi, VersionReport};
+
+/// Compare the version of the linked SDL_net library with the version of the bindings.
+///
+/// This calls [`NET_Version()`], so SDL_net must be linked. It doesn't check SDL itself;
+/// use [`sdl3_sys::version_check::check_compatible()`] for that.
+pub fn check_compatible() -> VersionReport {
+    sdl3_sys::__version_report!("SDL_net", SDL_NET_VERSION, NET_Version(), None)
+}
@@ -1,8 +1,34 @@
 #[cfg(feature = "build-from-source")]
 const SOURCE_DIR: &str = sdl3_sound_src::SOURCE_DIR;
+#[cfg(feature = "build-from-source")]
+const SOURCE_REVISION_HASH: &str = sdl3_sound_src::REVISION_HASH;
+#[cfg(feature = "build-from-source")]
+use sdl3_sound_src as source_crate;
 
 const LINK_FRAMEWORK: bool = cfg!(feature = "link-framework");
 
+// cmake options that are exported as lowercase cfgs if they were enabled when building from source
+const CMAKE_CFGS: &[&str] = &[
+    "SDLSOUND_WAV",
+    "SDLSOUND_AIFF",
+    "SDLSOUND_AU",
+    "SDLSOUND_VOC",
+    "SDLSOUND_FLAC",
+    "SDLSOUND_VORBIS",
+    "SDLSOUND_RAW",
+    "SDLSOUND_SHN",
+    "SDLSOUND_MODPLUG",
+    "SDLSOUND_MP3",
+    "SDLSOUND_MIDI",
+    "SDLSOUND_COREAUDIO",
+];
+
+// features of the linked library that are exported as cfgs if they can be detected
+const FEATURE_PROBES: FeatureProbes = FeatureProbes::NONE;
+
+// emscripten port and the static libraries it builds, linked for emscripten when not building from source
+const EMSCRIPTEN_PORT: Option<(&str, &[&str])> = None;
+
 include!("build-common.rs");
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -20,7 +46,7 @@ fn main() -> Result<(), Box<dyn Error>> {
                     "SDL3_sound is currently missing a configuration option to build as a framework."
                 );
                 //config.define("SDL_FRAMEWORK", "ON");
-            } else if cfg!(feature = "link-static") {
+            } else if link_static() {
                 config.define("SDLSOUND_BUILD_SHARED", "OFF");
             }
 
@@ -11,3 +11,5 @@ pub use generated::everything;
 
 #[cfg(feature = "metadata")]
 pub mod metadata;
+
+pub mod version_check;
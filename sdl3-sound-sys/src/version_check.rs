@@ -0,0 +1,19 @@
+//! Check that the linked SDL_sound library is compatible with the bindings at runtime
+//!
+//! See [`sdl3_sys::version_check`] for details.
+
+use crate::sound::{SDL_SOUND_VERSION, Sound_Version};
+pub use sdl3_sys::version_check::{ApiKind, UnavailableApi, VersionReport};
+
+/// Compare the version of the linked SDL_sound library with the version of the bindings.
+///
+/// This calls [`Sound_Version()`], so SDL_sound must be linked. It doesn't check SDL itself;
+/// use [`sdl3_sys::version_check::check_compatible()`] for that.
+pub fn check_compatible() -> VersionReport {
+    sdl3_sys::__version_report!(
+        "SDL_sound",
+        SDL_SOUND_VERSION,
+        unsafe { Sound_Version() },
+        None
+    )
+}
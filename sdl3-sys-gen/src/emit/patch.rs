@@ -1092,6 +1092,211 @@ pub fn patch_emit_opaque_struct(
     Ok(false)
 }
 
+enum EventTypes {
+    One(String),
+    Range(String, String),
+}
+
+// Get the event types for an event struct from the doc comment of its `type` field, e.g.
+// "SDL_EVENT_KEY_DOWN or SDL_EVENT_KEY_UP", "SDL_EVENT_DISPLAY_*" or
+// "SDL_EVENT_USER through SDL_EVENT_LAST". The names are returned without the `SDL_EVENT_`
+// prefix, as used for the associated constants of `SDL_EventType`
+fn event_types_from_doc(doc: &str) -> Vec<EventTypes> {
+    let mut names = Vec::new();
+    let mut rest = doc;
+    while let Some(i) = rest.find("SDL_EVENT_") {
+        rest = &rest[i + "SDL_EVENT_".len()..];
+        let len = rest
+            .find(|c: char| !(c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_'))
+            .unwrap_or(rest.len());
+        let (name, after) = rest.split_at(len);
+        if let Some(prefix) = after.strip_prefix('*').map(|_| name) {
+            names.push(EventTypes::Range(
+                format!("{prefix}FIRST"),
+                format!("{prefix}LAST"),
+            ));
+        } else if !name.is_empty() {
+            names.push(EventTypes::One(name.to_owned()));
+        }
+        rest = after;
+    }
+    if doc.contains(" through ")
+        && let [EventTypes::One(first), EventTypes::One(last)] = &names[..]
+    {
+        return vec![EventTypes::Range(first.clone(), last.clone())];
+    }
+    names
+}
+
+// Emit `event_type()`, safe accessors for the union members, and `From` impls for SDL_Event
+fn emit_event_helpers(ctx: &mut EmitContext, td: &TypeDef) -> EmitResult {
+    let TypeEnum::Struct(s) = &td.ty.ty else {
+        unreachable!()
+    };
+    let members: Vec<(&str, &str, Vec<EventTypes>)> = s
+        .fields
+        .iter()
+        .flat_map(|fields| &fields.fields)
+        .filter_map(|field| match &field.ty.ty {
+            TypeEnum::Ident(ty)
+                if ty.as_str().starts_with("SDL_") && ty.as_str().ends_with("Event") =>
+            {
+                let types = ctx
+                    .lookup_struct_sym(ty)
+                    .and_then(|sym| sym.fields)
+                    .and_then(|fields| {
+                        fields
+                            .fields
+                            .into_iter()
+                            .find(|field| field.ident.as_str() == "type")
+                    })
+                    .and_then(|field| field.doc)
+                    .map(|doc| event_types_from_doc(&doc.to_string()))
+                    .unwrap_or_default();
+                Some((field.ident.as_str(), ty.as_str(), types))
+            }
+            _ => None,
+        })
+        .collect();
+    // accessor method name and doc list of event types for each member
+    let mut accessors = Vec::new();
+
+    writeln!(ctx, "impl SDL_Event {{")?;
+    ctx.increase_indent();
+    writeln!(ctx, "/// Get the type of this event")?;
+    writeln!(ctx, "#[inline(always)]")?;
+    writeln!(ctx, "pub const fn event_type(&self) -> SDL_EventType {{")?;
+    ctx.increase_indent();
+    writeln!(ctx, "SDL_EventType(unsafe {{ self.r#type }})")?;
+    ctx.decrease_indent();
+    writeln!(ctx, "}}")?;
+
+    for (member, ty, types) in &members {
+        let name = ty
+            .strip_prefix("SDL_")
+            .unwrap()
+            .strip_suffix("Event")
+            .unwrap();
+        let mut method = String::from("as");
+        for c in name.chars() {
+            if c.is_ascii_uppercase() {
+                method.push('_');
+            }
+            method.push(c.to_ascii_lowercase());
+        }
+        writeln!(ctx)?;
+        if types.is_empty() {
+            // SDL_CommonEvent
+            writeln!(ctx, "/// Get the data shared by all events")?;
+            writeln!(ctx, "#[inline(always)]")?;
+            writeln!(ctx, "pub const fn {method}(&self) -> &{ty} {{")?;
+            ctx.increase_indent();
+            writeln!(ctx, "unsafe {{ &self.{member} }}")?;
+            ctx.decrease_indent();
+            writeln!(ctx, "}}")?;
+            accessors.push((method, String::new()));
+            continue;
+        }
+        let mut cond = String::new();
+        let mut doc = String::new();
+        for (i, t) in types.iter().enumerate() {
+            if i != 0 {
+                cond.push_str(" || ");
+                doc.push_str(if i + 1 == types.len() { " or " } else { ", " });
+            }
+            match t {
+                EventTypes::One(t) => {
+                    write!(cond, "t == SDL_EventType::{t}.0")?;
+                    write!(doc, "[`{t}`](SDL_EventType::{t})")?;
+                }
+                EventTypes::Range(first, last) => {
+                    let range =
+                        format!("t >= SDL_EventType::{first}.0 && t <= SDL_EventType::{last}.0");
+                    if types.len() == 1 {
+                        cond.push_str(&range);
+                    } else {
+                        write!(cond, "({range})")?;
+                    }
+                    write!(
+                        doc,
+                        "[`{first}`](SDL_EventType::{first})..=[`{last}`](SDL_EventType::{last})"
+                    )?;
+                }
+            }
+        }
+        writeln!(
+            ctx,
+            "/// Get the [`{ty}`] data of this event, if the event type is {doc}"
+        )?;
+        writeln!(ctx, "#[inline]")?;
+        writeln!(ctx, "pub const fn {method}(&self) -> Option<&{ty}> {{")?;
+        ctx.increase_indent();
+        writeln!(ctx, "let t = self.event_type().0;")?;
+        writeln!(ctx, "if {cond} {{")?;
+        ctx.increase_indent();
+        writeln!(ctx, "Some(unsafe {{ &self.{member} }})")?;
+        ctx.decrease_indent();
+        writeln!(ctx, "}} else {{")?;
+        ctx.increase_indent();
+        writeln!(ctx, "None")?;
+        ctx.decrease_indent();
+        writeln!(ctx, "}}")?;
+        ctx.decrease_indent();
+        writeln!(ctx, "}}")?;
+        accessors.push((method, doc));
+    }
+    ctx.decrease_indent();
+    writeln!(ctx, "}}")?;
+    writeln!(ctx)?;
+
+    for ((member, ty, types), (method, doc)) in members.iter().zip(&accessors) {
+        // the event type can only be filled in if there's exactly one choice. SDL_CommonEvent
+        // can be any event, so it doesn't get a From impl
+        let single = match &types[..] {
+            [] => continue,
+            [EventTypes::One(t)] => Some(t),
+            _ => None,
+        };
+        if let Some(t) = single {
+            writeln!(
+                ctx,
+                "/// Sets the event type to [`{t}`](SDL_EventType::{t}) if it's [`FIRST`](SDL_EventType::FIRST) (zero)"
+            )?;
+        } else {
+            writeln!(
+                ctx,
+                "/// The event type must be set to {doc}. This is checked with a debug assertion"
+            )?;
+        }
+        writeln!(ctx, "impl From<{ty}> for SDL_Event {{")?;
+        ctx.increase_indent();
+        writeln!(ctx, "#[inline]")?;
+        writeln!(ctx, "fn from(value: {ty}) -> Self {{")?;
+        ctx.increase_indent();
+        writeln!(ctx, "let mut event = Self::default();")?;
+        writeln!(ctx, "event.{member} = value;")?;
+        if let Some(t) = single {
+            writeln!(ctx, "if value.r#type == SDL_EventType::FIRST {{")?;
+            ctx.increase_indent();
+            writeln!(ctx, "event.{member}.r#type = SDL_EventType::{t};")?;
+            ctx.decrease_indent();
+            writeln!(ctx, "}}")?;
+        } else {
+            writeln!(
+                ctx,
+                "debug_assert!(event.{method}().is_some(), \"invalid event type for {ty}\");"
+            )?;
+        }
+        writeln!(ctx, "event")?;
+        ctx.decrease_indent();
+        writeln!(ctx, "}}")?;
+        ctx.decrease_indent();
+        writeln!(ctx, "}}")?;
+        writeln!(ctx)?;
+    }
+    Ok(())
+}
+
 pub fn patch_emit_type_def(
     ctx: &mut EmitContext,
     ident: &str,
@@ -1101,18 +1306,7 @@ pub fn patch_emit_type_def(
         let module = ctx.module().to_owned();
         return match (module.as_str(), ident) {
             ("events", "SDL_Event") => {
-                writeln!(ctx, "impl SDL_Event {{")?;
-                ctx.increase_indent();
-                writeln!(ctx, "/// Get the type of this event")?;
-                writeln!(ctx, "#[inline(always)]")?;
-                writeln!(ctx, "pub const fn event_type(&self) -> SDL_EventType {{")?;
-                ctx.increase_indent();
-                writeln!(ctx, "SDL_EventType(unsafe {{ self.r#type }})")?;
-                ctx.decrease_indent();
-                writeln!(ctx, "}}")?;
-                ctx.decrease_indent();
-                writeln!(ctx, "}}")?;
-                writeln!(ctx)?;
+                emit_event_helpers(ctx, td)?;
                 Ok(false)
             }
             ("gamepad", "SDL_GamepadBinding") => {
@@ -1,8 +1,307 @@
 #[cfg(feature = "build-from-source")]
 const SOURCE_DIR: &str = sdl3_src::SOURCE_DIR;
+#[cfg(feature = "build-from-source")]
+const SOURCE_REVISION_HASH: &str = sdl3_src::REVISION_HASH;
+#[cfg(feature = "build-from-source")]
+use sdl3_src as source_crate;
 
 const LINK_FRAMEWORK: bool = cfg!(feature = "link-framework");
 
+// cmake options that are exported as lowercase cfgs if they were enabled when building from source
+const CMAKE_CFGS: &[&str] = &[];
+
+// features of the linked library that are exported as cfgs if they can be detected
+const FEATURE_PROBES: FeatureProbes = FeatureProbes {
+    build_config_header: "SDL_build_config.h",
+    probes: &[
+        FeatureProbe {
+            cfg: "sdl_has_audio",
+            define: "!SDL_AUDIO_DISABLED",
+            cmake_option: "SDL_AUDIO",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_has_video",
+            define: "!SDL_VIDEO_DISABLED",
+            cmake_option: "SDL_VIDEO",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_has_gpu",
+            define: "!SDL_GPU_DISABLED",
+            cmake_option: "SDL_GPU",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_has_render",
+            define: "!SDL_RENDER_DISABLED",
+            cmake_option: "SDL_RENDER",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_has_camera",
+            define: "!SDL_CAMERA_DISABLED",
+            cmake_option: "SDL_CAMERA",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_has_joystick",
+            define: "!SDL_JOYSTICK_DISABLED",
+            cmake_option: "SDL_JOYSTICK",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_has_haptic",
+            define: "!SDL_HAPTIC_DISABLED",
+            cmake_option: "SDL_HAPTIC",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_has_hidapi",
+            define: "!SDL_HIDAPI_DISABLED",
+            cmake_option: "SDL_HIDAPI",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_has_power",
+            define: "!SDL_POWER_DISABLED",
+            cmake_option: "SDL_POWER",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_has_sensor",
+            define: "!SDL_SENSOR_DISABLED",
+            cmake_option: "SDL_SENSOR",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_has_dialog",
+            define: "!SDL_DIALOG_DISABLED",
+            cmake_option: "SDL_DIALOG",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_video_x11",
+            define: "SDL_VIDEO_DRIVER_X11",
+            cmake_option: "SDL_X11",
+            requires: &["x11"],
+        },
+        FeatureProbe {
+            cfg: "sdl_video_wayland",
+            define: "SDL_VIDEO_DRIVER_WAYLAND",
+            cmake_option: "SDL_WAYLAND",
+            requires: &["wayland-client"],
+        },
+        FeatureProbe {
+            cfg: "sdl_video_kmsdrm",
+            define: "SDL_VIDEO_DRIVER_KMSDRM",
+            cmake_option: "SDL_KMSDRM",
+            requires: &["libdrm", "gbm"],
+        },
+        FeatureProbe {
+            cfg: "sdl_video_offscreen",
+            define: "SDL_VIDEO_DRIVER_OFFSCREEN",
+            cmake_option: "SDL_OFFSCREEN",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_video_dummy",
+            define: "SDL_VIDEO_DRIVER_DUMMY",
+            cmake_option: "SDL_DUMMYVIDEO",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_video_cocoa",
+            define: "SDL_VIDEO_DRIVER_COCOA",
+            cmake_option: "",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_video_uikit",
+            define: "SDL_VIDEO_DRIVER_UIKIT",
+            cmake_option: "",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_video_windows",
+            define: "SDL_VIDEO_DRIVER_WINDOWS",
+            cmake_option: "",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_video_android",
+            define: "SDL_VIDEO_DRIVER_ANDROID",
+            cmake_option: "",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_video_emscripten",
+            define: "SDL_VIDEO_DRIVER_EMSCRIPTEN",
+            cmake_option: "",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_video_opengl",
+            define: "SDL_VIDEO_OPENGL",
+            cmake_option: "SDL_OPENGL",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_video_opengles",
+            define: "SDL_VIDEO_OPENGL_ES2",
+            cmake_option: "SDL_OPENGLES",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_video_vulkan",
+            define: "SDL_VIDEO_VULKAN",
+            cmake_option: "SDL_VULKAN",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_video_metal",
+            define: "SDL_VIDEO_METAL",
+            cmake_option: "",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_audio_alsa",
+            define: "SDL_AUDIO_DRIVER_ALSA",
+            cmake_option: "SDL_ALSA",
+            requires: &["alsa"],
+        },
+        FeatureProbe {
+            cfg: "sdl_audio_pulseaudio",
+            define: "SDL_AUDIO_DRIVER_PULSEAUDIO",
+            cmake_option: "SDL_PULSEAUDIO",
+            requires: &["libpulse"],
+        },
+        FeatureProbe {
+            cfg: "sdl_audio_pipewire",
+            define: "SDL_AUDIO_DRIVER_PIPEWIRE",
+            cmake_option: "SDL_PIPEWIRE",
+            requires: &["libpipewire-0.3"],
+        },
+        FeatureProbe {
+            cfg: "sdl_audio_jack",
+            define: "SDL_AUDIO_DRIVER_JACK",
+            cmake_option: "SDL_JACK",
+            requires: &["jack"],
+        },
+        FeatureProbe {
+            cfg: "sdl_audio_sndio",
+            define: "SDL_AUDIO_DRIVER_SNDIO",
+            cmake_option: "SDL_SNDIO",
+            requires: &["sndio"],
+        },
+        FeatureProbe {
+            cfg: "sdl_audio_oss",
+            define: "SDL_AUDIO_DRIVER_OSS",
+            cmake_option: "SDL_OSS",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_audio_dummy",
+            define: "SDL_AUDIO_DRIVER_DUMMY",
+            cmake_option: "SDL_DUMMYAUDIO",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_audio_disk",
+            define: "SDL_AUDIO_DRIVER_DISK",
+            cmake_option: "SDL_DISKAUDIO",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_render_d3d",
+            define: "SDL_VIDEO_RENDER_D3D",
+            cmake_option: "SDL_RENDER_D3D",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_render_d3d11",
+            define: "SDL_VIDEO_RENDER_D3D11",
+            cmake_option: "SDL_RENDER_D3D11",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_render_d3d12",
+            define: "SDL_VIDEO_RENDER_D3D12",
+            cmake_option: "SDL_RENDER_D3D12",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_render_metal",
+            define: "SDL_VIDEO_RENDER_METAL",
+            cmake_option: "SDL_RENDER_METAL",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_render_vulkan",
+            define: "SDL_VIDEO_RENDER_VULKAN",
+            cmake_option: "SDL_RENDER_VULKAN",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_render_gpu",
+            define: "SDL_VIDEO_RENDER_GPU",
+            cmake_option: "SDL_RENDER_GPU",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_render_opengl",
+            define: "SDL_VIDEO_RENDER_OGL",
+            cmake_option: "",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_render_opengles",
+            define: "SDL_VIDEO_RENDER_OGL_ES2",
+            cmake_option: "",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_gpu_vulkan",
+            define: "SDL_GPU_VULKAN",
+            cmake_option: "",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_gpu_d3d12",
+            define: "SDL_GPU_D3D12",
+            cmake_option: "",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_gpu_metal",
+            define: "SDL_GPU_METAL",
+            cmake_option: "",
+            requires: &[],
+        },
+        FeatureProbe {
+            cfg: "sdl_libudev",
+            define: "HAVE_LIBUDEV_H",
+            cmake_option: "SDL_LIBUDEV",
+            requires: &["libudev"],
+        },
+        FeatureProbe {
+            cfg: "sdl_dbus",
+            define: "HAVE_DBUS_DBUS_H",
+            cmake_option: "SDL_DBUS",
+            requires: &["dbus-1"],
+        },
+        FeatureProbe {
+            cfg: "sdl_ibus",
+            define: "HAVE_IBUS_IBUS_H",
+            cmake_option: "SDL_IBUS",
+            requires: &["ibus-1.0"],
+        },
+    ],
+};
+
+// emscripten port and the static libraries it builds, linked for emscripten when not building from source
+const EMSCRIPTEN_PORT: Option<(&str, &[&str])> = Some(("sdl3", &["SDL3"]));
+
 include!("build-common.rs");
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -23,7 +322,7 @@ fn main() -> Result<(), Box<dyn Error>> {
 
             if LINK_FRAMEWORK {
                 config.define("SDL_FRAMEWORK", "ON");
-            } else if cfg!(feature = "link-static") {
+            } else if link_static() {
                 config.define("SDL_STATIC", "ON");
             }
 
@@ -45,6 +344,32 @@ fn main() -> Result<(), Box<dyn Error>> {
                 SDL_SENSOR,
                 SDL_DIALOG,
                 SDL_TRAY,
+                SDL_X11,
+                SDL_WAYLAND,
+                SDL_KMSDRM,
+                SDL_OFFSCREEN,
+                SDL_DUMMYVIDEO,
+                SDL_OPENGL,
+                SDL_OPENGLES,
+                SDL_VULKAN,
+                SDL_ALSA,
+                SDL_PULSEAUDIO,
+                SDL_PIPEWIRE,
+                SDL_JACK,
+                SDL_SNDIO,
+                SDL_OSS,
+                SDL_DUMMYAUDIO,
+                SDL_DISKAUDIO,
+                SDL_RENDER_D3D,
+                SDL_RENDER_D3D11,
+                SDL_RENDER_D3D12,
+                SDL_RENDER_METAL,
+                SDL_RENDER_VULKAN,
+                SDL_RENDER_GPU,
+                SDL_LIBUDEV,
+                SDL_DBUS,
+                SDL_IBUS,
+                SDL_HIDAPI_LIBUSB,
             }
         }
         Ok(())
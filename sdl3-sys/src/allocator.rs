@@ -0,0 +1,237 @@
+//! Share one heap between Rust and SDL
+//!
+//! [`SdlAllocator`] is a [`GlobalAlloc`] that allocates with SDL's current memory functions,
+//! so Rust allocations are included in [`SDL_GetNumAllocations()`] and go through any memory
+//! functions set with [`SDL_SetMemoryFunctions()`].
+//!
+//! [`set_memory_functions()`] does the reverse: it makes SDL allocate with a Rust allocator.
+//!
+//! Don't use both at once, as SDL and the Rust allocator would call each other forever.
+
+use crate::stdinc::{
+    SDL_GetNumAllocations, SDL_SetMemoryFunctions, SDL_aligned_alloc, SDL_aligned_free, SDL_calloc,
+    SDL_free, SDL_malloc, SDL_realloc,
+};
+use core::{
+    alloc::{GlobalAlloc, Layout},
+    ffi::{c_int, c_void},
+    ptr,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+
+// Alignment that SDL_malloc and friends are assumed to always satisfy. Allocations with
+// greater alignment use SDL_aligned_alloc
+const MALLOC_ALIGN: usize = align_of::<usize>();
+
+/// A [`GlobalAlloc`] that allocates memory with SDL's memory functions.
+///
+/// Allocations with an alignment greater than that of `usize` use [`SDL_aligned_alloc()`],
+/// and all others use [`SDL_malloc()`], [`SDL_calloc()`] and [`SDL_realloc()`].
+///
+/// Memory functions set with [`SDL_SetMemoryFunctions()`] must not allocate with the Rust
+/// global allocator when this is used as the global allocator.
+///
+/// Example:
+/// ```rust,no_run
+/// use sdl3_sys::allocator::SdlAllocator;
+///
+/// #[global_allocator]
+/// static ALLOCATOR: SdlAllocator = SdlAllocator;
+/// # fn main() {}
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SdlAllocator;
+
+impl SdlAllocator {
+    /// Get the number of outstanding allocations made with SDL's memory functions,
+    /// including those made through this allocator. See [`SDL_GetNumAllocations()`].
+    ///
+    /// This returns -1 if SDL wasn't built with allocation counting.
+    #[inline(always)]
+    pub fn num_allocations(&self) -> c_int {
+        SDL_GetNumAllocations()
+    }
+}
+
+unsafe impl GlobalAlloc for SdlAllocator {
+    #[inline]
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if layout.align() <= MALLOC_ALIGN {
+            unsafe { SDL_malloc(layout.size()) as *mut u8 }
+        } else {
+            unsafe { SDL_aligned_alloc(layout.align(), layout.size()) as *mut u8 }
+        }
+    }
+
+    #[inline]
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        if layout.align() <= MALLOC_ALIGN {
+            unsafe { SDL_calloc(1, layout.size()) as *mut u8 }
+        } else {
+            let ptr = unsafe { SDL_aligned_alloc(layout.align(), layout.size()) as *mut u8 };
+            if !ptr.is_null() {
+                unsafe { ptr.write_bytes(0, layout.size()) };
+            }
+            ptr
+        }
+    }
+
+    #[inline]
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if layout.align() <= MALLOC_ALIGN {
+            unsafe { SDL_free(ptr as *mut c_void) }
+        } else {
+            unsafe { SDL_aligned_free(ptr as *mut c_void) }
+        }
+    }
+
+    #[inline]
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if layout.align() <= MALLOC_ALIGN {
+            unsafe { SDL_realloc(ptr as *mut c_void, new_size) as *mut u8 }
+        } else {
+            // SDL doesn't have an aligned realloc
+            let new_layout = unsafe { Layout::from_size_align_unchecked(new_size, layout.align()) };
+            let new_ptr = unsafe { self.alloc(new_layout) };
+            if !new_ptr.is_null() {
+                unsafe {
+                    ptr::copy_nonoverlapping(ptr, new_ptr, layout.size().min(new_size));
+                    self.dealloc(ptr, layout);
+                }
+            }
+            new_ptr
+        }
+    }
+}
+
+// The size of each block is stored in a header before the memory returned to SDL. This is
+// also the alignment of all blocks, which must be suitable for any C type
+const HEADER_SIZE: usize = 16;
+
+static ALLOCATOR: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+
+/// Make SDL allocate memory with a Rust allocator, with [`SDL_SetMemoryFunctions()`].
+///
+/// Returns false if memory functions have already been set with this function, or if
+/// [`SDL_SetMemoryFunctions()`] fails. Call [`SDL_GetError()`](crate::error::SDL_GetError)
+/// for more information in the latter case.
+///
+/// This should be called before any other SDL function, so that no memory allocated by SDL's
+/// original memory functions is passed to the new ones.
+///
+/// The allocator must not use [`SdlAllocator`].
+///
+/// Example:
+/// ```rust,no_run
+/// use std::alloc::System;
+///
+/// assert!(sdl3_sys::allocator::set_memory_functions(&System));
+/// ```
+pub fn set_memory_functions<A: GlobalAlloc + Sync>(allocator: &'static A) -> bool {
+    let allocator = allocator as *const A as *mut c_void;
+    if ALLOCATOR
+        .compare_exchange(
+            ptr::null_mut(),
+            allocator,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        )
+        .is_err()
+    {
+        return false;
+    }
+    let ok = unsafe {
+        SDL_SetMemoryFunctions(
+            Some(rust_malloc::<A>),
+            Some(rust_calloc::<A>),
+            Some(rust_realloc::<A>),
+            Some(rust_free::<A>),
+        )
+    };
+    if !ok {
+        ALLOCATOR.store(ptr::null_mut(), Ordering::Release);
+    }
+    ok
+}
+
+#[inline(always)]
+unsafe fn allocator<A: 'static>() -> &'static A {
+    unsafe { &*(ALLOCATOR.load(Ordering::Acquire) as *const A) }
+}
+
+#[inline(always)]
+fn block_layout(size: usize) -> Option<Layout> {
+    Layout::from_size_align(size.checked_add(HEADER_SIZE)?, HEADER_SIZE).ok()
+}
+
+#[inline(always)]
+unsafe fn finish_block(block: *mut u8, size: usize) -> *mut c_void {
+    if block.is_null() {
+        return ptr::null_mut();
+    }
+    unsafe {
+        (block as *mut usize).write(size);
+        block.add(HEADER_SIZE) as *mut c_void
+    }
+}
+
+// Get the start and the layout of the block containing `mem`
+#[inline(always)]
+unsafe fn block_of(mem: *mut c_void) -> (*mut u8, Layout) {
+    unsafe {
+        let block = (mem as *mut u8).sub(HEADER_SIZE);
+        let size = (block as *const usize).read();
+        (
+            block,
+            Layout::from_size_align_unchecked(size + HEADER_SIZE, HEADER_SIZE),
+        )
+    }
+}
+
+unsafe extern "C" fn rust_malloc<A: GlobalAlloc + 'static>(size: usize) -> *mut c_void {
+    let Some(layout) = block_layout(size) else {
+        return ptr::null_mut();
+    };
+    unsafe { finish_block(allocator::<A>().alloc(layout), size) }
+}
+
+unsafe extern "C" fn rust_calloc<A: GlobalAlloc + 'static>(
+    nmemb: usize,
+    size: usize,
+) -> *mut c_void {
+    let Some(size) = nmemb.checked_mul(size) else {
+        return ptr::null_mut();
+    };
+    let Some(layout) = block_layout(size) else {
+        return ptr::null_mut();
+    };
+    unsafe { finish_block(allocator::<A>().alloc_zeroed(layout), size) }
+}
+
+unsafe extern "C" fn rust_realloc<A: GlobalAlloc + 'static>(
+    mem: *mut c_void,
+    size: usize,
+) -> *mut c_void {
+    if mem.is_null() {
+        return unsafe { rust_malloc::<A>(size) };
+    }
+    let Some(new_layout) = block_layout(size) else {
+        return ptr::null_mut();
+    };
+    unsafe {
+        let (block, layout) = block_of(mem);
+        finish_block(
+            allocator::<A>().realloc(block, layout, new_layout.size()),
+            size,
+        )
+    }
+}
+
+unsafe extern "C" fn rust_free<A: GlobalAlloc + 'static>(mem: *mut c_void) {
+    if !mem.is_null() {
+        unsafe {
+            let (block, layout) = block_of(mem);
+            allocator::<A>().dealloc(block, layout);
+        }
+    }
+}
@@ -1965,6 +1965,954 @@ impl SDL_Event {
     pub const fn event_type(&self) -> SDL_EventType {
         SDL_EventType(unsafe { self.r#type })
     }
+
+    /// Get the data shared by all events
+    #[inline(always)]
+    pub const fn as_common(&self) -> &SDL_CommonEvent {
+        unsafe { &self.common }
+    }
+
+    /// Get the [`SDL_DisplayEvent`] data of this event, if the event type is [`DISPLAY_FIRST`](SDL_EventType::DISPLAY_FIRST)..=[`DISPLAY_LAST`](SDL_EventType::DISPLAY_LAST)
+    #[inline]
+    pub const fn as_display(&self) -> Option<&SDL_DisplayEvent> {
+        let t = self.event_type().0;
+        if t >= SDL_EventType::DISPLAY_FIRST.0 && t <= SDL_EventType::DISPLAY_LAST.0 {
+            Some(unsafe { &self.display })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_WindowEvent`] data of this event, if the event type is [`WINDOW_FIRST`](SDL_EventType::WINDOW_FIRST)..=[`WINDOW_LAST`](SDL_EventType::WINDOW_LAST)
+    #[inline]
+    pub const fn as_window(&self) -> Option<&SDL_WindowEvent> {
+        let t = self.event_type().0;
+        if t >= SDL_EventType::WINDOW_FIRST.0 && t <= SDL_EventType::WINDOW_LAST.0 {
+            Some(unsafe { &self.window })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_KeyboardDeviceEvent`] data of this event, if the event type is [`KEYBOARD_ADDED`](SDL_EventType::KEYBOARD_ADDED) or [`KEYBOARD_REMOVED`](SDL_EventType::KEYBOARD_REMOVED)
+    #[inline]
+    pub const fn as_keyboard_device(&self) -> Option<&SDL_KeyboardDeviceEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::KEYBOARD_ADDED.0 || t == SDL_EventType::KEYBOARD_REMOVED.0 {
+            Some(unsafe { &self.kdevice })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_KeyboardEvent`] data of this event, if the event type is [`KEY_DOWN`](SDL_EventType::KEY_DOWN) or [`KEY_UP`](SDL_EventType::KEY_UP)
+    #[inline]
+    pub const fn as_keyboard(&self) -> Option<&SDL_KeyboardEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::KEY_DOWN.0 || t == SDL_EventType::KEY_UP.0 {
+            Some(unsafe { &self.key })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_TextEditingEvent`] data of this event, if the event type is [`TEXT_EDITING`](SDL_EventType::TEXT_EDITING)
+    #[inline]
+    pub const fn as_text_editing(&self) -> Option<&SDL_TextEditingEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::TEXT_EDITING.0 {
+            Some(unsafe { &self.edit })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_TextEditingCandidatesEvent`] data of this event, if the event type is [`TEXT_EDITING_CANDIDATES`](SDL_EventType::TEXT_EDITING_CANDIDATES)
+    #[inline]
+    pub const fn as_text_editing_candidates(&self) -> Option<&SDL_TextEditingCandidatesEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::TEXT_EDITING_CANDIDATES.0 {
+            Some(unsafe { &self.edit_candidates })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_TextInputEvent`] data of this event, if the event type is [`TEXT_INPUT`](SDL_EventType::TEXT_INPUT)
+    #[inline]
+    pub const fn as_text_input(&self) -> Option<&SDL_TextInputEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::TEXT_INPUT.0 {
+            Some(unsafe { &self.text })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_MouseDeviceEvent`] data of this event, if the event type is [`MOUSE_ADDED`](SDL_EventType::MOUSE_ADDED) or [`MOUSE_REMOVED`](SDL_EventType::MOUSE_REMOVED)
+    #[inline]
+    pub const fn as_mouse_device(&self) -> Option<&SDL_MouseDeviceEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::MOUSE_ADDED.0 || t == SDL_EventType::MOUSE_REMOVED.0 {
+            Some(unsafe { &self.mdevice })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_MouseMotionEvent`] data of this event, if the event type is [`MOUSE_MOTION`](SDL_EventType::MOUSE_MOTION)
+    #[inline]
+    pub const fn as_mouse_motion(&self) -> Option<&SDL_MouseMotionEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::MOUSE_MOTION.0 {
+            Some(unsafe { &self.motion })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_MouseButtonEvent`] data of this event, if the event type is [`MOUSE_BUTTON_DOWN`](SDL_EventType::MOUSE_BUTTON_DOWN) or [`MOUSE_BUTTON_UP`](SDL_EventType::MOUSE_BUTTON_UP)
+    #[inline]
+    pub const fn as_mouse_button(&self) -> Option<&SDL_MouseButtonEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::MOUSE_BUTTON_DOWN.0 || t == SDL_EventType::MOUSE_BUTTON_UP.0 {
+            Some(unsafe { &self.button })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_MouseWheelEvent`] data of this event, if the event type is [`MOUSE_WHEEL`](SDL_EventType::MOUSE_WHEEL)
+    #[inline]
+    pub const fn as_mouse_wheel(&self) -> Option<&SDL_MouseWheelEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::MOUSE_WHEEL.0 {
+            Some(unsafe { &self.wheel })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_JoyDeviceEvent`] data of this event, if the event type is [`JOYSTICK_ADDED`](SDL_EventType::JOYSTICK_ADDED), [`JOYSTICK_REMOVED`](SDL_EventType::JOYSTICK_REMOVED) or [`JOYSTICK_UPDATE_COMPLETE`](SDL_EventType::JOYSTICK_UPDATE_COMPLETE)
+    #[inline]
+    pub const fn as_joy_device(&self) -> Option<&SDL_JoyDeviceEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::JOYSTICK_ADDED.0
+            || t == SDL_EventType::JOYSTICK_REMOVED.0
+            || t == SDL_EventType::JOYSTICK_UPDATE_COMPLETE.0
+        {
+            Some(unsafe { &self.jdevice })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_JoyAxisEvent`] data of this event, if the event type is [`JOYSTICK_AXIS_MOTION`](SDL_EventType::JOYSTICK_AXIS_MOTION)
+    #[inline]
+    pub const fn as_joy_axis(&self) -> Option<&SDL_JoyAxisEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::JOYSTICK_AXIS_MOTION.0 {
+            Some(unsafe { &self.jaxis })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_JoyBallEvent`] data of this event, if the event type is [`JOYSTICK_BALL_MOTION`](SDL_EventType::JOYSTICK_BALL_MOTION)
+    #[inline]
+    pub const fn as_joy_ball(&self) -> Option<&SDL_JoyBallEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::JOYSTICK_BALL_MOTION.0 {
+            Some(unsafe { &self.jball })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_JoyHatEvent`] data of this event, if the event type is [`JOYSTICK_HAT_MOTION`](SDL_EventType::JOYSTICK_HAT_MOTION)
+    #[inline]
+    pub const fn as_joy_hat(&self) -> Option<&SDL_JoyHatEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::JOYSTICK_HAT_MOTION.0 {
+            Some(unsafe { &self.jhat })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_JoyButtonEvent`] data of this event, if the event type is [`JOYSTICK_BUTTON_DOWN`](SDL_EventType::JOYSTICK_BUTTON_DOWN) or [`JOYSTICK_BUTTON_UP`](SDL_EventType::JOYSTICK_BUTTON_UP)
+    #[inline]
+    pub const fn as_joy_button(&self) -> Option<&SDL_JoyButtonEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::JOYSTICK_BUTTON_DOWN.0 || t == SDL_EventType::JOYSTICK_BUTTON_UP.0 {
+            Some(unsafe { &self.jbutton })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_JoyBatteryEvent`] data of this event, if the event type is [`JOYSTICK_BATTERY_UPDATED`](SDL_EventType::JOYSTICK_BATTERY_UPDATED)
+    #[inline]
+    pub const fn as_joy_battery(&self) -> Option<&SDL_JoyBatteryEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::JOYSTICK_BATTERY_UPDATED.0 {
+            Some(unsafe { &self.jbattery })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_GamepadDeviceEvent`] data of this event, if the event type is [`GAMEPAD_ADDED`](SDL_EventType::GAMEPAD_ADDED), [`GAMEPAD_REMOVED`](SDL_EventType::GAMEPAD_REMOVED), [`GAMEPAD_REMAPPED`](SDL_EventType::GAMEPAD_REMAPPED), [`GAMEPAD_UPDATE_COMPLETE`](SDL_EventType::GAMEPAD_UPDATE_COMPLETE) or [`GAMEPAD_STEAM_HANDLE_UPDATED`](SDL_EventType::GAMEPAD_STEAM_HANDLE_UPDATED)
+    #[inline]
+    pub const fn as_gamepad_device(&self) -> Option<&SDL_GamepadDeviceEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::GAMEPAD_ADDED.0
+            || t == SDL_EventType::GAMEPAD_REMOVED.0
+            || t == SDL_EventType::GAMEPAD_REMAPPED.0
+            || t == SDL_EventType::GAMEPAD_UPDATE_COMPLETE.0
+            || t == SDL_EventType::GAMEPAD_STEAM_HANDLE_UPDATED.0
+        {
+            Some(unsafe { &self.gdevice })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_GamepadAxisEvent`] data of this event, if the event type is [`GAMEPAD_AXIS_MOTION`](SDL_EventType::GAMEPAD_AXIS_MOTION)
+    #[inline]
+    pub const fn as_gamepad_axis(&self) -> Option<&SDL_GamepadAxisEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::GAMEPAD_AXIS_MOTION.0 {
+            Some(unsafe { &self.gaxis })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_GamepadButtonEvent`] data of this event, if the event type is [`GAMEPAD_BUTTON_DOWN`](SDL_EventType::GAMEPAD_BUTTON_DOWN) or [`GAMEPAD_BUTTON_UP`](SDL_EventType::GAMEPAD_BUTTON_UP)
+    #[inline]
+    pub const fn as_gamepad_button(&self) -> Option<&SDL_GamepadButtonEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::GAMEPAD_BUTTON_DOWN.0 || t == SDL_EventType::GAMEPAD_BUTTON_UP.0 {
+            Some(unsafe { &self.gbutton })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_GamepadTouchpadEvent`] data of this event, if the event type is [`GAMEPAD_TOUCHPAD_DOWN`](SDL_EventType::GAMEPAD_TOUCHPAD_DOWN), [`GAMEPAD_TOUCHPAD_MOTION`](SDL_EventType::GAMEPAD_TOUCHPAD_MOTION) or [`GAMEPAD_TOUCHPAD_UP`](SDL_EventType::GAMEPAD_TOUCHPAD_UP)
+    #[inline]
+    pub const fn as_gamepad_touchpad(&self) -> Option<&SDL_GamepadTouchpadEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::GAMEPAD_TOUCHPAD_DOWN.0
+            || t == SDL_EventType::GAMEPAD_TOUCHPAD_MOTION.0
+            || t == SDL_EventType::GAMEPAD_TOUCHPAD_UP.0
+        {
+            Some(unsafe { &self.gtouchpad })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_GamepadSensorEvent`] data of this event, if the event type is [`GAMEPAD_SENSOR_UPDATE`](SDL_EventType::GAMEPAD_SENSOR_UPDATE)
+    #[inline]
+    pub const fn as_gamepad_sensor(&self) -> Option<&SDL_GamepadSensorEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::GAMEPAD_SENSOR_UPDATE.0 {
+            Some(unsafe { &self.gsensor })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_AudioDeviceEvent`] data of this event, if the event type is [`AUDIO_DEVICE_ADDED`](SDL_EventType::AUDIO_DEVICE_ADDED), [`AUDIO_DEVICE_REMOVED`](SDL_EventType::AUDIO_DEVICE_REMOVED) or [`AUDIO_DEVICE_FORMAT_CHANGED`](SDL_EventType::AUDIO_DEVICE_FORMAT_CHANGED)
+    #[inline]
+    pub const fn as_audio_device(&self) -> Option<&SDL_AudioDeviceEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::AUDIO_DEVICE_ADDED.0
+            || t == SDL_EventType::AUDIO_DEVICE_REMOVED.0
+            || t == SDL_EventType::AUDIO_DEVICE_FORMAT_CHANGED.0
+        {
+            Some(unsafe { &self.adevice })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_CameraDeviceEvent`] data of this event, if the event type is [`CAMERA_DEVICE_ADDED`](SDL_EventType::CAMERA_DEVICE_ADDED), [`CAMERA_DEVICE_REMOVED`](SDL_EventType::CAMERA_DEVICE_REMOVED), [`CAMERA_DEVICE_APPROVED`](SDL_EventType::CAMERA_DEVICE_APPROVED) or [`CAMERA_DEVICE_DENIED`](SDL_EventType::CAMERA_DEVICE_DENIED)
+    #[inline]
+    pub const fn as_camera_device(&self) -> Option<&SDL_CameraDeviceEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::CAMERA_DEVICE_ADDED.0
+            || t == SDL_EventType::CAMERA_DEVICE_REMOVED.0
+            || t == SDL_EventType::CAMERA_DEVICE_APPROVED.0
+            || t == SDL_EventType::CAMERA_DEVICE_DENIED.0
+        {
+            Some(unsafe { &self.cdevice })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_SensorEvent`] data of this event, if the event type is [`SENSOR_UPDATE`](SDL_EventType::SENSOR_UPDATE)
+    #[inline]
+    pub const fn as_sensor(&self) -> Option<&SDL_SensorEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::SENSOR_UPDATE.0 {
+            Some(unsafe { &self.sensor })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_QuitEvent`] data of this event, if the event type is [`QUIT`](SDL_EventType::QUIT)
+    #[inline]
+    pub const fn as_quit(&self) -> Option<&SDL_QuitEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::QUIT.0 {
+            Some(unsafe { &self.quit })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_UserEvent`] data of this event, if the event type is [`USER`](SDL_EventType::USER)..=[`LAST`](SDL_EventType::LAST)
+    #[inline]
+    pub const fn as_user(&self) -> Option<&SDL_UserEvent> {
+        let t = self.event_type().0;
+        if t >= SDL_EventType::USER.0 && t <= SDL_EventType::LAST.0 {
+            Some(unsafe { &self.user })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_TouchFingerEvent`] data of this event, if the event type is [`FINGER_DOWN`](SDL_EventType::FINGER_DOWN), [`FINGER_UP`](SDL_EventType::FINGER_UP), [`FINGER_MOTION`](SDL_EventType::FINGER_MOTION) or [`FINGER_CANCELED`](SDL_EventType::FINGER_CANCELED)
+    #[inline]
+    pub const fn as_touch_finger(&self) -> Option<&SDL_TouchFingerEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::FINGER_DOWN.0
+            || t == SDL_EventType::FINGER_UP.0
+            || t == SDL_EventType::FINGER_MOTION.0
+            || t == SDL_EventType::FINGER_CANCELED.0
+        {
+            Some(unsafe { &self.tfinger })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_PinchFingerEvent`] data of this event, if the event type is [`PINCH_BEGIN`](SDL_EventType::PINCH_BEGIN), [`PINCH_UPDATE`](SDL_EventType::PINCH_UPDATE) or [`PINCH_END`](SDL_EventType::PINCH_END)
+    #[inline]
+    pub const fn as_pinch_finger(&self) -> Option<&SDL_PinchFingerEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::PINCH_BEGIN.0
+            || t == SDL_EventType::PINCH_UPDATE.0
+            || t == SDL_EventType::PINCH_END.0
+        {
+            Some(unsafe { &self.pinch })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_PenProximityEvent`] data of this event, if the event type is [`PEN_PROXIMITY_IN`](SDL_EventType::PEN_PROXIMITY_IN) or [`PEN_PROXIMITY_OUT`](SDL_EventType::PEN_PROXIMITY_OUT)
+    #[inline]
+    pub const fn as_pen_proximity(&self) -> Option<&SDL_PenProximityEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::PEN_PROXIMITY_IN.0 || t == SDL_EventType::PEN_PROXIMITY_OUT.0 {
+            Some(unsafe { &self.pproximity })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_PenTouchEvent`] data of this event, if the event type is [`PEN_DOWN`](SDL_EventType::PEN_DOWN) or [`PEN_UP`](SDL_EventType::PEN_UP)
+    #[inline]
+    pub const fn as_pen_touch(&self) -> Option<&SDL_PenTouchEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::PEN_DOWN.0 || t == SDL_EventType::PEN_UP.0 {
+            Some(unsafe { &self.ptouch })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_PenMotionEvent`] data of this event, if the event type is [`PEN_MOTION`](SDL_EventType::PEN_MOTION)
+    #[inline]
+    pub const fn as_pen_motion(&self) -> Option<&SDL_PenMotionEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::PEN_MOTION.0 {
+            Some(unsafe { &self.pmotion })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_PenButtonEvent`] data of this event, if the event type is [`PEN_BUTTON_DOWN`](SDL_EventType::PEN_BUTTON_DOWN) or [`PEN_BUTTON_UP`](SDL_EventType::PEN_BUTTON_UP)
+    #[inline]
+    pub const fn as_pen_button(&self) -> Option<&SDL_PenButtonEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::PEN_BUTTON_DOWN.0 || t == SDL_EventType::PEN_BUTTON_UP.0 {
+            Some(unsafe { &self.pbutton })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_PenAxisEvent`] data of this event, if the event type is [`PEN_AXIS`](SDL_EventType::PEN_AXIS)
+    #[inline]
+    pub const fn as_pen_axis(&self) -> Option<&SDL_PenAxisEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::PEN_AXIS.0 {
+            Some(unsafe { &self.paxis })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_RenderEvent`] data of this event, if the event type is [`RENDER_TARGETS_RESET`](SDL_EventType::RENDER_TARGETS_RESET), [`RENDER_DEVICE_RESET`](SDL_EventType::RENDER_DEVICE_RESET) or [`RENDER_DEVICE_LOST`](SDL_EventType::RENDER_DEVICE_LOST)
+    #[inline]
+    pub const fn as_render(&self) -> Option<&SDL_RenderEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::RENDER_TARGETS_RESET.0
+            || t == SDL_EventType::RENDER_DEVICE_RESET.0
+            || t == SDL_EventType::RENDER_DEVICE_LOST.0
+        {
+            Some(unsafe { &self.render })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_DropEvent`] data of this event, if the event type is [`DROP_BEGIN`](SDL_EventType::DROP_BEGIN), [`DROP_FILE`](SDL_EventType::DROP_FILE), [`DROP_TEXT`](SDL_EventType::DROP_TEXT), [`DROP_COMPLETE`](SDL_EventType::DROP_COMPLETE) or [`DROP_POSITION`](SDL_EventType::DROP_POSITION)
+    #[inline]
+    pub const fn as_drop(&self) -> Option<&SDL_DropEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::DROP_BEGIN.0
+            || t == SDL_EventType::DROP_FILE.0
+            || t == SDL_EventType::DROP_TEXT.0
+            || t == SDL_EventType::DROP_COMPLETE.0
+            || t == SDL_EventType::DROP_POSITION.0
+        {
+            Some(unsafe { &self.drop })
+        } else {
+            None
+        }
+    }
+
+    /// Get the [`SDL_ClipboardEvent`] data of this event, if the event type is [`CLIPBOARD_UPDATE`](SDL_EventType::CLIPBOARD_UPDATE)
+    #[inline]
+    pub const fn as_clipboard(&self) -> Option<&SDL_ClipboardEvent> {
+        let t = self.event_type().0;
+        if t == SDL_EventType::CLIPBOARD_UPDATE.0 {
+            Some(unsafe { &self.clipboard })
+        } else {
+            None
+        }
+    }
+}
+
+/// The event type must be set to [`DISPLAY_FIRST`](SDL_EventType::DISPLAY_FIRST)..=[`DISPLAY_LAST`](SDL_EventType::DISPLAY_LAST). This is checked with a debug assertion
+impl From<SDL_DisplayEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_DisplayEvent) -> Self {
+        let mut event = Self::default();
+        event.display = value;
+        debug_assert!(
+            event.as_display().is_some(),
+            "invalid event type for SDL_DisplayEvent"
+        );
+        event
+    }
+}
+
+/// The event type must be set to [`WINDOW_FIRST`](SDL_EventType::WINDOW_FIRST)..=[`WINDOW_LAST`](SDL_EventType::WINDOW_LAST). This is checked with a debug assertion
+impl From<SDL_WindowEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_WindowEvent) -> Self {
+        let mut event = Self::default();
+        event.window = value;
+        debug_assert!(
+            event.as_window().is_some(),
+            "invalid event type for SDL_WindowEvent"
+        );
+        event
+    }
+}
+
+/// The event type must be set to [`KEYBOARD_ADDED`](SDL_EventType::KEYBOARD_ADDED) or [`KEYBOARD_REMOVED`](SDL_EventType::KEYBOARD_REMOVED). This is checked with a debug assertion
+impl From<SDL_KeyboardDeviceEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_KeyboardDeviceEvent) -> Self {
+        let mut event = Self::default();
+        event.kdevice = value;
+        debug_assert!(
+            event.as_keyboard_device().is_some(),
+            "invalid event type for SDL_KeyboardDeviceEvent"
+        );
+        event
+    }
+}
+
+/// The event type must be set to [`KEY_DOWN`](SDL_EventType::KEY_DOWN) or [`KEY_UP`](SDL_EventType::KEY_UP). This is checked with a debug assertion
+impl From<SDL_KeyboardEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_KeyboardEvent) -> Self {
+        let mut event = Self::default();
+        event.key = value;
+        debug_assert!(
+            event.as_keyboard().is_some(),
+            "invalid event type for SDL_KeyboardEvent"
+        );
+        event
+    }
+}
+
+/// Sets the event type to [`TEXT_EDITING`](SDL_EventType::TEXT_EDITING) if it's [`FIRST`](SDL_EventType::FIRST) (zero)
+impl From<SDL_TextEditingEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_TextEditingEvent) -> Self {
+        let mut event = Self::default();
+        event.edit = value;
+        if value.r#type == SDL_EventType::FIRST {
+            event.edit.r#type = SDL_EventType::TEXT_EDITING;
+        }
+        event
+    }
+}
+
+/// Sets the event type to [`TEXT_EDITING_CANDIDATES`](SDL_EventType::TEXT_EDITING_CANDIDATES) if it's [`FIRST`](SDL_EventType::FIRST) (zero)
+impl From<SDL_TextEditingCandidatesEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_TextEditingCandidatesEvent) -> Self {
+        let mut event = Self::default();
+        event.edit_candidates = value;
+        if value.r#type == SDL_EventType::FIRST {
+            event.edit_candidates.r#type = SDL_EventType::TEXT_EDITING_CANDIDATES;
+        }
+        event
+    }
+}
+
+/// Sets the event type to [`TEXT_INPUT`](SDL_EventType::TEXT_INPUT) if it's [`FIRST`](SDL_EventType::FIRST) (zero)
+impl From<SDL_TextInputEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_TextInputEvent) -> Self {
+        let mut event = Self::default();
+        event.text = value;
+        if value.r#type == SDL_EventType::FIRST {
+            event.text.r#type = SDL_EventType::TEXT_INPUT;
+        }
+        event
+    }
+}
+
+/// The event type must be set to [`MOUSE_ADDED`](SDL_EventType::MOUSE_ADDED) or [`MOUSE_REMOVED`](SDL_EventType::MOUSE_REMOVED). This is checked with a debug assertion
+impl From<SDL_MouseDeviceEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_MouseDeviceEvent) -> Self {
+        let mut event = Self::default();
+        event.mdevice = value;
+        debug_assert!(
+            event.as_mouse_device().is_some(),
+            "invalid event type for SDL_MouseDeviceEvent"
+        );
+        event
+    }
+}
+
+/// Sets the event type to [`MOUSE_MOTION`](SDL_EventType::MOUSE_MOTION) if it's [`FIRST`](SDL_EventType::FIRST) (zero)
+impl From<SDL_MouseMotionEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_MouseMotionEvent) -> Self {
+        let mut event = Self::default();
+        event.motion = value;
+        if value.r#type == SDL_EventType::FIRST {
+            event.motion.r#type = SDL_EventType::MOUSE_MOTION;
+        }
+        event
+    }
+}
+
+/// The event type must be set to [`MOUSE_BUTTON_DOWN`](SDL_EventType::MOUSE_BUTTON_DOWN) or [`MOUSE_BUTTON_UP`](SDL_EventType::MOUSE_BUTTON_UP). This is checked with a debug assertion
+impl From<SDL_MouseButtonEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_MouseButtonEvent) -> Self {
+        let mut event = Self::default();
+        event.button = value;
+        debug_assert!(
+            event.as_mouse_button().is_some(),
+            "invalid event type for SDL_MouseButtonEvent"
+        );
+        event
+    }
+}
+
+/// Sets the event type to [`MOUSE_WHEEL`](SDL_EventType::MOUSE_WHEEL) if it's [`FIRST`](SDL_EventType::FIRST) (zero)
+impl From<SDL_MouseWheelEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_MouseWheelEvent) -> Self {
+        let mut event = Self::default();
+        event.wheel = value;
+        if value.r#type == SDL_EventType::FIRST {
+            event.wheel.r#type = SDL_EventType::MOUSE_WHEEL;
+        }
+        event
+    }
+}
+
+/// The event type must be set to [`JOYSTICK_ADDED`](SDL_EventType::JOYSTICK_ADDED), [`JOYSTICK_REMOVED`](SDL_EventType::JOYSTICK_REMOVED) or [`JOYSTICK_UPDATE_COMPLETE`](SDL_EventType::JOYSTICK_UPDATE_COMPLETE). This is checked with a debug assertion
+impl From<SDL_JoyDeviceEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_JoyDeviceEvent) -> Self {
+        let mut event = Self::default();
+        event.jdevice = value;
+        debug_assert!(
+            event.as_joy_device().is_some(),
+            "invalid event type for SDL_JoyDeviceEvent"
+        );
+        event
+    }
+}
+
+/// Sets the event type to [`JOYSTICK_AXIS_MOTION`](SDL_EventType::JOYSTICK_AXIS_MOTION) if it's [`FIRST`](SDL_EventType::FIRST) (zero)
+impl From<SDL_JoyAxisEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_JoyAxisEvent) -> Self {
+        let mut event = Self::default();
+        event.jaxis = value;
+        if value.r#type == SDL_EventType::FIRST {
+            event.jaxis.r#type = SDL_EventType::JOYSTICK_AXIS_MOTION;
+        }
+        event
+    }
+}
+
+/// Sets the event type to [`JOYSTICK_BALL_MOTION`](SDL_EventType::JOYSTICK_BALL_MOTION) if it's [`FIRST`](SDL_EventType::FIRST) (zero)
+impl From<SDL_JoyBallEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_JoyBallEvent) -> Self {
+        let mut event = Self::default();
+        event.jball = value;
+        if value.r#type == SDL_EventType::FIRST {
+            event.jball.r#type = SDL_EventType::JOYSTICK_BALL_MOTION;
+        }
+        event
+    }
+}
+
+/// Sets the event type to [`JOYSTICK_HAT_MOTION`](SDL_EventType::JOYSTICK_HAT_MOTION) if it's [`FIRST`](SDL_EventType::FIRST) (zero)
+impl From<SDL_JoyHatEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_JoyHatEvent) -> Self {
+        let mut event = Self::default();
+        event.jhat = value;
+        if value.r#type == SDL_EventType::FIRST {
+            event.jhat.r#type = SDL_EventType::JOYSTICK_HAT_MOTION;
+        }
+        event
+    }
+}
+
+/// The event type must be set to [`JOYSTICK_BUTTON_DOWN`](SDL_EventType::JOYSTICK_BUTTON_DOWN) or [`JOYSTICK_BUTTON_UP`](SDL_EventType::JOYSTICK_BUTTON_UP). This is checked with a debug assertion
+impl From<SDL_JoyButtonEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_JoyButtonEvent) -> Self {
+        let mut event = Self::default();
+        event.jbutton = value;
+        debug_assert!(
+            event.as_joy_button().is_some(),
+            "invalid event type for SDL_JoyButtonEvent"
+        );
+        event
+    }
+}
+
+/// Sets the event type to [`JOYSTICK_BATTERY_UPDATED`](SDL_EventType::JOYSTICK_BATTERY_UPDATED) if it's [`FIRST`](SDL_EventType::FIRST) (zero)
+impl From<SDL_JoyBatteryEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_JoyBatteryEvent) -> Self {
+        let mut event = Self::default();
+        event.jbattery = value;
+        if value.r#type == SDL_EventType::FIRST {
+            event.jbattery.r#type = SDL_EventType::JOYSTICK_BATTERY_UPDATED;
+        }
+        event
+    }
+}
+
+/// The event type must be set to [`GAMEPAD_ADDED`](SDL_EventType::GAMEPAD_ADDED), [`GAMEPAD_REMOVED`](SDL_EventType::GAMEPAD_REMOVED), [`GAMEPAD_REMAPPED`](SDL_EventType::GAMEPAD_REMAPPED), [`GAMEPAD_UPDATE_COMPLETE`](SDL_EventType::GAMEPAD_UPDATE_COMPLETE) or [`GAMEPAD_STEAM_HANDLE_UPDATED`](SDL_EventType::GAMEPAD_STEAM_HANDLE_UPDATED). This is checked with a debug assertion
+impl From<SDL_GamepadDeviceEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_GamepadDeviceEvent) -> Self {
+        let mut event = Self::default();
+        event.gdevice = value;
+        debug_assert!(
+            event.as_gamepad_device().is_some(),
+            "invalid event type for SDL_GamepadDeviceEvent"
+        );
+        event
+    }
+}
+
+/// Sets the event type to [`GAMEPAD_AXIS_MOTION`](SDL_EventType::GAMEPAD_AXIS_MOTION) if it's [`FIRST`](SDL_EventType::FIRST) (zero)
+impl From<SDL_GamepadAxisEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_GamepadAxisEvent) -> Self {
+        let mut event = Self::default();
+        event.gaxis = value;
+        if value.r#type == SDL_EventType::FIRST {
+            event.gaxis.r#type = SDL_EventType::GAMEPAD_AXIS_MOTION;
+        }
+        event
+    }
+}
+
+/// The event type must be set to [`GAMEPAD_BUTTON_DOWN`](SDL_EventType::GAMEPAD_BUTTON_DOWN) or [`GAMEPAD_BUTTON_UP`](SDL_EventType::GAMEPAD_BUTTON_UP). This is checked with a debug assertion
+impl From<SDL_GamepadButtonEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_GamepadButtonEvent) -> Self {
+        let mut event = Self::default();
+        event.gbutton = value;
+        debug_assert!(
+            event.as_gamepad_button().is_some(),
+            "invalid event type for SDL_GamepadButtonEvent"
+        );
+        event
+    }
+}
+
+/// The event type must be set to [`GAMEPAD_TOUCHPAD_DOWN`](SDL_EventType::GAMEPAD_TOUCHPAD_DOWN), [`GAMEPAD_TOUCHPAD_MOTION`](SDL_EventType::GAMEPAD_TOUCHPAD_MOTION) or [`GAMEPAD_TOUCHPAD_UP`](SDL_EventType::GAMEPAD_TOUCHPAD_UP). This is checked with a debug assertion
+impl From<SDL_GamepadTouchpadEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_GamepadTouchpadEvent) -> Self {
+        let mut event = Self::default();
+        event.gtouchpad = value;
+        debug_assert!(
+            event.as_gamepad_touchpad().is_some(),
+            "invalid event type for SDL_GamepadTouchpadEvent"
+        );
+        event
+    }
+}
+
+/// Sets the event type to [`GAMEPAD_SENSOR_UPDATE`](SDL_EventType::GAMEPAD_SENSOR_UPDATE) if it's [`FIRST`](SDL_EventType::FIRST) (zero)
+impl From<SDL_GamepadSensorEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_GamepadSensorEvent) -> Self {
+        let mut event = Self::default();
+        event.gsensor = value;
+        if value.r#type == SDL_EventType::FIRST {
+            event.gsensor.r#type = SDL_EventType::GAMEPAD_SENSOR_UPDATE;
+        }
+        event
+    }
+}
+
+/// The event type must be set to [`AUDIO_DEVICE_ADDED`](SDL_EventType::AUDIO_DEVICE_ADDED), [`AUDIO_DEVICE_REMOVED`](SDL_EventType::AUDIO_DEVICE_REMOVED) or [`AUDIO_DEVICE_FORMAT_CHANGED`](SDL_EventType::AUDIO_DEVICE_FORMAT_CHANGED). This is checked with a debug assertion
+impl From<SDL_AudioDeviceEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_AudioDeviceEvent) -> Self {
+        let mut event = Self::default();
+        event.adevice = value;
+        debug_assert!(
+            event.as_audio_device().is_some(),
+            "invalid event type for SDL_AudioDeviceEvent"
+        );
+        event
+    }
+}
+
+/// The event type must be set to [`CAMERA_DEVICE_ADDED`](SDL_EventType::CAMERA_DEVICE_ADDED), [`CAMERA_DEVICE_REMOVED`](SDL_EventType::CAMERA_DEVICE_REMOVED), [`CAMERA_DEVICE_APPROVED`](SDL_EventType::CAMERA_DEVICE_APPROVED) or [`CAMERA_DEVICE_DENIED`](SDL_EventType::CAMERA_DEVICE_DENIED). This is checked with a debug assertion
+impl From<SDL_CameraDeviceEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_CameraDeviceEvent) -> Self {
+        let mut event = Self::default();
+        event.cdevice = value;
+        debug_assert!(
+            event.as_camera_device().is_some(),
+            "invalid event type for SDL_CameraDeviceEvent"
+        );
+        event
+    }
+}
+
+/// Sets the event type to [`SENSOR_UPDATE`](SDL_EventType::SENSOR_UPDATE) if it's [`FIRST`](SDL_EventType::FIRST) (zero)
+impl From<SDL_SensorEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_SensorEvent) -> Self {
+        let mut event = Self::default();
+        event.sensor = value;
+        if value.r#type == SDL_EventType::FIRST {
+            event.sensor.r#type = SDL_EventType::SENSOR_UPDATE;
+        }
+        event
+    }
+}
+
+/// Sets the event type to [`QUIT`](SDL_EventType::QUIT) if it's [`FIRST`](SDL_EventType::FIRST) (zero)
+impl From<SDL_QuitEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_QuitEvent) -> Self {
+        let mut event = Self::default();
+        event.quit = value;
+        if value.r#type == SDL_EventType::FIRST {
+            event.quit.r#type = SDL_EventType::QUIT;
+        }
+        event
+    }
+}
+
+/// The event type must be set to [`USER`](SDL_EventType::USER)..=[`LAST`](SDL_EventType::LAST). This is checked with a debug assertion
+impl From<SDL_UserEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_UserEvent) -> Self {
+        let mut event = Self::default();
+        event.user = value;
+        debug_assert!(
+            event.as_user().is_some(),
+            "invalid event type for SDL_UserEvent"
+        );
+        event
+    }
+}
+
+/// The event type must be set to [`FINGER_DOWN`](SDL_EventType::FINGER_DOWN), [`FINGER_UP`](SDL_EventType::FINGER_UP), [`FINGER_MOTION`](SDL_EventType::FINGER_MOTION) or [`FINGER_CANCELED`](SDL_EventType::FINGER_CANCELED). This is checked with a debug assertion
+impl From<SDL_TouchFingerEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_TouchFingerEvent) -> Self {
+        let mut event = Self::default();
+        event.tfinger = value;
+        debug_assert!(
+            event.as_touch_finger().is_some(),
+            "invalid event type for SDL_TouchFingerEvent"
+        );
+        event
+    }
+}
+
+/// The event type must be set to [`PINCH_BEGIN`](SDL_EventType::PINCH_BEGIN), [`PINCH_UPDATE`](SDL_EventType::PINCH_UPDATE) or [`PINCH_END`](SDL_EventType::PINCH_END). This is checked with a debug assertion
+impl From<SDL_PinchFingerEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_PinchFingerEvent) -> Self {
+        let mut event = Self::default();
+        event.pinch = value;
+        debug_assert!(
+            event.as_pinch_finger().is_some(),
+            "invalid event type for SDL_PinchFingerEvent"
+        );
+        event
+    }
+}
+
+/// The event type must be set to [`PEN_PROXIMITY_IN`](SDL_EventType::PEN_PROXIMITY_IN) or [`PEN_PROXIMITY_OUT`](SDL_EventType::PEN_PROXIMITY_OUT). This is checked with a debug assertion
+impl From<SDL_PenProximityEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_PenProximityEvent) -> Self {
+        let mut event = Self::default();
+        event.pproximity = value;
+        debug_assert!(
+            event.as_pen_proximity().is_some(),
+            "invalid event type for SDL_PenProximityEvent"
+        );
+        event
+    }
+}
+
+/// The event type must be set to [`PEN_DOWN`](SDL_EventType::PEN_DOWN) or [`PEN_UP`](SDL_EventType::PEN_UP). This is checked with a debug assertion
+impl From<SDL_PenTouchEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_PenTouchEvent) -> Self {
+        let mut event = Self::default();
+        event.ptouch = value;
+        debug_assert!(
+            event.as_pen_touch().is_some(),
+            "invalid event type for SDL_PenTouchEvent"
+        );
+        event
+    }
+}
+
+/// Sets the event type to [`PEN_MOTION`](SDL_EventType::PEN_MOTION) if it's [`FIRST`](SDL_EventType::FIRST) (zero)
+impl From<SDL_PenMotionEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_PenMotionEvent) -> Self {
+        let mut event = Self::default();
+        event.pmotion = value;
+        if value.r#type == SDL_EventType::FIRST {
+            event.pmotion.r#type = SDL_EventType::PEN_MOTION;
+        }
+        event
+    }
+}
+
+/// The event type must be set to [`PEN_BUTTON_DOWN`](SDL_EventType::PEN_BUTTON_DOWN) or [`PEN_BUTTON_UP`](SDL_EventType::PEN_BUTTON_UP). This is checked with a debug assertion
+impl From<SDL_PenButtonEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_PenButtonEvent) -> Self {
+        let mut event = Self::default();
+        event.pbutton = value;
+        debug_assert!(
+            event.as_pen_button().is_some(),
+            "invalid event type for SDL_PenButtonEvent"
+        );
+        event
+    }
+}
+
+/// Sets the event type to [`PEN_AXIS`](SDL_EventType::PEN_AXIS) if it's [`FIRST`](SDL_EventType::FIRST) (zero)
+impl From<SDL_PenAxisEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_PenAxisEvent) -> Self {
+        let mut event = Self::default();
+        event.paxis = value;
+        if value.r#type == SDL_EventType::FIRST {
+            event.paxis.r#type = SDL_EventType::PEN_AXIS;
+        }
+        event
+    }
+}
+
+/// The event type must be set to [`RENDER_TARGETS_RESET`](SDL_EventType::RENDER_TARGETS_RESET), [`RENDER_DEVICE_RESET`](SDL_EventType::RENDER_DEVICE_RESET) or [`RENDER_DEVICE_LOST`](SDL_EventType::RENDER_DEVICE_LOST). This is checked with a debug assertion
+impl From<SDL_RenderEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_RenderEvent) -> Self {
+        let mut event = Self::default();
+        event.render = value;
+        debug_assert!(
+            event.as_render().is_some(),
+            "invalid event type for SDL_RenderEvent"
+        );
+        event
+    }
+}
+
+/// The event type must be set to [`DROP_BEGIN`](SDL_EventType::DROP_BEGIN), [`DROP_FILE`](SDL_EventType::DROP_FILE), [`DROP_TEXT`](SDL_EventType::DROP_TEXT), [`DROP_COMPLETE`](SDL_EventType::DROP_COMPLETE) or [`DROP_POSITION`](SDL_EventType::DROP_POSITION). This is checked with a debug assertion
+impl From<SDL_DropEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_DropEvent) -> Self {
+        let mut event = Self::default();
+        event.drop = value;
+        debug_assert!(
+            event.as_drop().is_some(),
+            "invalid event type for SDL_DropEvent"
+        );
+        event
+    }
+}
+
+/// Sets the event type to [`CLIPBOARD_UPDATE`](SDL_EventType::CLIPBOARD_UPDATE) if it's [`FIRST`](SDL_EventType::FIRST) (zero)
+impl From<SDL_ClipboardEvent> for SDL_Event {
+    #[inline]
+    fn from(value: SDL_ClipboardEvent) -> Self {
+        let mut event = Self::default();
+        event.clipboard = value;
+        if value.r#type == SDL_EventType::FIRST {
+            event.clipboard.r#type = SDL_EventType::CLIPBOARD_UPDATE;
+        }
+        event
+    }
 }
 
 /// The structure for all events in SDL.
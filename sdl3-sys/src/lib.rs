@@ -116,4 +116,8 @@ pub mod ffi {
     pub enum VaList {}
 }
 
+pub mod allocator;
+
 pub mod metadata;
+
+pub mod version_check;
@@ -0,0 +1,273 @@
+//! Check that the linked library is compatible with the bindings at runtime
+//!
+//! The bindings are generated from the headers of a specific version of the library, but the
+//! library that's loaded at runtime may be older. APIs that were added after the version of the
+//! linked library will fail to link or behave unexpectedly.
+//!
+//! [`VersionReport::unavailable_apis()`] lists hints, properties, constants and structs that the
+//! linked library predates. Functions aren't listed, because the metadata doesn't record which
+//! version added them. Use [`VersionReport::is_up_to_date()`] to find out if any functions may
+//! be missing.
+
+use crate::{
+    metadata::{Group, Hint, Property, Struct},
+    version::{
+        SDL_GetRevision, SDL_GetVersion, SDL_VERSION, SDL_VERSIONNUM, SDL_VERSIONNUM_MAJOR,
+        SDL_VERSIONNUM_MICRO, SDL_VERSIONNUM_MINOR,
+    },
+};
+use core::{
+    ffi::{CStr, c_int},
+    fmt::{self, Display, Formatter},
+    iter,
+};
+
+/// Metadata for the APIs in a crate, for [`VersionReport::unavailable_apis()`]
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct Apis {
+    pub hints: &'static [&'static Hint],
+    pub properties: &'static [&'static Property],
+    pub groups: &'static [&'static Group],
+    pub structs: &'static [&'static Struct],
+}
+
+impl Apis {
+    pub const NONE: Self = Self {
+        hints: &[],
+        properties: &[],
+        groups: &[],
+        structs: &[],
+    };
+}
+
+/// Kind of an API listed by [`VersionReport::unavailable_apis()`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ApiKind {
+    Hint,
+    Property,
+    Group,
+    GroupValue,
+    Struct,
+    Field,
+}
+
+/// An API that the bindings expose, but that the linked library predates
+#[derive(Clone, Copy, Debug)]
+pub struct UnavailableApi {
+    pub kind: ApiKind,
+    pub module: &'static str,
+    /// The name of the group for group values, or the name of the struct for fields
+    pub parent: Option<&'static str>,
+    pub name: &'static str,
+    /// The version the API was added in
+    pub available_since: c_int,
+}
+
+/// Result of comparing the version of the linked library with the version of the bindings
+#[derive(Clone, Copy)]
+pub struct VersionReport {
+    /// Name of the library, e.g. `SDL` or `SDL_image`
+    pub library: &'static str,
+    /// Version of the headers the bindings were generated from
+    pub header_version: c_int,
+    /// Oldest version of the library supported by the bindings
+    pub min_version: c_int,
+    /// Version of the linked library
+    pub linked_version: c_int,
+    /// Revision of the linked library, if the library reports it
+    pub linked_revision: Option<&'static CStr>,
+    apis: Apis,
+}
+
+impl VersionReport {
+    #[doc(hidden)]
+    pub const fn new(
+        library: &'static str,
+        header_version: c_int,
+        min_version: c_int,
+        linked_version: c_int,
+        linked_revision: Option<&'static CStr>,
+        apis: Apis,
+    ) -> Self {
+        Self {
+            library,
+            header_version,
+            min_version,
+            linked_version,
+            linked_revision,
+            apis,
+        }
+    }
+
+    /// Returns true if the linked library has the same major version as the bindings,
+    /// and is at least the oldest version the bindings support
+    pub const fn is_compatible(&self) -> bool {
+        SDL_VERSIONNUM_MAJOR(self.linked_version) == SDL_VERSIONNUM_MAJOR(self.header_version)
+            && self.linked_version >= self.min_version
+    }
+
+    /// Returns true if the linked library is at least the version the bindings were
+    /// generated from, so all the APIs they expose are available
+    pub const fn is_up_to_date(&self) -> bool {
+        self.is_compatible() && self.linked_version >= self.header_version
+    }
+
+    /// List the APIs that the bindings expose, but that the linked library predates.
+    ///
+    /// This is based on the availability in the metadata, so it's always empty
+    /// if the `metadata` feature isn't enabled. Functions aren't included, as the metadata
+    /// doesn't have their availability.
+    pub fn unavailable_apis(&self) -> impl Iterator<Item = UnavailableApi> + use<> {
+        let Apis {
+            hints,
+            properties,
+            groups,
+            structs,
+        } = self.apis;
+        let linked_version = self.linked_version;
+        let hints = hints
+            .iter()
+            .map(|h| (ApiKind::Hint, h.module, None, h.name, h.available_since));
+        let properties = properties
+            .iter()
+            .map(|p| (ApiKind::Property, p.module, None, p.name, p.available_since));
+        let groups = groups.iter().flat_map(|g| {
+            iter::once((ApiKind::Group, g.module, None, g.name, g.available_since)).chain(
+                g.values.iter().map(|v| {
+                    let parent = Some(g.name);
+                    (
+                        ApiKind::GroupValue,
+                        g.module,
+                        parent,
+                        v.name,
+                        v.available_since,
+                    )
+                }),
+            )
+        });
+        let structs = structs.iter().flat_map(|s| {
+            iter::once((ApiKind::Struct, s.module, None, s.name, s.available_since)).chain(
+                s.fields.iter().map(|f| {
+                    let parent = Some(s.name);
+                    (ApiKind::Field, s.module, parent, f.name, f.available_since)
+                }),
+            )
+        });
+        hints
+            .chain(properties)
+            .chain(groups)
+            .chain(structs)
+            .filter_map(move |(kind, module, parent, name, available_since)| {
+                let available_since = available_since?;
+                (available_since > linked_version).then_some(UnavailableApi {
+                    kind,
+                    module,
+                    parent,
+                    name,
+                    available_since,
+                })
+            })
+    }
+}
+
+impl Display for VersionReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let library = self.library;
+        write!(f, "linked {library} {}", Version(self.linked_version))?;
+        if let Some(Ok(revision)) = self.linked_revision.map(CStr::to_str) {
+            if !revision.is_empty() {
+                write!(f, " ({revision})")?;
+            }
+        }
+        if !self.is_compatible() {
+            write!(
+                f,
+                " is incompatible with the bindings (requires {library} {} or later)",
+                Version(self.min_version)
+            )
+        } else if !self.is_up_to_date() {
+            write!(
+                f,
+                " is older than the bindings ({library} {}); some APIs are unavailable",
+                Version(self.header_version)
+            )
+        } else {
+            write!(
+                f,
+                " is compatible with the bindings ({library} {})",
+                Version(self.header_version)
+            )
+        }
+    }
+}
+
+struct Version(c_int);
+
+impl Display for Version {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.{}.{}",
+            SDL_VERSIONNUM_MAJOR(self.0),
+            SDL_VERSIONNUM_MINOR(self.0),
+            SDL_VERSIONNUM_MICRO(self.0)
+        )
+    }
+}
+
+/// Parse a version string like `3.2.0` into a version number
+#[doc(hidden)]
+pub const fn parse_version(version: &str) -> c_int {
+    let bytes = version.as_bytes();
+    let mut parts = [0; 3];
+    let mut part = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'.' => part += 1,
+            b @ b'0'..=b'9' => parts[part] = parts[part] * 10 + (b - b'0') as c_int,
+            _ => panic!("invalid version"),
+        }
+        i += 1;
+    }
+    SDL_VERSIONNUM(parts[0], parts[1], parts[2])
+}
+
+// Create the `VersionReport` for a library, with the minimum version and metadata of the
+// calling crate. `crate` and `env!` refer to the calling crate on purpose
+#[doc(hidden)] // for internal use only
+#[allow(clippy::crate_in_macro_def)]
+#[macro_export]
+macro_rules! __version_report {
+    ($library:literal, $header_version:expr, $linked_version:expr, $linked_revision:expr) => {{
+        const MIN_VERSION: ::core::ffi::c_int =
+            $crate::version_check::parse_version(env!("LIB_MIN_VERSION"));
+        #[cfg(feature = "metadata")]
+        const APIS: $crate::version_check::Apis = $crate::version_check::Apis {
+            hints: crate::metadata::HINTS,
+            properties: crate::metadata::PROPERTIES,
+            groups: crate::metadata::GROUPS,
+            structs: crate::metadata::STRUCTS,
+        };
+        #[cfg(not(feature = "metadata"))]
+        const APIS: $crate::version_check::Apis = $crate::version_check::Apis::NONE;
+        $crate::version_check::VersionReport::new(
+            $library,
+            $header_version,
+            MIN_VERSION,
+            $linked_version,
+            $linked_revision,
+            APIS,
+        )
+    }};
+}
+
+/// Compare the version of the linked SDL library with the version of the bindings.
+///
+/// This calls [`SDL_GetVersion()`] and [`SDL_GetRevision()`], so SDL must be linked.
+pub fn check_compatible() -> VersionReport {
+    let revision = SDL_GetRevision();
+    let revision = (!revision.is_null()).then(|| unsafe { CStr::from_ptr(revision) });
+    crate::__version_report!("SDL", SDL_VERSION, SDL_GetVersion(), revision)
+}
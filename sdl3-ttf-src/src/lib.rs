@@ -24,3 +24,73 @@ pub const REVISION_OFFSET: &str = "0";
 
 /// Hash part of the revision
 pub const REVISION_HASH: &str = "ga1ce367";
+
+/// License of a library in the source tree
+#[derive(Clone, Copy, Debug)]
+pub struct License {
+    /// Name of the library
+    pub name: &'static str,
+    /// SPDX license expression
+    pub spdx: &'static str,
+    /// License files, relative to [`SOURCE_DIR`]
+    pub files: &'static [&'static str],
+}
+
+/// A library vendored in the source tree
+#[derive(Clone, Copy, Debug)]
+pub struct Vendored {
+    /// The library is built if [`VENDORED_OPTION`] and any of these CMake options are
+    /// enabled. It's always built with [`VENDORED_OPTION`] if this is empty.
+    pub cmake_options: &'static [&'static str],
+    /// License of the library
+    pub license: License,
+}
+
+/// License of SDL_ttf
+pub const LICENSE: License = License {
+    name: "SDL_ttf",
+    spdx: "Zlib",
+    files: &["LICENSE.txt"],
+};
+
+/// CMake option that enables building the vendored libraries
+pub const VENDORED_OPTION: Option<&str> = Some("SDLTTF_VENDORED");
+
+/// Libraries vendored in the source tree
+pub const VENDORED: &[Vendored] = &[
+    Vendored {
+        cmake_options: &[],
+        license: License {
+            name: "FreeType",
+            spdx: "FTL OR GPL-2.0-or-later",
+            files: &[
+                "external/freetype/LICENSE.TXT",
+                "external/freetype/docs/FTL.TXT",
+            ],
+        },
+    },
+    Vendored {
+        cmake_options: &["SDLTTF_HARFBUZZ"],
+        license: License {
+            name: "HarfBuzz",
+            spdx: "MIT-Modern-Variant",
+            files: &["external/harfbuzz/COPYING"],
+        },
+    },
+    Vendored {
+        cmake_options: &["SDLTTF_PLUTOSVG"],
+        license: License {
+            name: "plutosvg",
+            spdx: "MIT",
+            files: &["external/plutosvg/LICENSE"],
+        },
+    },
+    Vendored {
+        cmake_options: &["SDLTTF_PLUTOSVG"],
+        license: License {
+            name: "plutovg",
+            spdx: "MIT",
+            files: &["external/plutovg/LICENSE"],
+        },
+    },
+];
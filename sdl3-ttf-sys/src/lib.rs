@@ -7,3 +7,5 @@ pub use generated::*;
 
 #[cfg(feature = "metadata")]
 pub mod metadata;
+
+pub mod version_check;
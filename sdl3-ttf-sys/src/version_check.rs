@@ -0,0 +1,14 @@
+//! Check that the linked SDL_ttf library is compatible with the bindings at runtime
+//!
+//! See [`sdl3_sys::version_check`] for details.
+
+use crate::ttf::{SDL_TTF_VERSION, TTF_Version};
+pub use sdl3_sys::version_check::{ApiKind, UnavailableApi, VersionReport};
+
+/// Compare the version of the linked SDL_ttf library with the version of the bindings.
+///
+/// This calls [`TTF_Version()`], so SDL_ttf must be linked. It doesn't check SDL itself;
+/// use [`sdl3_sys::version_check::check_compatible()`] for that.
+pub fn check_compatible() -> VersionReport {
+    sdl3_sys::__version_report!("SDL_ttf", SDL_TTF_VERSION, TTF_Version(), None)
+}